@@ -0,0 +1,109 @@
+use crate::context::Uid;
+use crate::enums::IDMatchMethod;
+use crate::{Code, Extension};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// Eid Object
+///
+/// Extended identifier, a set of user identifiers issued by a single source such as an
+/// identity provider or ID system.
+///
+/// # Generic Parameters
+///
+/// * `Ext` - Extension object type (must implement [`Extension`]). Defaults to [`DefaultExt`](crate::DefaultExt).
+#[derive(Builder, Serialize, Deserialize, Default, Clone, Debug, PartialEq)]
+#[builder(build_fn(error = "crate::Error"), default)]
+#[serde(bound(serialize = "Ext: Extension", deserialize = "Ext: Extension"))]
+pub struct Eid<Ext: Extension = crate::DefaultExt> {
+    /// Source or technology provider responsible for the set of included IDs (top-level domain)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+
+    /// Array of user identifiers from the source
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uids: Option<Vec<Uid<Ext>>>,
+
+    /// Canonical domain of the entity that caused the ID array element to be added
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inserter: Option<String>,
+
+    /// Technology providing the match method as defined in `mm` (domain)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matcher: Option<String>,
+
+    /// Method by which the user ID was matched. Exchange-specific methods are kept as
+    /// [`Code::Unknown`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mm: Option<Code<IDMatchMethod>>,
+
+    /// Extension object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Box<Ext>>,
+}
+
+impl Eid {
+    /// Convenience method to create a new instance using the builder pattern.
+    pub fn builder() -> EidBuilder {
+        EidBuilder::create_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eid_builder() {
+        let eid = Eid::builder()
+            .source(Some("liveramp.com".to_string()))
+            .uids(Some(vec![
+                Uid::builder()
+                    .id(Some(
+                        "XY1000bIVBVah9ium-sZ3ykhPiXQbEcUpn4GjCtxrrw2BRDGM".to_string(),
+                    ))
+                    .atype(Some(3))
+                    .build()
+                    .unwrap(),
+            ]))
+            .mm(Some(Code::Known(IDMatchMethod::Authenticated)))
+            .build()
+            .unwrap();
+
+        assert_eq!(eid.source, Some("liveramp.com".to_string()));
+        assert_eq!(eid.uids.as_ref().unwrap().len(), 1);
+        assert_eq!(eid.mm, Some(Code::Known(IDMatchMethod::Authenticated)));
+    }
+
+    #[test]
+    fn test_eid_serialization_roundtrip() {
+        let json = r#"{"source":"id5-sync.com","uids":[{"id":"ID5-abc","atype":1}],"inserter":"ssp.com","matcher":"id5-sync.com","mm":2}"#;
+        let eid: Eid = serde_json::from_str(json).unwrap();
+
+        assert_eq!(eid.inserter, Some("ssp.com".to_string()));
+        assert_eq!(eid.matcher, Some("id5-sync.com".to_string()));
+        assert_eq!(eid.mm, Some(Code::Known(IDMatchMethod::CookieSync)));
+        assert_eq!(serde_json::to_string(&eid).unwrap(), json);
+    }
+
+    #[test]
+    fn test_eid_observed_match_method() {
+        let eid: Eid = serde_json::from_str(r#"{"source":"a.com","mm":4}"#).unwrap();
+        assert_eq!(eid.mm, Some(Code::Known(IDMatchMethod::Observed)));
+    }
+
+    #[test]
+    fn test_eid_exchange_specific_match_method() {
+        let json = r#"{"source":"a.com","mm":501}"#;
+        let eid: Eid = serde_json::from_str(json).unwrap();
+
+        assert_eq!(eid.mm, Some(Code::Unknown(501)));
+        assert_eq!(serde_json::to_string(&eid).unwrap(), json);
+    }
+
+    #[test]
+    fn test_eid_invalid_match_method() {
+        let result: Result<Eid, _> = serde_json::from_str(r#"{"source":"a.com","mm":"3"}"#);
+        assert!(result.is_err());
+    }
+}
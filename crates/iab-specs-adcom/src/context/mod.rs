@@ -14,6 +14,7 @@ mod data;
 mod device;
 mod distribution_channel;
 mod dooh;
+mod eid;
 mod geo;
 mod network;
 mod producer;
@@ -21,6 +22,7 @@ mod publisher;
 mod regs;
 mod segment;
 mod site;
+mod uid;
 mod user;
 mod user_agent;
 
@@ -32,6 +34,7 @@ pub use data::*;
 pub use device::*;
 pub use distribution_channel::*;
 pub use dooh::*;
+pub use eid::*;
 pub use geo::*;
 pub use network::*;
 pub use producer::*;
@@ -39,5 +42,6 @@ pub use publisher::*;
 pub use regs::*;
 pub use segment::*;
 pub use site::*;
+pub use uid::*;
 pub use user::*;
 pub use user_agent::*;
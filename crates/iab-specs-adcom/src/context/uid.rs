@@ -0,0 +1,64 @@
+use crate::Extension;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// Uid Object
+///
+/// A single user identifier provided as part of an extended identifier ([`Eid`](crate::context::Eid)).
+///
+/// # Generic Parameters
+///
+/// * `Ext` - Extension object type (must implement [`Extension`]). Defaults to [`DefaultExt`](crate::DefaultExt).
+#[derive(Builder, Serialize, Deserialize, Default, Clone, Debug, PartialEq)]
+#[builder(build_fn(error = "crate::Error"), default)]
+#[serde(bound(serialize = "Ext: Extension", deserialize = "Ext: Extension"))]
+pub struct Uid<Ext: Extension = crate::DefaultExt> {
+    /// The identifier for the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Type of user agent the ID is from (1=web browser or device, 2=in-app, 3=person-based)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub atype: Option<i32>,
+
+    /// Extension object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Box<Ext>>,
+}
+
+impl Uid {
+    /// Convenience method to create a new instance using the builder pattern.
+    pub fn builder() -> UidBuilder {
+        UidBuilder::create_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uid_builder() {
+        let uid = Uid::builder()
+            .id(Some("BB6E8B0D-4F1B-4C6B-9F1A-3C5D9B0E7A21".to_string()))
+            .atype(Some(1))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            uid.id,
+            Some("BB6E8B0D-4F1B-4C6B-9F1A-3C5D9B0E7A21".to_string())
+        );
+        assert_eq!(uid.atype, Some(1));
+    }
+
+    #[test]
+    fn test_uid_serialization_roundtrip() {
+        let json = r#"{"id":"person-123","atype":3}"#;
+        let uid: Uid = serde_json::from_str(json).unwrap();
+
+        assert_eq!(uid.id, Some("person-123".to_string()));
+        assert_eq!(uid.atype, Some(3));
+        assert_eq!(serde_json::to_string(&uid).unwrap(), json);
+    }
+}
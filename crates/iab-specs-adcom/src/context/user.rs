@@ -1,5 +1,5 @@
 use crate::Extension;
-use crate::context::{Data, Geo};
+use crate::context::{Data, Eid, Geo};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
@@ -46,6 +46,10 @@ pub struct User<Ext: Extension = crate::DefaultExt> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<Vec<Data>>,
 
+    /// Extended identifiers from identity providers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eids: Option<Vec<Eid>>,
+
     /// Extension object
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ext: Option<Box<Ext>>,
@@ -144,6 +148,20 @@ mod tests {
         assert!(user.consent.as_ref().unwrap().starts_with("CPtRHYQ"));
     }
 
+    #[test]
+    fn test_user_with_eids() {
+        let json = r#"{"id":"user321","eids":[{"source":"adserver.org","uids":[{"id":"tdid-1","atype":1}],"mm":1}]}"#;
+        let user: User = serde_json::from_str(json).unwrap();
+
+        let eids = user.eids.as_ref().unwrap();
+        assert_eq!(eids[0].source, Some("adserver.org".to_string()));
+        assert_eq!(
+            eids[0].mm,
+            Some(crate::Code::Known(crate::enums::IDMatchMethod::NoMatching))
+        );
+        assert_eq!(serde_json::to_string(&user).unwrap(), json);
+    }
+
     /// AdCOM 1.0 Section 7.7 - User serialization roundtrip
     #[test]
     fn test_user_serialization_roundtrip() {
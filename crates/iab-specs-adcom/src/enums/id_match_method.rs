@@ -2,21 +2,28 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 
 /// ID matching methods for user identification.
 ///
-/// Indicates the method used to match a user ID across different contexts.
+/// Indicates the method used to match a user ID across different contexts. Values of 500+
+/// are exchange-specific.
 #[derive(Serialize_repr, Deserialize_repr, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[repr(u8)]
 pub enum IDMatchMethod {
-    /// No matching - ID came directly from 3rd-party cookie or device IFA
-    NoMatching = 0,
+    /// Unknown
+    Unknown = 0,
 
-    /// First-party observation without user authentication
-    FirstParty = 1,
+    /// No matching - ID came directly from 3rd-party cookie or OS-provided resettable device ID
+    NoMatching = 1,
 
-    /// Probabilistic matching based on non-authenticated features
-    Probabilistic = 2,
+    /// Cookie syncing
+    CookieSync = 2,
 
-    /// Deterministic matching with user authentication
-    Deterministic = 3,
+    /// Authenticated - matched through user login or other deterministic, authenticated methods
+    Authenticated = 3,
+
+    /// Observed - matched through first-party observation without user authentication
+    Observed = 4,
+
+    /// Inference - matched through statistical or probabilistic inference
+    Inference = 5,
 }
 
 #[cfg(test)]
@@ -25,8 +32,8 @@ mod tests {
 
     #[test]
     fn test_all_valid_values() {
-        // Test all valid IDMatchMethod values (0-3)
-        for value in 0..=3 {
+        // Test all valid IDMatchMethod values (0-5)
+        for value in 0..=5 {
             let json = format!("{}", value);
             let result: Result<IDMatchMethod, _> = serde_json::from_str(&json);
             assert!(
@@ -57,10 +64,12 @@ mod tests {
     #[test]
     fn test_serialization_roundtrip() {
         let values = [
+            IDMatchMethod::Unknown,
             IDMatchMethod::NoMatching,
-            IDMatchMethod::FirstParty,
-            IDMatchMethod::Probabilistic,
-            IDMatchMethod::Deterministic,
+            IDMatchMethod::CookieSync,
+            IDMatchMethod::Authenticated,
+            IDMatchMethod::Observed,
+            IDMatchMethod::Inference,
         ];
 
         for original in values {
@@ -1,4 +1,4 @@
-pub use iab_specs_core::{DefaultExt, Error, Extension, Result};
+pub use iab_specs_core::{Code, DefaultExt, Error, Extension, Result};
 
 pub mod context;
 pub mod enums;
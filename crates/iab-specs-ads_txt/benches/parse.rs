@@ -0,0 +1,67 @@
+//! Compares the throughput of the owned and streaming ads.txt parsers.
+//!
+//! Run with `cargo bench -p iab-specs-ads_txt`.
+
+use iab_specs_ads_txt::{AdsTxt, AdsTxtEntryRef, AdsTxtReader};
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+const LINES: usize = 200_000;
+const ITERATIONS: u32 = 10;
+
+fn generate() -> String {
+    let mut content = String::from("# Generated ads.txt\ncontact=adops@example.com\n");
+    for i in 0..LINES {
+        let relation = if i % 3 == 0 { "DIRECT" } else { "RESELLER" };
+        content.push_str(&format!(
+            "Exchange{}.com, Pub-{:08}, {}, f08c47fec0942fa0 # seat {}\n",
+            i % 500,
+            i,
+            relation,
+            i
+        ));
+    }
+    content
+}
+
+fn measure(name: &str, bytes: usize, mut f: impl FnMut() -> usize) {
+    let mut total = Duration::ZERO;
+    let mut records = 0;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        records = black_box(f());
+        total += start.elapsed();
+    }
+    let per_iteration = total / ITERATIONS;
+    let throughput = bytes as f64 / per_iteration.as_secs_f64() / (1024.0 * 1024.0);
+    println!(
+        "{:<24} {:>10.2?} per file, {:>8.1} MiB/s ({} records)",
+        name, per_iteration, throughput, records
+    );
+}
+
+fn main() {
+    let content = generate();
+    let bytes = content.len();
+    println!("{} lines, {} bytes", LINES, bytes);
+
+    measure("AdsTxt::from_str", bytes, || {
+        AdsTxt::from_str(black_box(&content)).unwrap().systems.len()
+    });
+    measure("AdsTxt::entries", bytes, || {
+        AdsTxt::entries(black_box(&content))
+            .filter(|entry| matches!(entry, Ok((_, AdsTxtEntryRef::System(_)))))
+            .count()
+    });
+    measure("AdsTxtReader", bytes, || {
+        let mut reader = AdsTxtReader::new(black_box(content.as_bytes()));
+        let mut records = 0;
+        while let Some(entry) = reader.next_entry() {
+            if let Ok((_, AdsTxtEntryRef::System(_))) = entry {
+                records += 1;
+            }
+        }
+        records
+    });
+}
@@ -0,0 +1,546 @@
+use crate::AdsTxt;
+use derive_builder::Builder;
+
+/// An HTTP response received while crawling an ads.txt file, independently of the HTTP
+/// client used to retrieve it.
+#[derive(Builder, Clone, Debug, PartialEq, Eq)]
+#[builder(build_fn(error = "crate::Error"))]
+pub struct AdsTxtHttpResponse {
+    /// HTTP status code.
+    pub status: u16,
+
+    /// Response headers, as `(name, value)` pairs. Names are matched case-insensitively.
+    #[builder(default)]
+    pub headers: Vec<(String, String)>,
+
+    /// Response body.
+    #[builder(default, setter(into))]
+    pub body: String,
+}
+
+impl AdsTxtHttpResponse {
+    /// Convenience method to create a new instance using the builder pattern.
+    pub fn builder() -> AdsTxtHttpResponseBuilder {
+        AdsTxtHttpResponseBuilder::create_empty()
+    }
+
+    /// Returns the value of the first header named `name`, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Returns the `Location` header if the response is a redirect.
+    pub fn redirect_location(&self) -> Option<&str> {
+        match self.status {
+            301 | 302 | 303 | 307 | 308 => self.header("location"),
+            _ => None,
+        }
+    }
+}
+
+/// Reason why a crawl did not produce a usable ads.txt file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AdsTxtCrawlError {
+    /// A redirect left the original root domain a second time, or a third-party location
+    /// redirected again.
+    RedirectOutOfScope { location: String },
+
+    /// More than [`AdsTxtCrawl::MAX_REDIRECTS`] redirects were followed.
+    TooManyRedirects,
+
+    /// A redirect response had no usable `Location` header.
+    MissingLocation,
+
+    /// The sequence of responses ended on a redirect.
+    Incomplete,
+
+    /// The file was served with a Content-Type other than `text/plain`.
+    InvalidContentType(String),
+
+    /// The file was served without a Content-Type.
+    MissingContentType,
+
+    /// The server failed (5xx). The previously crawled file may still be used.
+    ServerError(u16),
+
+    /// Any other status code, including 3xx codes that are not redirects such as `304`.
+    UnexpectedStatus(u16),
+}
+
+/// How an ads.txt crawl should be interpreted.
+#[derive(Clone, Debug)]
+pub enum AdsTxtCrawlOutcome {
+    /// The file declares records or variables.
+    Valid(AdsTxt),
+
+    /// The file exists but declares nothing: no seller is authorised.
+    Empty,
+
+    /// The file does not exist (404 or 410): no declaration exists for the domain.
+    Missing,
+
+    /// The server refused access to the file (401 or 403).
+    Unauthorized,
+
+    /// The file could not be retrieved or must be ignored.
+    Error(AdsTxtCrawlError),
+}
+
+/// Interpretation of the HTTP responses of an ads.txt crawl, following the crawler section of
+/// the ads.txt 1.1 specification.
+///
+/// - Redirects are followed as long as they stay within the original root domain. A single
+///   redirect to another domain is allowed; a redirect from that third-party location is an
+///   error.
+/// - Only a `200 OK` served as `text/plain` is parsed, invalid lines being skipped as with
+///   [`AdsTxt::parse_lenient`].
+/// - `404`/`410` mean no declaration exists, `401`/`403` that access is denied and `5xx` a
+///   temporary failure.
+/// - The result expires as directed by the `Cache-Control` `max-age` directive, or else by the
+///   `Expires` header relative to `Date`, and at the latest after [`AdsTxtCrawl::MAX_AGE`].
+#[derive(Clone, Debug)]
+pub struct AdsTxtCrawl {
+    /// What the crawl yields.
+    pub outcome: AdsTxtCrawlOutcome,
+
+    /// Domain that served the last response.
+    pub final_domain: String,
+
+    /// How long the result may be cached, in seconds, from the caching headers of the last
+    /// response and capped at [`AdsTxtCrawl::MAX_AGE`]. `Some(0)` for `no-cache`, `no-store`
+    /// and expired responses. `None` if no final response was received.
+    pub max_age: Option<u64>,
+}
+
+impl AdsTxtCrawl {
+    /// Maximum number of redirects followed.
+    pub const MAX_REDIRECTS: usize = 5;
+
+    /// Maximum time a crawled file may be used before crawling it again, in seconds (7 days).
+    pub const MAX_AGE: u64 = 7 * 24 * 60 * 60;
+
+    /// Interprets `responses`, the responses received in order when requesting
+    /// `https://<root_domain>/ads.txt` and following its redirects.
+    pub fn from_responses(root_domain: &str, responses: &[AdsTxtHttpResponse]) -> Self {
+        let root_domain = root_domain.to_lowercase();
+        let mut domain = root_domain.clone();
+        let mut left_root_domain = false;
+        let mut redirects = 0;
+
+        for response in responses {
+            let max_age = max_age(response);
+            let crawl = |outcome| AdsTxtCrawl {
+                outcome,
+                final_domain: domain.clone(),
+                max_age,
+            };
+            let error = |e| crawl(AdsTxtCrawlOutcome::Error(e));
+
+            if matches!(response.status, 301 | 302 | 303 | 307 | 308) {
+                let Some(location) = response.redirect_location() else {
+                    return error(AdsTxtCrawlError::MissingLocation);
+                };
+                let Some(target) = location_domain(location, &domain) else {
+                    return error(AdsTxtCrawlError::MissingLocation);
+                };
+                redirects += 1;
+                if redirects > Self::MAX_REDIRECTS {
+                    return error(AdsTxtCrawlError::TooManyRedirects);
+                }
+                if left_root_domain {
+                    return error(AdsTxtCrawlError::RedirectOutOfScope {
+                        location: location.to_string(),
+                    });
+                }
+                if !within_domain(&target, &root_domain) {
+                    left_root_domain = true;
+                }
+                domain = target;
+                continue;
+            }
+
+            let outcome = match response.status {
+                200 => match response.header("content-type") {
+                    None => AdsTxtCrawlOutcome::Error(AdsTxtCrawlError::MissingContentType),
+                    Some(content_type) if !is_text_plain(content_type) => {
+                        AdsTxtCrawlOutcome::Error(AdsTxtCrawlError::InvalidContentType(
+                            content_type.to_string(),
+                        ))
+                    }
+                    Some(_) => {
+                        let ads_txt = AdsTxt::parse_lenient(&response.body).ads_txt;
+                        if is_empty(&ads_txt) {
+                            AdsTxtCrawlOutcome::Empty
+                        } else {
+                            AdsTxtCrawlOutcome::Valid(ads_txt)
+                        }
+                    }
+                },
+                204 => AdsTxtCrawlOutcome::Empty,
+                401 | 403 => AdsTxtCrawlOutcome::Unauthorized,
+                404 | 410 => AdsTxtCrawlOutcome::Missing,
+                500..=599 => {
+                    AdsTxtCrawlOutcome::Error(AdsTxtCrawlError::ServerError(response.status))
+                }
+                status => AdsTxtCrawlOutcome::Error(AdsTxtCrawlError::UnexpectedStatus(status)),
+            };
+            return crawl(outcome);
+        }
+
+        AdsTxtCrawl {
+            outcome: AdsTxtCrawlOutcome::Error(AdsTxtCrawlError::Incomplete),
+            final_domain: domain,
+            max_age: None,
+        }
+    }
+}
+
+fn is_text_plain(content_type: &str) -> bool {
+    content_type
+        .split(';')
+        .next()
+        .is_some_and(|mime| mime.trim().eq_ignore_ascii_case("text/plain"))
+}
+
+fn is_empty(ads_txt: &AdsTxt) -> bool {
+    ads_txt.systems.is_empty()
+        && ads_txt.contact.is_none()
+        && ads_txt.subdomains.is_empty()
+        && ads_txt.inventory_partner_domains.is_empty()
+        && ads_txt.owner_domain.is_none()
+        && ads_txt.manager_domains.is_empty()
+        && ads_txt.unknown_variables.is_empty()
+}
+
+/// Computes how long a response may be cached, in seconds.
+///
+/// `Cache-Control` takes precedence over `Expires`, as in RFC 9111. An `Expires` value that
+/// cannot be parsed means the response is already expired.
+fn max_age(response: &AdsTxtHttpResponse) -> Option<u64> {
+    let max_age = cache_control_max_age(response).or_else(|| {
+        let expires = response.header("expires")?;
+        let date = response.header("date").and_then(parse_http_date);
+        Some(match (parse_http_date(expires), date) {
+            (Some(expires), Some(date)) => expires.saturating_sub(date),
+            _ => 0,
+        })
+    });
+    Some(max_age.map_or(AdsTxtCrawl::MAX_AGE, |max_age| {
+        max_age.min(AdsTxtCrawl::MAX_AGE)
+    }))
+}
+
+/// Extracts `max-age` from the `Cache-Control` header.
+fn cache_control_max_age(response: &AdsTxtHttpResponse) -> Option<u64> {
+    let cache_control = response.header("cache-control")?;
+    let mut max_age = None;
+    for directive in cache_control.split(',').map(str::trim) {
+        if directive.eq_ignore_ascii_case("no-cache") || directive.eq_ignore_ascii_case("no-store")
+        {
+            return Some(0);
+        }
+        if let Some((name, value)) = directive.split_once('=') {
+            if name.trim().eq_ignore_ascii_case("max-age") {
+                max_age = value.trim().trim_matches('"').parse().ok();
+            }
+        }
+    }
+    max_age
+}
+
+/// Parses an IMF-fixdate such as `Sun, 06 Nov 1994 08:49:37 GMT` into seconds since the Unix
+/// epoch.
+fn parse_http_date(value: &str) -> Option<u64> {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    let (_, date) = value.trim().split_once(',')?;
+    let mut parts = date.split_whitespace();
+    let day: u64 = parts.next()?.parse().ok()?;
+    let month = parts.next()?.to_lowercase();
+    let month = MONTHS.iter().position(|m| *m == month)? as u64 + 1;
+    let year: u64 = parts.next()?.parse().ok()?;
+    let mut time = parts.next()?.split(':').map(|t| t.parse::<u64>().ok());
+    let (hour, minute, second) = (time.next()??, time.next()??, time.next()??);
+    if !parts.next()?.eq_ignore_ascii_case("gmt") || year < 1970 || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    // Days since the epoch of a proleptic Gregorian date, shifting years to start in March.
+    let (y, m) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let days = 365 * y + y / 4 - y / 100 + y / 400 + (153 * m + 2) / 5 + day - 1 - 719_468;
+    Some(days * 86_400 + hour * 3_600 + minute * 60 + second)
+}
+
+/// Returns the host a `Location` header points to, `current` for relative locations.
+fn location_domain(location: &str, current: &str) -> Option<String> {
+    let location = location.trim();
+    let authority = match location.split_once("://") {
+        Some((_, rest)) => rest,
+        None => match location.strip_prefix("//") {
+            Some(rest) => rest,
+            None => return Some(current.to_string()),
+        },
+    };
+    let authority = authority.split(['/', '?', '#']).next()?;
+    let host = authority.rsplit_once('@').map_or(authority, |(_, h)| h);
+    let host = host.split(':').next()?.trim_end_matches('.');
+    (!host.is_empty()).then(|| host.to_lowercase())
+}
+
+/// Returns `true` if `domain` is `root_domain` or one of its subdomains.
+fn within_domain(domain: &str, root_domain: &str) -> bool {
+    domain == root_domain
+        || domain
+            .strip_suffix(root_domain)
+            .is_some_and(|prefix| prefix.ends_with('.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(status: u16, headers: &[(&str, &str)], body: &str) -> AdsTxtHttpResponse {
+        AdsTxtHttpResponse::builder()
+            .status(status)
+            .headers(
+                headers
+                    .iter()
+                    .map(|(n, v)| (n.to_string(), v.to_string()))
+                    .collect(),
+            )
+            .body(body)
+            .build()
+            .unwrap()
+    }
+
+    const TEXT_PLAIN: &[(&str, &str)] = &[("Content-Type", "text/plain")];
+
+    #[test]
+    fn valid_file() {
+        let crawl = AdsTxtCrawl::from_responses(
+            "example.com",
+            &[response(
+                200,
+                &[
+                    ("Content-Type", "text/plain; charset=utf-8"),
+                    ("Cache-Control", "public, max-age=86400"),
+                ],
+                "greenadexchange.com, XF7342, DIRECT",
+            )],
+        );
+        assert!(
+            matches!(crawl.outcome, AdsTxtCrawlOutcome::Valid(ads_txt) if ads_txt.systems.len() == 1)
+        );
+        assert_eq!(crawl.final_domain, "example.com");
+        assert_eq!(crawl.max_age, Some(86400));
+    }
+
+    #[test]
+    fn empty_missing_and_unauthorized() {
+        let crawl = AdsTxtCrawl::from_responses(
+            "example.com",
+            &[response(200, TEXT_PLAIN, "# no sellers\n")],
+        );
+        assert!(matches!(crawl.outcome, AdsTxtCrawlOutcome::Empty));
+
+        let crawl = AdsTxtCrawl::from_responses("example.com", &[response(404, &[], "")]);
+        assert!(matches!(crawl.outcome, AdsTxtCrawlOutcome::Missing));
+
+        let crawl = AdsTxtCrawl::from_responses("example.com", &[response(403, &[], "")]);
+        assert!(matches!(crawl.outcome, AdsTxtCrawlOutcome::Unauthorized));
+    }
+
+    #[test]
+    fn invalid_content_type_and_errors() {
+        let crawl = AdsTxtCrawl::from_responses(
+            "example.com",
+            &[response(
+                200,
+                &[("content-type", "text/html")],
+                "<html></html>",
+            )],
+        );
+        assert!(matches!(
+            crawl.outcome,
+            AdsTxtCrawlOutcome::Error(AdsTxtCrawlError::InvalidContentType(ref c)) if c == "text/html"
+        ));
+
+        let crawl = AdsTxtCrawl::from_responses(
+            "example.com",
+            &[response(200, &[], "greenadexchange.com, XF7342, DIRECT")],
+        );
+        assert!(matches!(
+            crawl.outcome,
+            AdsTxtCrawlOutcome::Error(AdsTxtCrawlError::MissingContentType)
+        ));
+
+        let crawl = AdsTxtCrawl::from_responses("example.com", &[response(503, &[], "")]);
+        assert!(matches!(
+            crawl.outcome,
+            AdsTxtCrawlOutcome::Error(AdsTxtCrawlError::ServerError(503))
+        ));
+
+        let crawl = AdsTxtCrawl::from_responses("example.com", &[response(418, &[], "")]);
+        assert!(matches!(
+            crawl.outcome,
+            AdsTxtCrawlOutcome::Error(AdsTxtCrawlError::UnexpectedStatus(418))
+        ));
+
+        let crawl = AdsTxtCrawl::from_responses(
+            "example.com",
+            &[response(304, &[("Location", "/ads.txt")], "")],
+        );
+        assert!(matches!(
+            crawl.outcome,
+            AdsTxtCrawlOutcome::Error(AdsTxtCrawlError::UnexpectedStatus(304))
+        ));
+
+        let crawl = AdsTxtCrawl::from_responses("example.com", &[]);
+        assert!(matches!(
+            crawl.outcome,
+            AdsTxtCrawlOutcome::Error(AdsTxtCrawlError::Incomplete)
+        ));
+    }
+
+    #[test]
+    fn redirects_within_root_domain() {
+        let crawl = AdsTxtCrawl::from_responses(
+            "example.com",
+            &[
+                response(301, &[("Location", "https://www.example.com/ads.txt")], ""),
+                response(302, &[("Location", "/static/ads.txt")], ""),
+                response(200, TEXT_PLAIN, "redssp.com, 1, DIRECT"),
+            ],
+        );
+        assert!(matches!(crawl.outcome, AdsTxtCrawlOutcome::Valid(_)));
+        assert_eq!(crawl.final_domain, "www.example.com");
+    }
+
+    #[test]
+    fn single_redirect_out_of_root_domain() {
+        let crawl = AdsTxtCrawl::from_responses(
+            "example.com",
+            &[
+                response(301, &[("Location", "https://www.example.com/ads.txt")], ""),
+                response(
+                    307,
+                    &[("Location", "https://cdn.thirdparty.com:443/example/ads.txt")],
+                    "",
+                ),
+                response(200, TEXT_PLAIN, "redssp.com, 1, DIRECT"),
+            ],
+        );
+        assert!(matches!(crawl.outcome, AdsTxtCrawlOutcome::Valid(_)));
+        assert_eq!(crawl.final_domain, "cdn.thirdparty.com");
+
+        let crawl = AdsTxtCrawl::from_responses(
+            "example.com",
+            &[
+                response(301, &[("Location", "https://thirdparty.com/ads.txt")], ""),
+                response(
+                    301,
+                    &[("Location", "https://thirdparty.com/v2/ads.txt")],
+                    "",
+                ),
+                response(200, TEXT_PLAIN, "redssp.com, 1, DIRECT"),
+            ],
+        );
+        assert!(matches!(
+            crawl.outcome,
+            AdsTxtCrawlOutcome::Error(AdsTxtCrawlError::RedirectOutOfScope { .. })
+        ));
+    }
+
+    #[test]
+    fn redirect_limits() {
+        let crawl = AdsTxtCrawl::from_responses("example.com", &[response(301, &[], "")]);
+        assert!(matches!(
+            crawl.outcome,
+            AdsTxtCrawlOutcome::Error(AdsTxtCrawlError::MissingLocation)
+        ));
+
+        let redirects: Vec<_> = (0..=AdsTxtCrawl::MAX_REDIRECTS)
+            .map(|_| response(302, &[("Location", "/ads.txt")], ""))
+            .collect();
+        let crawl = AdsTxtCrawl::from_responses("example.com", &redirects);
+        assert!(matches!(
+            crawl.outcome,
+            AdsTxtCrawlOutcome::Error(AdsTxtCrawlError::TooManyRedirects)
+        ));
+    }
+
+    #[test]
+    fn cache_control() {
+        let crawl = AdsTxtCrawl::from_responses(
+            "example.com",
+            &[response(404, &[("Cache-Control", "no-store")], "")],
+        );
+        assert_eq!(crawl.max_age, Some(0));
+
+        let crawl = AdsTxtCrawl::from_responses(
+            "example.com",
+            &[response(200, &[("Cache-Control", "max-age=31536000")], "")],
+        );
+        assert_eq!(crawl.max_age, Some(AdsTxtCrawl::MAX_AGE));
+
+        let crawl = AdsTxtCrawl::from_responses("example.com", &[response(404, &[], "")]);
+        assert_eq!(crawl.max_age, Some(AdsTxtCrawl::MAX_AGE));
+    }
+
+    #[test]
+    fn expires_header() {
+        let crawl = AdsTxtCrawl::from_responses(
+            "example.com",
+            &[response(
+                200,
+                &[
+                    ("Content-Type", "text/plain"),
+                    ("Date", "Sun, 06 Nov 1994 08:49:37 GMT"),
+                    ("Expires", "Mon, 07 Nov 1994 08:49:37 GMT"),
+                ],
+                "redssp.com, 1, DIRECT",
+            )],
+        );
+        assert_eq!(crawl.max_age, Some(86400));
+
+        let crawl = AdsTxtCrawl::from_responses(
+            "example.com",
+            &[response(
+                200,
+                &[
+                    ("Content-Type", "text/plain"),
+                    ("Cache-Control", "max-age=60"),
+                    ("Expires", "0"),
+                ],
+                "redssp.com, 1, DIRECT",
+            )],
+        );
+        assert_eq!(crawl.max_age, Some(60));
+
+        let crawl =
+            AdsTxtCrawl::from_responses("example.com", &[response(200, &[("Expires", "0")], "")]);
+        assert_eq!(crawl.max_age, Some(0));
+    }
+
+    #[test]
+    fn http_date() {
+        assert_eq!(parse_http_date("Thu, 01 Jan 1970 00:00:00 GMT"), Some(0));
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(784_111_777)
+        );
+        assert_eq!(
+            parse_http_date("Tue, 29 Feb 2028 12:00:00 GMT"),
+            Some(1_835_438_400)
+        );
+        assert_eq!(parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), None);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::AdsTxt;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// How serious a diagnostic reported by the lenient ads.txt parser is.
+///
+/// A `Warning` never causes data to be dropped, whereas an `Error` means the
+/// offending line was skipped and is missing from the parsed [`AdsTxt`].
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum AdsTxtDiagnosticSeverity {
+    /// The line was kept but does not follow the specification or best practices.
+    Warning,
+    /// The line could not be interpreted and was skipped.
+    Error,
+}
+
+/// Machine-readable reason attached to an [`AdsTxtDiagnostic`].
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum AdsTxtDiagnosticCode {
+    /// A `key=value` variable whose key is not defined by the specification.
+    UnknownVariable,
+    /// A variable without value, or a record missing one of its mandatory fields.
+    MissingField,
+    /// A record whose relationship field is neither `DIRECT` nor `RESELLER`.
+    InvalidRelation,
+    /// A MANAGERDOMAIN value that is not `domain[,country_code]`.
+    InvalidManagerDomain,
+    /// A record with more fields than the specification allows.
+    TooManyFields,
+    /// A record or variable that already appeared earlier in the file.
+    DuplicateEntry,
+}
+
+/// A single line-numbered finding reported by [`AdsTxt::parse_lenient`].
+#[derive(Builder, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[builder(build_fn(error = "crate::Error"))]
+pub struct AdsTxtDiagnostic {
+    /// 1-based line number in the parsed content.
+    pub line: usize,
+
+    /// Severity of the finding.
+    pub severity: AdsTxtDiagnosticSeverity,
+
+    /// Machine-readable reason of the finding.
+    pub code: AdsTxtDiagnosticCode,
+
+    /// Human readable description of the finding.
+    #[builder(setter(into))]
+    pub message: String,
+}
+
+impl AdsTxtDiagnostic {
+    /// Convenience method to create a new instance using the builder pattern.
+    pub fn builder() -> AdsTxtDiagnosticBuilder {
+        AdsTxtDiagnosticBuilder::create_empty()
+    }
+
+    pub(crate) fn new(
+        line: usize,
+        severity: AdsTxtDiagnosticSeverity,
+        code: AdsTxtDiagnosticCode,
+        message: impl Into<String>,
+    ) -> Self {
+        AdsTxtDiagnostic {
+            line,
+            severity,
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// Result of a lenient ads.txt parse: every valid record and variable, plus the
+/// diagnostics raised by the lines that did not follow the specification.
+#[derive(Clone, Debug)]
+pub struct AdsTxtParseReport {
+    /// The valid content of the file.
+    pub ads_txt: AdsTxt,
+
+    /// Findings in the order of the lines they refer to.
+    pub diagnostics: Vec<AdsTxtDiagnostic>,
+}
+
+impl AdsTxtParseReport {
+    /// Returns `true` if at least one line was skipped.
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+
+    /// Iterates over the diagnostics with [`AdsTxtDiagnosticSeverity::Error`] severity.
+    pub fn errors(&self) -> impl Iterator<Item = &AdsTxtDiagnostic> {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.severity == AdsTxtDiagnosticSeverity::Error)
+    }
+
+    /// Iterates over the diagnostics with [`AdsTxtDiagnosticSeverity::Warning`] severity.
+    pub fn warnings(&self) -> impl Iterator<Item = &AdsTxtDiagnostic> {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.severity == AdsTxtDiagnosticSeverity::Warning)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_diagnostic() {
+        let diagnostic = AdsTxtDiagnostic::builder()
+            .line(3)
+            .severity(AdsTxtDiagnosticSeverity::Error)
+            .code(AdsTxtDiagnosticCode::InvalidRelation)
+            .message("invalid relation")
+            .build()
+            .unwrap();
+        let json = serde_json::to_string(&diagnostic).unwrap();
+        assert_eq!(
+            json,
+            r#"{"line":3,"severity":"error","code":"invalid_relation","message":"invalid relation"}"#
+        );
+        let parsed: AdsTxtDiagnostic = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, diagnostic);
+    }
+
+    #[test]
+    fn report_splits_errors_and_warnings() {
+        let report = AdsTxtParseReport {
+            ads_txt: AdsTxt::builder().build().unwrap(),
+            diagnostics: vec![
+                AdsTxtDiagnostic::new(
+                    1,
+                    AdsTxtDiagnosticSeverity::Warning,
+                    AdsTxtDiagnosticCode::UnknownVariable,
+                    "unknown",
+                ),
+                AdsTxtDiagnostic::new(
+                    2,
+                    AdsTxtDiagnosticSeverity::Error,
+                    AdsTxtDiagnosticCode::MissingField,
+                    "missing",
+                ),
+            ],
+        };
+        assert!(report.has_errors());
+        assert_eq!(report.errors().count(), 1);
+        assert_eq!(report.warnings().count(), 1);
+        assert_eq!(report.errors().next().unwrap().line, 2);
+    }
+}
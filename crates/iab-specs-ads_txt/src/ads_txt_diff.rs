@@ -0,0 +1,470 @@
+use crate::{AdsTxt, AdsTxtSystem, ManagerDomain, SellerRelationType};
+use isosphere::CountryCode;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A single difference between two [`AdsTxt`] snapshots.
+///
+/// Data records are identified by their `(domain, publisher_id)` pair and manager domains by
+/// their domain, so that a modified entry is reported as a change rather than as a removal
+/// followed by an addition. Comments are ignored.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AdsTxtChange {
+    /// A data record only present in the newer snapshot.
+    RecordAdded { record: AdsTxtSystem },
+
+    /// A data record only present in the older snapshot.
+    RecordRemoved { record: AdsTxtSystem },
+
+    /// The relationship of an account flipped between DIRECT and RESELLER.
+    RelationChanged {
+        domain: String,
+        publisher_id: String,
+        from: SellerRelationType,
+        to: SellerRelationType,
+    },
+
+    /// The certification authority ID of an account changed.
+    CertIdChanged {
+        domain: String,
+        publisher_id: String,
+        from: Option<String>,
+        to: Option<String>,
+    },
+
+    /// A MANAGERDOMAIN only present in the newer snapshot.
+    ManagerDomainAdded { manager_domain: ManagerDomain },
+
+    /// A MANAGERDOMAIN only present in the older snapshot.
+    ManagerDomainRemoved { manager_domain: ManagerDomain },
+
+    /// The country a MANAGERDOMAIN applies to changed.
+    ManagerDomainCountryChanged {
+        domain: String,
+        from: Option<CountryCode>,
+        to: Option<CountryCode>,
+    },
+
+    /// The OWNERDOMAIN changed, was added or was removed.
+    OwnerDomainChanged {
+        from: Option<String>,
+        to: Option<String>,
+    },
+
+    /// The CONTACT changed, was added or was removed.
+    ContactChanged {
+        from: Option<String>,
+        to: Option<String>,
+    },
+
+    /// A SUBDOMAIN only present in the newer snapshot.
+    SubdomainAdded { subdomain: String },
+
+    /// A SUBDOMAIN only present in the older snapshot.
+    SubdomainRemoved { subdomain: String },
+
+    /// An INVENTORYPARTNERDOMAIN only present in the newer snapshot.
+    InventoryPartnerDomainAdded { domain: String },
+
+    /// An INVENTORYPARTNERDOMAIN only present in the older snapshot.
+    InventoryPartnerDomainRemoved { domain: String },
+
+    /// A variable not defined by the specification only present in the newer snapshot.
+    UnknownVariableAdded { key: String, value: String },
+
+    /// A variable not defined by the specification only present in the older snapshot.
+    UnknownVariableRemoved { key: String, value: String },
+}
+
+/// The list of differences between two [`AdsTxt`] snapshots, as returned by [`AdsTxt::diff`].
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct AdsTxtDiff {
+    /// Changes, variables first, then unknown variables, then manager domains, then data
+    /// records in file order.
+    pub changes: Vec<AdsTxtChange>,
+}
+
+impl AdsTxtDiff {
+    /// Returns `true` if both snapshots are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Data records only present in the newer snapshot.
+    pub fn added_records(&self) -> impl Iterator<Item = &AdsTxtSystem> {
+        self.changes.iter().filter_map(|c| match c {
+            AdsTxtChange::RecordAdded { record } => Some(record),
+            _ => None,
+        })
+    }
+
+    /// Data records only present in the older snapshot.
+    pub fn removed_records(&self) -> impl Iterator<Item = &AdsTxtSystem> {
+        self.changes.iter().filter_map(|c| match c {
+            AdsTxtChange::RecordRemoved { record } => Some(record),
+            _ => None,
+        })
+    }
+}
+
+impl AdsTxt {
+    /// Computes the changes needed to go from `self` to `newer`.
+    pub fn diff(&self, newer: &AdsTxt) -> AdsTxtDiff {
+        let mut changes = vec![];
+
+        if self.contact != newer.contact {
+            changes.push(AdsTxtChange::ContactChanged {
+                from: self.contact.clone(),
+                to: newer.contact.clone(),
+            });
+        }
+        if self.owner_domain != newer.owner_domain {
+            changes.push(AdsTxtChange::OwnerDomainChanged {
+                from: self.owner_domain.clone(),
+                to: newer.owner_domain.clone(),
+            });
+        }
+        for subdomain in newer.subdomains.iter() {
+            if !self.subdomains.contains(subdomain) {
+                changes.push(AdsTxtChange::SubdomainAdded {
+                    subdomain: subdomain.clone(),
+                });
+            }
+        }
+        for subdomain in self.subdomains.iter() {
+            if !newer.subdomains.contains(subdomain) {
+                changes.push(AdsTxtChange::SubdomainRemoved {
+                    subdomain: subdomain.clone(),
+                });
+            }
+        }
+        for domain in newer.inventory_partner_domains.iter() {
+            if !self.inventory_partner_domains.contains(domain) {
+                changes.push(AdsTxtChange::InventoryPartnerDomainAdded {
+                    domain: domain.clone(),
+                });
+            }
+        }
+        for domain in self.inventory_partner_domains.iter() {
+            if !newer.inventory_partner_domains.contains(domain) {
+                changes.push(AdsTxtChange::InventoryPartnerDomainRemoved {
+                    domain: domain.clone(),
+                });
+            }
+        }
+
+        let unknown_variables = pair_by_key(
+            &self.unknown_variables,
+            &newer.unknown_variables,
+            |(key, value)| (key.to_lowercase(), value.clone()),
+            |_, _| true,
+        );
+        for pair in unknown_variables {
+            changes.push(match pair {
+                (None, Some((key, value))) => AdsTxtChange::UnknownVariableAdded {
+                    key: key.clone(),
+                    value: value.clone(),
+                },
+                (Some((key, value)), _) => AdsTxtChange::UnknownVariableRemoved {
+                    key: key.clone(),
+                    value: value.clone(),
+                },
+                (None, None) => unreachable!(),
+            });
+        }
+
+        let manager_domains = pair_by_key(
+            &self.manager_domains,
+            &newer.manager_domains,
+            |m| m.domain.to_lowercase(),
+            |a, b| a.country_code == b.country_code,
+        );
+        for pair in manager_domains {
+            changes.push(match pair {
+                (Some(old), Some(new)) => AdsTxtChange::ManagerDomainCountryChanged {
+                    domain: new.domain.clone(),
+                    from: old.country_code,
+                    to: new.country_code,
+                },
+                (None, Some(new)) => AdsTxtChange::ManagerDomainAdded {
+                    manager_domain: new.clone(),
+                },
+                (Some(old), _) => AdsTxtChange::ManagerDomainRemoved {
+                    manager_domain: old.clone(),
+                },
+                (None, None) => unreachable!(),
+            });
+        }
+
+        let systems = pair_by_key(
+            &self.systems,
+            &newer.systems,
+            |s| (s.domain.to_lowercase(), s.publisher_id.to_lowercase()),
+            |a, b| a.relation == b.relation && same_cert_id(a, b),
+        );
+        for pair in systems {
+            match pair {
+                (Some(old), Some(new)) => {
+                    if old.relation != new.relation {
+                        changes.push(AdsTxtChange::RelationChanged {
+                            domain: new.domain.clone(),
+                            publisher_id: new.publisher_id.clone(),
+                            from: old.relation,
+                            to: new.relation,
+                        });
+                    }
+                    if !same_cert_id(old, new) {
+                        changes.push(AdsTxtChange::CertIdChanged {
+                            domain: new.domain.clone(),
+                            publisher_id: new.publisher_id.clone(),
+                            from: old.cert_id.clone(),
+                            to: new.cert_id.clone(),
+                        });
+                    }
+                }
+                (None, Some(new)) => changes.push(AdsTxtChange::RecordAdded {
+                    record: new.clone(),
+                }),
+                (Some(old), _) => changes.push(AdsTxtChange::RecordRemoved {
+                    record: old.clone(),
+                }),
+                (None, None) => unreachable!(),
+            }
+        }
+
+        AdsTxtDiff { changes }
+    }
+}
+
+fn same_cert_id(a: &AdsTxtSystem, b: &AdsTxtSystem) -> bool {
+    match (&a.cert_id, &b.cert_id) {
+        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+        (a, b) => a == b,
+    }
+}
+
+/// Pairs the entries of two lists grouped by `key`.
+///
+/// Entries that are `same` on both sides are dropped. Within a key, the remaining older and
+/// newer entries are paired in order; unpaired entries come with `None` on the other side.
+/// Keys are visited in order of first appearance.
+fn pair_by_key<'a, T, K: Hash + Eq>(
+    older: &'a [T],
+    newer: &'a [T],
+    key: impl Fn(&T) -> K,
+    same: impl Fn(&T, &T) -> bool,
+) -> Vec<(Option<&'a T>, Option<&'a T>)> {
+    // Groups the entries of both lists by key in a single pass, in order of first appearance.
+    let mut indexes: HashMap<K, usize> = HashMap::new();
+    let mut groups: Vec<(Vec<&T>, Vec<&T>)> = vec![];
+    for (entry, is_newer) in older
+        .iter()
+        .map(|e| (e, false))
+        .chain(newer.iter().map(|e| (e, true)))
+    {
+        let index = *indexes.entry(key(entry)).or_insert_with(|| {
+            groups.push((vec![], vec![]));
+            groups.len() - 1
+        });
+        let group = &mut groups[index];
+        match is_newer {
+            false => group.0.push(entry),
+            true => group.1.push(entry),
+        }
+    }
+    let mut pairs = vec![];
+    for (mut old, mut new) in groups {
+        new.retain(|n| match old.iter().position(|o| same(o, n)) {
+            Some(position) => {
+                old.remove(position);
+                false
+            }
+            None => true,
+        });
+        let paired = old.len().min(new.len());
+        pairs.extend(
+            old.iter()
+                .zip(new.iter())
+                .map(|(o, n)| (Some(*o), Some(*n))),
+        );
+        pairs.extend(new[paired..].iter().map(|n| (None, Some(*n))));
+        pairs.extend(old[paired..].iter().map(|o| (Some(*o), None)));
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn diff_identical_snapshots_is_empty() {
+        let content = "ownerdomain=example.com\ngreenadexchange.com, XF7342, DIRECT # one";
+        let older = AdsTxt::from_str(content).unwrap();
+        let newer =
+            AdsTxt::from_str("ownerdomain=example.com\ngreenadexchange.com, XF7342, DIRECT # two")
+                .unwrap();
+        assert!(older.diff(&newer).is_empty());
+    }
+
+    #[test]
+    fn diff_records() {
+        let older = AdsTxt::from_str(
+            "greenadexchange.com, XF7342, DIRECT, 5jyxf8k54
+redssp.com, 57013, RESELLER
+bluessp.com, 1, DIRECT",
+        )
+        .unwrap();
+        let newer = AdsTxt::from_str(
+            "greenadexchange.com, XF7342, RESELLER, f08c47fec0942fa0
+bluessp.com, 1, DIRECT
+silverssp.com, 9876, RESELLER",
+        )
+        .unwrap();
+        let diff = older.diff(&newer);
+        assert_eq!(diff.changes.len(), 4);
+        assert_eq!(
+            diff.changes[0],
+            AdsTxtChange::RelationChanged {
+                domain: "greenadexchange.com".to_string(),
+                publisher_id: "xf7342".to_string(),
+                from: SellerRelationType::Direct,
+                to: SellerRelationType::Reseller,
+            }
+        );
+        assert_eq!(
+            diff.changes[1],
+            AdsTxtChange::CertIdChanged {
+                domain: "greenadexchange.com".to_string(),
+                publisher_id: "xf7342".to_string(),
+                from: Some("5jyxf8k54".to_string()),
+                to: Some("f08c47fec0942fa0".to_string()),
+            }
+        );
+        assert_eq!(
+            diff.removed_records()
+                .map(|r| r.domain.as_str())
+                .collect::<Vec<_>>(),
+            vec!["redssp.com"]
+        );
+        assert_eq!(
+            diff.added_records()
+                .map(|r| r.domain.as_str())
+                .collect::<Vec<_>>(),
+            vec!["silverssp.com"]
+        );
+    }
+
+    #[test]
+    fn diff_same_account_declared_twice() {
+        let older = AdsTxt::from_str("redssp.com, 1, DIRECT\nredssp.com, 1, RESELLER").unwrap();
+        let newer = AdsTxt::from_str("redssp.com, 1, RESELLER").unwrap();
+        let diff = older.diff(&newer);
+        assert_eq!(diff.changes.len(), 1);
+        assert!(matches!(
+            &diff.changes[0],
+            AdsTxtChange::RecordRemoved { record } if record.relation == SellerRelationType::Direct
+        ));
+    }
+
+    #[test]
+    fn diff_variables_and_manager_domains() {
+        let older = AdsTxt::from_str(
+            "contact=adops@example.com
+subdomain=a.example.com
+managerdomain=manager.com, FR
+managerdomain=global.com",
+        )
+        .unwrap();
+        let newer = AdsTxt::from_str(
+            "ownerdomain=example.com
+subdomain=b.example.com
+inventorypartnerdomain=partner.com
+managerdomain=manager.com, DE",
+        )
+        .unwrap();
+        let diff = older.diff(&newer);
+        assert_eq!(
+            diff.changes,
+            vec![
+                AdsTxtChange::ContactChanged {
+                    from: Some("adops@example.com".to_string()),
+                    to: None,
+                },
+                AdsTxtChange::OwnerDomainChanged {
+                    from: None,
+                    to: Some("example.com".to_string()),
+                },
+                AdsTxtChange::SubdomainAdded {
+                    subdomain: "b.example.com".to_string(),
+                },
+                AdsTxtChange::SubdomainRemoved {
+                    subdomain: "a.example.com".to_string(),
+                },
+                AdsTxtChange::InventoryPartnerDomainAdded {
+                    domain: "partner.com".to_string(),
+                },
+                AdsTxtChange::ManagerDomainCountryChanged {
+                    domain: "manager.com".to_string(),
+                    from: Some(CountryCode::from_str("FR").unwrap()),
+                    to: Some(CountryCode::from_str("DE").unwrap()),
+                },
+                AdsTxtChange::ManagerDomainRemoved {
+                    manager_domain: ManagerDomain::from_str("global.com").unwrap(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_unknown_variables() {
+        let older = AdsTxt::from_str("FutureVar=One\nfuturevar=two\nother=x").unwrap();
+        let newer = AdsTxt::from_str("futurevar=One\nfuturevar=Two\nother=x").unwrap();
+        let diff = older.diff(&newer);
+        assert_eq!(
+            diff.changes,
+            vec![
+                AdsTxtChange::UnknownVariableRemoved {
+                    key: "futurevar".to_string(),
+                    value: "two".to_string(),
+                },
+                AdsTxtChange::UnknownVariableAdded {
+                    key: "futurevar".to_string(),
+                    value: "Two".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_large_snapshots() {
+        let older: Vec<String> = (0..20_000)
+            .map(|i| format!("ssp{}.com, {}, DIRECT", i % 100, i))
+            .collect();
+        let mut newer = older.clone();
+        newer[10] = "ssp10.com, 10, RESELLER".to_string();
+        newer.push("newssp.com, 1, DIRECT".to_string());
+        let older = AdsTxt::from_str(&older.join("\n")).unwrap();
+        let newer = AdsTxt::from_str(&newer.join("\n")).unwrap();
+        let diff = older.diff(&newer);
+        assert_eq!(diff.changes.len(), 2);
+        assert_eq!(diff.added_records().count(), 1);
+    }
+
+    #[test]
+    fn serialize_diff() {
+        let older = AdsTxt::from_str("redssp.com, 1, DIRECT").unwrap();
+        let newer = AdsTxt::from_str("redssp.com, 1, RESELLER\nmanagerdomain=m.com, FR").unwrap();
+        let diff = older.diff(&newer);
+        let json = serde_json::to_string(&diff).unwrap();
+        assert_eq!(
+            json,
+            r#"{"changes":[{"type":"manager_domain_added","manager_domain":"m.com,FR"},{"type":"relation_changed","domain":"redssp.com","publisher_id":"1","from":"direct","to":"reseller"}]}"#
+        );
+        let parsed: AdsTxtDiff = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, diff);
+    }
+}
@@ -0,0 +1,454 @@
+use crate::ads_txt_grammar::AdsTxtLines;
+use crate::{AdsTxt, AdsTxtDiagnosticCode, AdsTxtEntryRef, AdsTxtSystem};
+use serde::de::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::str::FromStr;
+
+/// What a single line of an [`AdsTxtDocument`] contains.
+#[derive(Clone, Debug)]
+pub enum AdsTxtLineKind {
+    /// An empty or whitespace-only line.
+    Blank,
+    /// A comment-only line.
+    Comment,
+    /// A `key=value` variable, with key and value trimmed and the trailing comment removed.
+    Variable { key: String, value: String },
+    /// A data record, with the casing of the file.
+    Record(AdsTxtSystem),
+    /// A line that is neither a variable nor a valid data record.
+    Invalid(AdsTxtDiagnosticCode),
+}
+
+/// A single line of an [`AdsTxtDocument`], keeping its original text.
+#[derive(Clone, Debug)]
+pub struct AdsTxtLine {
+    text: String,
+    line_ending: &'static str,
+    kind: AdsTxtLineKind,
+}
+
+impl AdsTxtLine {
+    fn parse(text: &str, line_ending: &'static str) -> Self {
+        let kind = match AdsTxtEntryRef::parse(text) {
+            None if text.trim().is_empty() => AdsTxtLineKind::Blank,
+            None => AdsTxtLineKind::Comment,
+            Some(Ok(AdsTxtEntryRef::Variable { key, value })) => AdsTxtLineKind::Variable {
+                key: key.to_string(),
+                value: value.to_string(),
+            },
+            Some(Ok(AdsTxtEntryRef::System(system))) => AdsTxtLineKind::Record(AdsTxtSystem {
+                domain: system.domain.to_string(),
+                publisher_id: system.publisher_id.to_string(),
+                relation: system.relation,
+                cert_id: system.cert_id.map(str::to_string),
+                comment: system.comment.map(str::to_string),
+            }),
+            Some(Err(_)) => AdsTxtLineKind::Invalid(AdsTxtSystem::diagnose(text.trim())),
+        };
+        AdsTxtLine {
+            text: text.to_string(),
+            line_ending,
+            kind,
+        }
+    }
+
+    /// The text of the line, as written, without its line ending.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The line ending terminating the line: `"\n"`, `"\r\n"`, `"\r"`, or `""` for a last
+    /// line without line ending.
+    pub fn line_ending(&self) -> &str {
+        self.line_ending
+    }
+
+    /// What the line contains.
+    pub fn kind(&self) -> &AdsTxtLineKind {
+        &self.kind
+    }
+}
+
+/// A lossless, line-oriented view of an ads.txt file.
+///
+/// [`AdsTxt`] is a semantic model: parsing lowercases values and `Display` writes variables
+/// first and drops comments. `AdsTxtDocument` keeps every line of the file, including blank
+/// lines, comments, invalid lines, original casing and line endings, so that a file can be
+/// edited programmatically and written back byte-for-byte identical except for the edited
+/// lines.
+#[derive(Clone, Debug, Default)]
+pub struct AdsTxtDocument {
+    lines: Vec<AdsTxtLine>,
+}
+
+impl AdsTxtDocument {
+    /// Parses `content`. Invalid lines are kept as [`AdsTxtLineKind::Invalid`].
+    pub fn parse(content: &str) -> Self {
+        let lines = AdsTxtLines::new(content)
+            .map(|(text, line_ending)| AdsTxtLine::parse(text, line_ending))
+            .collect();
+        AdsTxtDocument { lines }
+    }
+
+    /// All the lines of the document.
+    pub fn lines(&self) -> &[AdsTxtLine] {
+        &self.lines
+    }
+
+    /// Iterates over the data records with the index of their line.
+    ///
+    /// Unlike [`AdsTxt`], records keep the casing of the file.
+    pub fn records(&self) -> impl Iterator<Item = (usize, &AdsTxtSystem)> {
+        self.lines
+            .iter()
+            .enumerate()
+            .filter_map(|(index, line)| match &line.kind {
+                AdsTxtLineKind::Record(system) => Some((index, system)),
+                _ => None,
+            })
+    }
+
+    /// Replaces the data record at line `index`, keeping its line ending.
+    ///
+    /// Only the fields that differ from the current record are rewritten, the rest of the
+    /// line keeps its original text, spacing and casing.
+    ///
+    /// Fails if the line at `index` is not a data record.
+    pub fn replace_record(&mut self, index: usize, system: AdsTxtSystem) -> crate::Result<()> {
+        let line = self
+            .lines
+            .get_mut(index)
+            .ok_or_else(|| serde_plain::Error::custom(format!("no record at line {index}")))?;
+        let AdsTxtLineKind::Record(current) = &line.kind else {
+            return Err(serde_plain::Error::custom(format!("no record at line {index}")).into());
+        };
+        line.text = edit_record_line(&line.text, current, &system);
+        line.kind = AdsTxtLineKind::Record(system);
+        Ok(())
+    }
+
+    /// Inserts a data record before line `index`, or at the end of the document if `index`
+    /// is the number of lines.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of lines.
+    pub fn insert_record(&mut self, index: usize, system: AdsTxtSystem) {
+        if index == self.lines.len() {
+            return self.push_record(system);
+        }
+        let line_ending = self.default_line_ending();
+        self.lines.insert(
+            index,
+            AdsTxtLine {
+                text: record_line(&system),
+                line_ending,
+                kind: AdsTxtLineKind::Record(system),
+            },
+        );
+    }
+
+    /// Appends a data record at the end of the document.
+    pub fn push_record(&mut self, system: AdsTxtSystem) {
+        let line_ending = self.default_line_ending();
+        if let Some(last) = self.lines.last_mut().filter(|l| l.line_ending.is_empty()) {
+            last.line_ending = line_ending;
+        }
+        self.lines.push(AdsTxtLine {
+            text: record_line(&system),
+            line_ending,
+            kind: AdsTxtLineKind::Record(system),
+        });
+    }
+
+    /// Removes and returns the line at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_line(&mut self, index: usize) -> AdsTxtLine {
+        self.lines.remove(index)
+    }
+
+    /// Builds the semantic [`AdsTxt`] view of the document, skipping invalid lines.
+    pub fn to_ads_txt(&self) -> AdsTxt {
+        AdsTxt::parse_lenient(&self.to_string()).ads_txt
+    }
+
+    /// The line ending used by the first line that has one, `"\n"` otherwise.
+    fn default_line_ending(&self) -> &'static str {
+        self.lines
+            .iter()
+            .map(|l| l.line_ending)
+            .find(|e| !e.is_empty())
+            .unwrap_or("\n")
+    }
+}
+
+impl Display for AdsTxtDocument {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for line in &self.lines {
+            write!(f, "{}{}", line.text, line.line_ending)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for AdsTxtDocument {
+    type Err = crate::Error;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        Ok(AdsTxtDocument::parse(content))
+    }
+}
+
+/// Writes a data record as it was given, without the lowercasing done by
+/// `Display for AdsTxtSystem`.
+fn record_line(system: &AdsTxtSystem) -> String {
+    let mut line = format!(
+        "{}, {}, {}",
+        system.domain,
+        system.publisher_id,
+        system.relation.to_string().to_uppercase()
+    );
+    if let Some(cert_id) = &system.cert_id {
+        line = format!("{}, {}", line, cert_id);
+    }
+    if let Some(comment) = &system.comment {
+        line = format!("{} # {}", line, comment);
+    }
+    line
+}
+
+/// Rewrites the fields of the record `line` that differ between `current` and `system`.
+///
+/// Edits are applied from the end of the line, so that the byte ranges of the fields
+/// before them stay valid.
+fn edit_record_line(line: &str, current: &AdsTxtSystem, system: &AdsTxtSystem) -> String {
+    let data_end = line.find('#').unwrap_or(line.len());
+    let mut fields = vec![];
+    let mut start = 0;
+    for field in line[..data_end].split(',') {
+        fields.push(trimmed_range(line, start, start + field.len()));
+        start += field.len() + 1;
+    }
+    let relation_end = fields[2].end;
+    let last_end = match &current.cert_id {
+        Some(_) => fields[3].end,
+        None => relation_end,
+    };
+
+    let mut text = line.to_string();
+    if current.comment != system.comment {
+        match (&current.comment, &system.comment) {
+            (Some(_), Some(comment)) => {
+                text.replace_range(trimmed_range(line, data_end + 1, line.len()), comment)
+            }
+            (Some(_), None) => text.replace_range(last_end..line.len(), ""),
+            (None, Some(comment)) if data_end < line.len() => {
+                text.replace_range(data_end..line.len(), &format!("# {}", comment))
+            }
+            (None, Some(comment)) => text.insert_str(last_end, &format!(" # {}", comment)),
+            (None, None) => {}
+        }
+    }
+    if current.cert_id != system.cert_id {
+        match (&current.cert_id, &system.cert_id) {
+            (Some(_), Some(cert_id)) => text.replace_range(fields[3].clone(), cert_id),
+            (Some(_), None) => text.replace_range(relation_end..fields[3].end, ""),
+            (None, Some(cert_id)) => text.insert_str(relation_end, &format!(", {}", cert_id)),
+            (None, None) => {}
+        }
+    }
+    if current.relation != system.relation {
+        let relation = system.relation.to_string().to_uppercase();
+        text.replace_range(fields[2].clone(), &relation);
+    }
+    if current.publisher_id != system.publisher_id {
+        text.replace_range(fields[1].clone(), &system.publisher_id);
+    }
+    if current.domain != system.domain {
+        text.replace_range(fields[0].clone(), &system.domain);
+    }
+    text
+}
+
+/// The byte range of `line[start..end]` without its surrounding whitespace.
+fn trimmed_range(line: &str, start: usize, end: usize) -> Range<usize> {
+    let field = &line[start..end];
+    let start = start + (field.len() - field.trim_start().len());
+    start..start + field.trim().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SellerRelationType;
+
+    const CONTENT: &str = "# Ads.txt for Example.com\r\nCONTACT=AdOps@Example.com\r\nownerdomain=Example.com # owner\r\n\r\n# Exchanges\r\nGreenAdExchange.com, XF7342, DIRECT, 5jyxf8k54 # Main Account\r\nredssp.com, 57013, RESELLER\r\nnot a valid line\r\n  silverssp.com ,9876, Reseller  ";
+
+    #[test]
+    fn roundtrip_is_byte_identical() {
+        let document = AdsTxtDocument::parse(CONTENT);
+        assert_eq!(document.to_string(), CONTENT);
+        assert_eq!(document.lines().len(), 9);
+        assert_eq!(document.lines()[0].line_ending(), "\r\n");
+        assert_eq!(document.lines()[8].line_ending(), "");
+
+        let document = AdsTxtDocument::from_str("").unwrap();
+        assert!(document.lines().is_empty());
+        assert_eq!(document.to_string(), "");
+    }
+
+    #[test]
+    fn roundtrip_cr_only_line_endings() {
+        let content = "contact=a@example.com\rredssp.com, 1, DIRECT\r";
+        let document = AdsTxtDocument::parse(content);
+        assert_eq!(document.lines().len(), 2);
+        assert_eq!(document.lines()[0].line_ending(), "\r");
+        assert_eq!(document.records().count(), 1);
+        assert_eq!(document.to_string(), content);
+    }
+
+    #[test]
+    fn classify_lines() {
+        let document = AdsTxtDocument::parse(CONTENT);
+        let lines = document.lines();
+        assert!(matches!(lines[0].kind(), AdsTxtLineKind::Comment));
+        assert!(
+            matches!(lines[1].kind(), AdsTxtLineKind::Variable { key, value } if key == "CONTACT" && value == "AdOps@Example.com")
+        );
+        assert!(
+            matches!(lines[2].kind(), AdsTxtLineKind::Variable { key, value } if key == "ownerdomain" && value == "Example.com")
+        );
+        assert!(matches!(lines[3].kind(), AdsTxtLineKind::Blank));
+        assert!(matches!(lines[5].kind(), AdsTxtLineKind::Record(_)));
+        assert!(matches!(
+            lines[7].kind(),
+            AdsTxtLineKind::Invalid(AdsTxtDiagnosticCode::MissingField)
+        ));
+        assert_eq!(document.records().count(), 3);
+    }
+
+    #[test]
+    fn replace_record_keeps_other_lines() {
+        let mut document = AdsTxtDocument::parse(CONTENT);
+        let (index, record) = document
+            .records()
+            .find(|(_, r)| r.domain == "redssp.com")
+            .map(|(i, r)| (i, r.clone()))
+            .unwrap();
+        let mut record = record;
+        record.relation = SellerRelationType::Direct;
+        record.cert_id = Some("F08C47FEC0942FA0".to_string());
+        document.replace_record(index, record).unwrap();
+
+        let expected = CONTENT.replace(
+            "redssp.com, 57013, RESELLER\r\n",
+            "redssp.com, 57013, DIRECT, F08C47FEC0942FA0\r\n",
+        );
+        assert_eq!(document.to_string(), expected);
+
+        assert!(
+            document
+                .replace_record(0, AdsTxtSystem::from_str("a.com, 1, DIRECT").unwrap())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn replace_record_only_rewrites_changed_fields() {
+        let content = "Example.com,Pub-ABC ,  Direct, F08C47FEC0942FA0 # Keep\n";
+        let mut document = AdsTxtDocument::parse(content);
+        let (index, record) = document.records().next().unwrap();
+        assert_eq!(record.domain, "Example.com");
+        assert_eq!(record.comment.as_deref(), Some("Keep"));
+
+        let mut record = record.clone();
+        record.relation = SellerRelationType::Reseller;
+        document.replace_record(index, record.clone()).unwrap();
+        assert_eq!(
+            document.to_string(),
+            "Example.com,Pub-ABC ,  RESELLER, F08C47FEC0942FA0 # Keep\n"
+        );
+
+        record.cert_id = None;
+        record.comment = Some("Moved".to_string());
+        document.replace_record(index, record.clone()).unwrap();
+        assert_eq!(
+            document.to_string(),
+            "Example.com,Pub-ABC ,  RESELLER # Moved\n"
+        );
+
+        record.comment = None;
+        record.cert_id = Some("TAG-1".to_string());
+        record.publisher_id = "Pub-XYZ".to_string();
+        document.replace_record(index, record).unwrap();
+        assert_eq!(
+            document.to_string(),
+            "Example.com,Pub-XYZ ,  RESELLER, TAG-1\n"
+        );
+        assert_eq!(
+            document.to_ads_txt().systems[0].to_string(),
+            "example.com,pub-xyz,reseller,tag-1"
+        );
+    }
+
+    #[test]
+    fn insert_record_at_end_without_line_ending() {
+        let mut document = AdsTxtDocument::parse("redssp.com, 1, DIRECT");
+        let record = AdsTxtSystem::from_str("ssp.com, 1, RESELLER").unwrap();
+        document.insert_record(document.lines().len(), record);
+        assert_eq!(
+            document.to_string(),
+            "redssp.com, 1, DIRECT\nssp.com, 1, RESELLER\n"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_record_out_of_bounds() {
+        let mut document = AdsTxtDocument::parse("redssp.com, 1, DIRECT");
+        document.insert_record(2, AdsTxtSystem::from_str("ssp.com, 1, RESELLER").unwrap());
+    }
+
+    #[test]
+    fn insert_push_and_remove_lines() {
+        let mut document = AdsTxtDocument::parse("# header\nredssp.com, 1, DIRECT");
+        document.push_record(
+            AdsTxtSystem::builder()
+                .domain("BlueSSP.com")
+                .publisher_id("Pub-1")
+                .relation(SellerRelationType::Reseller)
+                .comment(Some("Added".to_string()))
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            document.to_string(),
+            "# header\nredssp.com, 1, DIRECT\nBlueSSP.com, Pub-1, RESELLER # Added\n"
+        );
+
+        document.insert_record(
+            1,
+            AdsTxtSystem::from_str("greenssp.com, 2, DIRECT").unwrap(),
+        );
+        let removed = document.remove_line(2);
+        assert_eq!(removed.text(), "redssp.com, 1, DIRECT");
+        assert_eq!(
+            document.to_string(),
+            "# header\ngreenssp.com, 2, DIRECT\nBlueSSP.com, Pub-1, RESELLER # Added\n"
+        );
+    }
+
+    #[test]
+    fn semantic_view() {
+        let document = AdsTxtDocument::parse(CONTENT);
+        let ads_txt = document.to_ads_txt();
+        assert_eq!(ads_txt.owner_domain.as_deref(), Some("example.com"));
+        assert_eq!(ads_txt.systems.len(), 3);
+        assert_eq!(ads_txt.systems[0].domain, "greenadexchange.com");
+    }
+}
@@ -0,0 +1,435 @@
+use crate::{AdsTxtEntryRef, AdsTxtSystemRef};
+use iab_specs_core::slice_up_to;
+use serde::de::Error;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::io::BufRead;
+use std::iter::Enumerate;
+
+/// A variable defined by the ads.txt family of specifications.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum AdsTxtVariable {
+    /// `CONTACT`, human readable contact information.
+    Contact,
+    /// `SUBDOMAIN`, pointer to a subdomain file.
+    Subdomain,
+    /// `INVENTORYPARTNERDOMAIN`, pointer to the file of an inventory partner.
+    InventoryPartnerDomain,
+    /// `OWNERDOMAIN`, business domain of the owner of the inventory.
+    OwnerDomain,
+    /// `MANAGERDOMAIN`, business domain of a monetization partner.
+    ManagerDomain,
+}
+
+impl AdsTxtVariable {
+    /// Every variable, in the order files usually declare them.
+    pub const ALL: [AdsTxtVariable; 5] = [
+        AdsTxtVariable::Contact,
+        AdsTxtVariable::Subdomain,
+        AdsTxtVariable::InventoryPartnerDomain,
+        AdsTxtVariable::OwnerDomain,
+        AdsTxtVariable::ManagerDomain,
+    ];
+
+    /// The key of the variable, lowercased.
+    pub fn key(&self) -> &'static str {
+        match self {
+            AdsTxtVariable::Contact => "contact",
+            AdsTxtVariable::Subdomain => "subdomain",
+            AdsTxtVariable::InventoryPartnerDomain => "inventorypartnerdomain",
+            AdsTxtVariable::OwnerDomain => "ownerdomain",
+            AdsTxtVariable::ManagerDomain => "managerdomain",
+        }
+    }
+
+    /// The variable named `key`. Keys are case-insensitive.
+    pub fn from_key(key: &str) -> Option<AdsTxtVariable> {
+        AdsTxtVariable::ALL
+            .into_iter()
+            .find(|variable| variable.key().eq_ignore_ascii_case(key))
+    }
+
+    /// The specification that introduced the variable.
+    pub fn introduced_in(&self) -> &'static str {
+        match self {
+            AdsTxtVariable::Contact
+            | AdsTxtVariable::Subdomain
+            | AdsTxtVariable::InventoryPartnerDomain => "ads.txt 1.0",
+            AdsTxtVariable::OwnerDomain | AdsTxtVariable::ManagerDomain => "ads.txt 1.1",
+        }
+    }
+}
+
+/// A line of an ads.txt-family file, interpreted by an [`AdsTxtGrammar`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdsTxtDirective<'a> {
+    /// A variable allowed by the grammar. The value is trimmed, without its trailing comment.
+    Variable {
+        variable: AdsTxtVariable,
+        value: &'a str,
+    },
+    /// A variable unknown to the grammar, only produced by grammars keeping unknown variables.
+    UnknownVariable { key: &'a str, value: &'a str },
+    /// A data record.
+    Record(AdsTxtSystemRef<'a>),
+}
+
+/// A non-blank, non-comment line of an ads.txt-family file.
+#[derive(Debug)]
+pub struct AdsTxtToken<'a> {
+    /// 1-based line number.
+    pub line_number: usize,
+
+    /// The trimmed text of the line.
+    pub line: &'a str,
+
+    /// The interpretation of the line, or why it is invalid.
+    pub directive: crate::Result<AdsTxtDirective<'a>>,
+}
+
+/// The grammar shared by ads.txt, app-ads.txt and similar files.
+///
+/// Every variant uses the same line syntax: blank lines, `#` comments, `key=value` variables
+/// and comma separated data records, with CR, LF and CRLF line endings and case-insensitive
+/// variable keys. Variants differ by the variables they allow and by how they treat unknown
+/// ones, which a grammar captures so that future variants can reuse the tokeniser:
+///
+/// ```
+/// use iab_specs_ads_txt::{AdsTxtDirective, AdsTxtGrammar, AdsTxtVariable};
+///
+/// const CTV_ADS_TXT: AdsTxtGrammar =
+///     AdsTxtGrammar::new("ctv-ads.txt", &[AdsTxtVariable::Contact], false);
+///
+/// let mut tokens = CTV_ADS_TXT.tokenize("CONTACT=ops@example.com\r\nownerdomain=example.com");
+/// assert!(matches!(
+///     tokens.next().unwrap().directive,
+///     Ok(AdsTxtDirective::Variable { variable: AdsTxtVariable::Contact, .. })
+/// ));
+/// assert!(tokens.next().unwrap().directive.is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AdsTxtGrammar {
+    name: &'static str,
+    variables: &'static [AdsTxtVariable],
+    keep_unknown_variables: bool,
+}
+
+impl AdsTxtGrammar {
+    /// ads.txt 1.1. Unknown variables are kept, as the specification asks crawlers to ignore
+    /// them.
+    pub const ADS_TXT_1_1: AdsTxtGrammar =
+        AdsTxtGrammar::new("ads.txt 1.1", &AdsTxtVariable::ALL, true);
+
+    /// app-ads.txt 1.0, without the ads.txt 1.1 variables. Unknown variables are kept, as in
+    /// ads.txt.
+    pub const APP_ADS_TXT_1_0: AdsTxtGrammar = AdsTxtGrammar::new(
+        "app-ads.txt v1.0",
+        &[
+            AdsTxtVariable::Contact,
+            AdsTxtVariable::Subdomain,
+            AdsTxtVariable::InventoryPartnerDomain,
+        ],
+        true,
+    );
+
+    /// app-ads.txt aligned with ads.txt 1.1. Unknown variables are kept, as in ads.txt.
+    pub const APP_ADS_TXT_1_1: AdsTxtGrammar =
+        AdsTxtGrammar::new("app-ads.txt v1.1", &AdsTxtVariable::ALL, true);
+
+    /// Creates a grammar named `name`, used in error messages, allowing `variables`.
+    ///
+    /// Unknown variables are reported as [`AdsTxtDirective::UnknownVariable`] if
+    /// `keep_unknown_variables` is set, and as errors otherwise.
+    pub const fn new(
+        name: &'static str,
+        variables: &'static [AdsTxtVariable],
+        keep_unknown_variables: bool,
+    ) -> Self {
+        AdsTxtGrammar {
+            name,
+            variables,
+            keep_unknown_variables,
+        }
+    }
+
+    /// The name of the grammar.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The variables allowed by the grammar.
+    pub fn variables(&self) -> &'static [AdsTxtVariable] {
+        self.variables
+    }
+
+    /// Iterates over the non-blank, non-comment lines of `content`.
+    pub fn tokenize<'a>(&self, content: &'a str) -> AdsTxtTokens<'a> {
+        AdsTxtTokens {
+            grammar: *self,
+            lines: AdsTxtLines::new(content).enumerate(),
+        }
+    }
+
+    /// Interprets a single line, returning `None` for blank and comment-only lines.
+    pub(crate) fn token<'a>(&self, line_number: usize, line: &'a str) -> Option<AdsTxtToken<'a>> {
+        AdsTxtEntryRef::parse(line).map(|entry| AdsTxtToken {
+            line_number,
+            line: line.trim(),
+            directive: entry.and_then(|entry| self.directive(entry)),
+        })
+    }
+
+    /// Interprets a line that is neither blank nor a comment.
+    fn directive<'a>(&self, entry: AdsTxtEntryRef<'a>) -> crate::Result<AdsTxtDirective<'a>> {
+        let (key, value) = match entry {
+            AdsTxtEntryRef::System(system) => return Ok(AdsTxtDirective::Record(system)),
+            AdsTxtEntryRef::Variable { key, value } => (key, value),
+        };
+        match AdsTxtVariable::from_key(key) {
+            Some(variable) if self.variables.contains(&variable) => {
+                Ok(AdsTxtDirective::Variable { variable, value })
+            }
+            Some(variable) => Err(serde_plain::Error::custom(format!(
+                "{} is not supported in {} (added in {})",
+                variable.key().to_uppercase(),
+                self.name,
+                variable.introduced_in()
+            ))
+            .into()),
+            None if self.keep_unknown_variables => {
+                Ok(AdsTxtDirective::UnknownVariable { key, value })
+            }
+            None => Err(self.unknown_variable(key)),
+        }
+    }
+
+    /// The error reported for the unknown variable `key`, or the warning of lenient parsers.
+    pub fn unknown_variable(&self, key: &str) -> crate::Error {
+        let expected: Vec<String> = self
+            .variables
+            .iter()
+            .map(|v| format!("`{}`", v.key()))
+            .collect();
+        let key: &str = slice_up_to!(key, 100);
+        serde_plain::Error::custom(format!(
+            "unknown field `{}`, expected one of {}",
+            key,
+            expected.join(", ")
+        ))
+        .into()
+    }
+}
+
+/// Iterator over the tokens of a file, created by [`AdsTxtGrammar::tokenize`].
+#[derive(Clone, Debug)]
+pub struct AdsTxtTokens<'a> {
+    grammar: AdsTxtGrammar,
+    lines: Enumerate<AdsTxtLines<'a>>,
+}
+
+impl<'a> Iterator for AdsTxtTokens<'a> {
+    type Item = AdsTxtToken<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, (line, _)) in self.lines.by_ref() {
+            if let Some(token) = self.grammar.token(index + 1, line) {
+                return Some(token);
+            }
+        }
+        None
+    }
+}
+
+/// Splits a file into lines, yielding each line with its line ending.
+///
+/// The implementer notes ask parsers to liberally interpret CR, LF and CRLF as line
+/// separators.
+#[derive(Clone, Debug)]
+pub(crate) struct AdsTxtLines<'a> {
+    remaining: &'a str,
+}
+
+impl<'a> AdsTxtLines<'a> {
+    pub(crate) fn new(content: &'a str) -> Self {
+        AdsTxtLines { remaining: content }
+    }
+}
+
+impl<'a> Iterator for AdsTxtLines<'a> {
+    type Item = (&'a str, &'static str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+        let (line, line_ending, rest) = match self.remaining.find(['\r', '\n']) {
+            Some(idx) => {
+                let line_ending = line_ending(&self.remaining.as_bytes()[idx..]);
+                (
+                    &self.remaining[..idx],
+                    line_ending,
+                    &self.remaining[idx + line_ending.len()..],
+                )
+            }
+            None => (self.remaining, "", ""),
+        };
+        self.remaining = rest;
+        Some((line, line_ending))
+    }
+}
+
+/// The line ending at the start of `separator`, which starts with CR or LF.
+fn line_ending(separator: &[u8]) -> &'static str {
+    match separator {
+        [b'\r', b'\n', ..] => "\r\n",
+        [b'\r', ..] => "\r",
+        _ => "\n",
+    }
+}
+
+/// Reads the next line of `reader` into `line`, without its line ending, splitting lines as
+/// [`AdsTxtLines`] does.
+///
+/// Returns `false` at the end of the input.
+pub(crate) fn read_line<R: BufRead>(reader: &mut R, line: &mut Vec<u8>) -> io::Result<bool> {
+    let mut read = false;
+    loop {
+        let available = match reader.fill_buf() {
+            Ok(available) => available,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        if available.is_empty() {
+            return Ok(read);
+        }
+        read = true;
+        let Some(idx) = available.iter().position(|b| matches!(b, b'\r' | b'\n')) else {
+            line.extend_from_slice(available);
+            let len = available.len();
+            reader.consume(len);
+            continue;
+        };
+        line.extend_from_slice(&available[..idx]);
+        let line_ending = line_ending(&available[idx..]);
+        reader.consume(idx + line_ending.len());
+        // A CR ending the buffered data may be followed by the LF of a CRLF.
+        if line_ending == "\r" && reader.fill_buf()?.first() == Some(&b'\n') {
+            reader.consume(1);
+        }
+        return Ok(true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_lines_on_any_line_ending() {
+        let lines: Vec<_> = AdsTxtLines::new("a\r\nb\rc\n\nd").collect();
+        assert_eq!(
+            lines,
+            vec![
+                ("a", "\r\n"),
+                ("b", "\r"),
+                ("c", "\n"),
+                ("", "\n"),
+                ("d", "")
+            ]
+        );
+        assert_eq!(AdsTxtLines::new("").count(), 0);
+        assert_eq!(
+            AdsTxtLines::new("a\n").collect::<Vec<_>>(),
+            vec![("a", "\n")]
+        );
+    }
+
+    #[test]
+    fn read_lines_on_any_line_ending() {
+        // A one-byte buffer splits every CRLF across two reads.
+        let mut reader = io::BufReader::with_capacity(1, "a\r\nb\rc\n\nd".as_bytes());
+        let mut lines = vec![];
+        let mut line = vec![];
+        while read_line(&mut reader, &mut line).unwrap() {
+            lines.push(String::from_utf8(std::mem::take(&mut line)).unwrap());
+        }
+        assert_eq!(lines, vec!["a", "b", "c", "", "d"]);
+    }
+
+    #[test]
+    fn variable_keys_are_case_insensitive() {
+        assert_eq!(
+            AdsTxtVariable::from_key("InventoryPartnerDomain"),
+            Some(AdsTxtVariable::InventoryPartnerDomain)
+        );
+        assert_eq!(AdsTxtVariable::from_key("subodmain"), None);
+        for variable in AdsTxtVariable::ALL {
+            assert_eq!(
+                AdsTxtVariable::from_key(&variable.key().to_uppercase()),
+                Some(variable)
+            );
+        }
+    }
+
+    #[test]
+    fn tokenize_ads_txt() {
+        let content = "# comment\rCONTACT=Ops@Example.com # ops\r\nfoo=bar\n\ngreenadexchange.com, XF7342, DIRECT\nnot a record";
+        let tokens: Vec<_> = AdsTxtGrammar::ADS_TXT_1_1.tokenize(content).collect();
+        assert_eq!(tokens.len(), 4);
+        assert_eq!(tokens[0].line_number, 2);
+        assert_eq!(
+            tokens[0].directive.as_ref().unwrap(),
+            &AdsTxtDirective::Variable {
+                variable: AdsTxtVariable::Contact,
+                value: "Ops@Example.com"
+            }
+        );
+        assert_eq!(
+            tokens[1].directive.as_ref().unwrap(),
+            &AdsTxtDirective::UnknownVariable {
+                key: "foo",
+                value: "bar"
+            }
+        );
+        assert_eq!(tokens[2].line_number, 5);
+        assert!(matches!(
+            tokens[2].directive,
+            Ok(AdsTxtDirective::Record(_))
+        ));
+        assert_eq!(tokens[3].line, "not a record");
+        assert!(tokens[3].directive.is_err());
+    }
+
+    #[test]
+    fn tokenize_app_ads_txt() {
+        let mut tokens =
+            AdsTxtGrammar::APP_ADS_TXT_1_0.tokenize("OwnerDomain=example.com\nfoo=bar");
+        assert_eq!(
+            tokens.next().unwrap().directive.unwrap_err().to_string(),
+            "OWNERDOMAIN is not supported in app-ads.txt v1.0 (added in ads.txt 1.1)"
+        );
+        assert_eq!(
+            tokens.next().unwrap().directive.unwrap(),
+            AdsTxtDirective::UnknownVariable {
+                key: "foo",
+                value: "bar"
+            }
+        );
+        assert_eq!(
+            AdsTxtGrammar::APP_ADS_TXT_1_0
+                .unknown_variable("foo")
+                .to_string(),
+            "unknown field `foo`, expected one of `contact`, `subdomain`, `inventorypartnerdomain`"
+        );
+
+        let mut tokens = AdsTxtGrammar::APP_ADS_TXT_1_1.tokenize("OwnerDomain=example.com");
+        assert!(matches!(
+            tokens.next().unwrap().directive,
+            Ok(AdsTxtDirective::Variable {
+                variable: AdsTxtVariable::OwnerDomain,
+                ..
+            })
+        ));
+    }
+}
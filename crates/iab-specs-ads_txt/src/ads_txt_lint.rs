@@ -0,0 +1,432 @@
+use crate::ads_txt_resolver::is_subdomain_of;
+use crate::{AdsTxt, SellerRelationType};
+use iab_specs_core::{is_email_address, is_host_name};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// How serious an [`AdsTxtLintFinding`] is.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum AdsTxtLintSeverity {
+    /// A suggestion, the file is correct.
+    Info,
+    /// The file does not follow best practices.
+    Warning,
+    /// The file does not follow the specification.
+    Error,
+}
+
+/// A best-practice rule checked by [`AdsTxtLinter`].
+///
+/// Rules are serialised as their stable [`AdsTxtLintRule::id`].
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "kebab-case")]
+pub enum AdsTxtLintRule {
+    /// The same record is declared more than once.
+    DuplicateRecord,
+    /// The same account is declared both DIRECT and RESELLER.
+    ConflictingRelation,
+    /// MANAGERDOMAIN is declared without OWNERDOMAIN.
+    ManagerDomainWithoutOwnerDomain,
+    /// More than one MANAGERDOMAIN is declared without country code.
+    MultipleGlobalManagerDomains,
+    /// More than one MANAGERDOMAIN is declared for the same country.
+    MultipleCountryManagerDomains,
+    /// CONTACT is neither an email address, a URL nor a phone number.
+    InvalidContact,
+    /// A SUBDOMAIN is not a subdomain of the domain publishing the file.
+    InvalidSubdomain,
+}
+
+impl AdsTxtLintRule {
+    /// Every rule, in catalogue order.
+    pub const ALL: [AdsTxtLintRule; 7] = [
+        AdsTxtLintRule::DuplicateRecord,
+        AdsTxtLintRule::ConflictingRelation,
+        AdsTxtLintRule::ManagerDomainWithoutOwnerDomain,
+        AdsTxtLintRule::MultipleGlobalManagerDomains,
+        AdsTxtLintRule::MultipleCountryManagerDomains,
+        AdsTxtLintRule::InvalidContact,
+        AdsTxtLintRule::InvalidSubdomain,
+    ];
+
+    /// The stable identifier of the rule.
+    pub fn id(&self) -> &'static str {
+        match self {
+            AdsTxtLintRule::DuplicateRecord => "duplicate-record",
+            AdsTxtLintRule::ConflictingRelation => "conflicting-relation",
+            AdsTxtLintRule::ManagerDomainWithoutOwnerDomain => {
+                "manager-domain-without-owner-domain"
+            }
+            AdsTxtLintRule::MultipleGlobalManagerDomains => "multiple-global-manager-domains",
+            AdsTxtLintRule::MultipleCountryManagerDomains => "multiple-country-manager-domains",
+            AdsTxtLintRule::InvalidContact => "invalid-contact",
+            AdsTxtLintRule::InvalidSubdomain => "invalid-subdomain",
+        }
+    }
+
+    /// The severity of the rule unless overridden with [`AdsTxtLinter::with_severity`].
+    pub fn default_severity(&self) -> AdsTxtLintSeverity {
+        match self {
+            AdsTxtLintRule::DuplicateRecord => AdsTxtLintSeverity::Info,
+            AdsTxtLintRule::ConflictingRelation => AdsTxtLintSeverity::Warning,
+            AdsTxtLintRule::ManagerDomainWithoutOwnerDomain => AdsTxtLintSeverity::Warning,
+            AdsTxtLintRule::MultipleGlobalManagerDomains => AdsTxtLintSeverity::Error,
+            AdsTxtLintRule::MultipleCountryManagerDomains => AdsTxtLintSeverity::Error,
+            AdsTxtLintRule::InvalidContact => AdsTxtLintSeverity::Warning,
+            AdsTxtLintRule::InvalidSubdomain => AdsTxtLintSeverity::Error,
+        }
+    }
+}
+
+/// A problem reported by [`AdsTxtLinter`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AdsTxtLintFinding {
+    /// The rule that reported the finding.
+    pub rule: AdsTxtLintRule,
+
+    /// Severity of the finding.
+    pub severity: AdsTxtLintSeverity,
+
+    /// Human readable description of the finding.
+    pub message: String,
+}
+
+/// Findings of an [`AdsTxtLinter`] run, in catalogue order.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AdsTxtLintReport {
+    /// Every finding, grouped by rule.
+    pub findings: Vec<AdsTxtLintFinding>,
+}
+
+impl AdsTxtLintReport {
+    /// Returns `true` if no rule reported anything.
+    pub fn is_empty(&self) -> bool {
+        self.findings.is_empty()
+    }
+
+    /// Returns `true` if at least one finding has the [`AdsTxtLintSeverity::Error`] severity.
+    pub fn has_errors(&self) -> bool {
+        self.findings
+            .iter()
+            .any(|f| f.severity == AdsTxtLintSeverity::Error)
+    }
+
+    /// Iterates over the findings reported by `rule`.
+    pub fn by_rule(&self, rule: AdsTxtLintRule) -> impl Iterator<Item = &AdsTxtLintFinding> {
+        self.findings.iter().filter(move |f| f.rule == rule)
+    }
+}
+
+/// Checks an [`AdsTxt`] against the best-practice rules of [`AdsTxtLintRule`].
+///
+/// Every rule is enabled with its default severity. Rules can be turned off with
+/// [`AdsTxtLinter::disable`] and their severity changed with [`AdsTxtLinter::with_severity`].
+#[derive(Clone, Debug, Default)]
+pub struct AdsTxtLinter {
+    disabled: BTreeSet<AdsTxtLintRule>,
+    severities: BTreeMap<AdsTxtLintRule, AdsTxtLintSeverity>,
+}
+
+impl AdsTxtLinter {
+    /// Creates a linter with every rule enabled.
+    pub fn new() -> Self {
+        AdsTxtLinter::default()
+    }
+
+    /// Turns `rule` off.
+    pub fn disable(mut self, rule: AdsTxtLintRule) -> Self {
+        self.disabled.insert(rule);
+        self
+    }
+
+    /// Reports the findings of `rule` with `severity`.
+    pub fn with_severity(mut self, rule: AdsTxtLintRule, severity: AdsTxtLintSeverity) -> Self {
+        self.severities.insert(rule, severity);
+        self
+    }
+
+    /// Lints `ads_txt`.
+    ///
+    /// SUBDOMAIN values are checked against OWNERDOMAIN when it is declared. Use
+    /// [`AdsTxtLinter::lint_for_domain`] to check them against the domain the file was
+    /// fetched from.
+    pub fn lint(&self, ads_txt: &AdsTxt) -> AdsTxtLintReport {
+        self.run(ads_txt, ads_txt.owner_domain.as_deref())
+    }
+
+    /// Lints `ads_txt`, published on `domain`.
+    pub fn lint_for_domain(&self, ads_txt: &AdsTxt, domain: &str) -> AdsTxtLintReport {
+        self.run(ads_txt, Some(&domain.to_lowercase()))
+    }
+
+    fn run(&self, ads_txt: &AdsTxt, domain: Option<&str>) -> AdsTxtLintReport {
+        let mut report = AdsTxtLintReport::default();
+        for rule in AdsTxtLintRule::ALL {
+            if self.disabled.contains(&rule) {
+                continue;
+            }
+            let severity = self
+                .severities
+                .get(&rule)
+                .copied()
+                .unwrap_or_else(|| rule.default_severity());
+            report
+                .findings
+                .extend(check(rule, ads_txt, domain).into_iter().map(|message| {
+                    AdsTxtLintFinding {
+                        rule,
+                        severity,
+                        message,
+                    }
+                }));
+        }
+        report
+    }
+}
+
+/// Runs a single rule, returning the message of each finding.
+fn check(rule: AdsTxtLintRule, ads_txt: &AdsTxt, domain: Option<&str>) -> Vec<String> {
+    let mut messages = vec![];
+    match rule {
+        AdsTxtLintRule::DuplicateRecord => {
+            let mut seen = BTreeSet::new();
+            for system in &ads_txt.systems {
+                let key = (&system.domain, &system.publisher_id, system.relation);
+                if !seen.insert(key) {
+                    messages.push(format!(
+                        "{}, {}, {} is declared more than once",
+                        system.domain, system.publisher_id, system.relation
+                    ));
+                }
+            }
+        }
+        AdsTxtLintRule::ConflictingRelation => {
+            let mut relations: BTreeMap<(&str, &str), BTreeSet<SellerRelationType>> =
+                BTreeMap::new();
+            for system in &ads_txt.systems {
+                relations
+                    .entry((&system.domain, &system.publisher_id))
+                    .or_default()
+                    .insert(system.relation);
+            }
+            for ((domain, publisher_id), relations) in relations {
+                if relations.len() > 1 {
+                    messages.push(format!(
+                        "{}, {} is declared both DIRECT and RESELLER",
+                        domain, publisher_id
+                    ));
+                }
+            }
+        }
+        AdsTxtLintRule::ManagerDomainWithoutOwnerDomain => {
+            if !ads_txt.manager_domains.is_empty() && ads_txt.owner_domain.is_none() {
+                messages.push("MANAGERDOMAIN is declared without OWNERDOMAIN".to_string());
+            }
+        }
+        AdsTxtLintRule::MultipleGlobalManagerDomains => {
+            let global: BTreeSet<&str> = ads_txt
+                .manager_domains
+                .iter()
+                .filter(|m| m.country_code.is_none())
+                .map(|m| m.domain.as_str())
+                .collect();
+            if global.len() > 1 {
+                messages.push(format!(
+                    "{} MANAGERDOMAIN are declared without country code: {}",
+                    global.len(),
+                    global.into_iter().collect::<Vec<_>>().join(", ")
+                ));
+            }
+        }
+        AdsTxtLintRule::MultipleCountryManagerDomains => {
+            let mut by_country: BTreeMap<String, BTreeSet<&str>> = BTreeMap::new();
+            for manager_domain in &ads_txt.manager_domains {
+                if let Some(country_code) = manager_domain.country_code {
+                    by_country
+                        .entry(country_code.to_string())
+                        .or_default()
+                        .insert(&manager_domain.domain);
+                }
+            }
+            for (country_code, domains) in by_country {
+                if domains.len() > 1 {
+                    messages.push(format!(
+                        "{} MANAGERDOMAIN are declared for {}: {}",
+                        domains.len(),
+                        country_code,
+                        domains.into_iter().collect::<Vec<_>>().join(", ")
+                    ));
+                }
+            }
+        }
+        AdsTxtLintRule::InvalidContact => {
+            if let Some(contact) = &ads_txt.contact {
+                if !is_email(contact) && !is_url(contact) && !is_phone_number(contact) {
+                    messages.push(format!(
+                        "contact {} is neither an email address, a URL nor a phone number",
+                        contact
+                    ));
+                }
+            }
+        }
+        AdsTxtLintRule::InvalidSubdomain => {
+            if let Some(domain) = domain {
+                for subdomain in &ads_txt.subdomains {
+                    if !is_subdomain_of(subdomain, domain) {
+                        messages.push(format!(
+                            "subdomain {} is not a subdomain of {}",
+                            subdomain, domain
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    messages
+}
+
+fn is_email(contact: &str) -> bool {
+    is_email_address(contact.strip_prefix("mailto:").unwrap_or(contact))
+}
+
+fn is_url(contact: &str) -> bool {
+    ["http://", "https://"].iter().any(|scheme| {
+        contact
+            .get(..scheme.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme))
+            && is_host_name(
+                contact[scheme.len()..]
+                    .split(['/', '?', '#', ':'])
+                    .next()
+                    .unwrap_or_default(),
+            )
+    })
+}
+
+fn is_phone_number(contact: &str) -> bool {
+    let contact = contact.strip_prefix("tel:").unwrap_or(contact);
+    let digits = contact.bytes().filter(u8::is_ascii_digit).count();
+    digits >= 7
+        && contact
+            .bytes()
+            .all(|c| c.is_ascii_digit() || b" +-().".contains(&c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn lint(content: &str) -> AdsTxtLintReport {
+        AdsTxtLinter::new().lint(&AdsTxt::from_str(content).unwrap())
+    }
+
+    fn rules(report: &AdsTxtLintReport) -> Vec<AdsTxtLintRule> {
+        report.findings.iter().map(|f| f.rule).collect()
+    }
+
+    #[test]
+    fn clean_file_has_no_findings() {
+        let report = lint(
+            "contact=adops@example.com\nownerdomain=example.com\nsubdomain=news.example.com\nmanagerdomain=manager.com\nmanagerdomain=other.com, FR\nredssp.com, 1, DIRECT\nredssp.com, 2, RESELLER",
+        );
+        assert!(report.is_empty(), "{:?}", report);
+    }
+
+    #[test]
+    fn duplicate_and_conflicting_records() {
+        let report =
+            lint("redssp.com, 1, DIRECT\nredssp.com, 1, DIRECT # again\nredssp.com, 1, RESELLER");
+        assert_eq!(
+            rules(&report),
+            vec![
+                AdsTxtLintRule::DuplicateRecord,
+                AdsTxtLintRule::ConflictingRelation
+            ]
+        );
+        assert_eq!(report.findings[0].severity, AdsTxtLintSeverity::Info);
+        assert!(!report.has_errors());
+    }
+
+    #[test]
+    fn manager_domains() {
+        let report = lint(
+            "managerdomain=a.com\nmanagerdomain=b.com\nmanagerdomain=c.com, FR\nmanagerdomain=d.com, FR\nmanagerdomain=e.com, DE",
+        );
+        assert_eq!(
+            rules(&report),
+            vec![
+                AdsTxtLintRule::ManagerDomainWithoutOwnerDomain,
+                AdsTxtLintRule::MultipleGlobalManagerDomains,
+                AdsTxtLintRule::MultipleCountryManagerDomains,
+            ]
+        );
+        assert!(report.has_errors());
+    }
+
+    #[test]
+    fn contact_formats() {
+        for contact in [
+            "adops@example.com",
+            "mailto:adops@example.com",
+            "https://example.com/contact",
+            "+1 (555) 010-9999",
+        ] {
+            let report = lint(&format!("contact={contact}"));
+            assert!(report.is_empty(), "{contact}");
+        }
+        for contact in ["john", "example@", "www.example.com", "12345"] {
+            let report = lint(&format!("contact={contact}"));
+            assert_eq!(
+                rules(&report),
+                vec![AdsTxtLintRule::InvalidContact],
+                "{contact}"
+            );
+        }
+    }
+
+    #[test]
+    fn subdomains() {
+        let ads_txt = AdsTxt::from_str("subdomain=news.example.com\nsubdomain=other.com").unwrap();
+        assert!(AdsTxtLinter::new().lint(&ads_txt).is_empty());
+
+        let report = AdsTxtLinter::new().lint_for_domain(&ads_txt, "Example.com");
+        assert_eq!(rules(&report), vec![AdsTxtLintRule::InvalidSubdomain]);
+        assert_eq!(
+            report.findings[0].message,
+            "subdomain other.com is not a subdomain of example.com"
+        );
+    }
+
+    #[test]
+    fn disable_and_override_rules() {
+        let ads_txt =
+            AdsTxt::from_str("contact=john\nredssp.com, 1, DIRECT\nredssp.com, 1, DIRECT").unwrap();
+        let report = AdsTxtLinter::new()
+            .disable(AdsTxtLintRule::InvalidContact)
+            .with_severity(AdsTxtLintRule::DuplicateRecord, AdsTxtLintSeverity::Error)
+            .lint(&ads_txt);
+        assert_eq!(rules(&report), vec![AdsTxtLintRule::DuplicateRecord]);
+        assert!(report.has_errors());
+    }
+
+    #[test]
+    fn report_serializes_to_json() {
+        let report = lint("redssp.com, 1, DIRECT\nredssp.com, 1, RESELLER");
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "findings": [{
+                    "rule": "conflicting-relation",
+                    "severity": "warning",
+                    "message": "redssp.com, 1 is declared both DIRECT and RESELLER",
+                }]
+            })
+        );
+        for rule in AdsTxtLintRule::ALL {
+            assert_eq!(serde_json::to_value(rule).unwrap(), rule.id());
+        }
+    }
+}
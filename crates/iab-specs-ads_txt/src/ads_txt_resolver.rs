@@ -0,0 +1,395 @@
+use crate::{AdsTxt, AdsTxtSystem};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::BuildHasher;
+
+/// Source of ads.txt files used by [`AdsTxtResolver`].
+///
+/// Implementations perform the actual retrieval, typically an HTTPS request to
+/// `https://<domain>/ads.txt`. An in-memory implementation is provided for `HashMap<String, String>`
+/// keyed by domain, which is convenient for tests.
+pub trait AdsTxtFetcher {
+    /// Fetches the ads.txt content published on `domain`.
+    ///
+    /// Returns `Ok(None)` if the domain does not publish an ads.txt file.
+    fn fetch(&self, domain: &str) -> crate::Result<Option<String>>;
+}
+
+impl<S: BuildHasher> AdsTxtFetcher for HashMap<String, String, S> {
+    fn fetch(&self, domain: &str) -> crate::Result<Option<String>> {
+        Ok(self.get(domain).cloned())
+    }
+}
+
+/// How the crawler reached an ads.txt file.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AdsTxtReferral {
+    /// The file of the root domain the resolution started from.
+    Root,
+    /// A file reached through a SUBDOMAIN variable. Its records apply to the subdomain.
+    Subdomain,
+    /// A file reached through an INVENTORYPARTNERDOMAIN variable.
+    InventoryPartnerDomain,
+}
+
+/// Where a resolved record was declared.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AdsTxtOrigin {
+    /// Domain of the ads.txt file declaring the record.
+    pub domain: String,
+
+    /// How the file was reached.
+    pub referral: AdsTxtReferral,
+
+    /// Number of referrals followed from the root domain.
+    pub depth: usize,
+}
+
+/// A data record together with the file it comes from.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ResolvedAdsTxtSystem {
+    /// The declared record.
+    pub system: AdsTxtSystem,
+
+    /// Where the record was declared.
+    pub origin: AdsTxtOrigin,
+}
+
+/// A referral that could not be followed during a resolution.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AdsTxtResolutionIssue {
+    /// The fetcher failed to retrieve the file.
+    FetchFailed { domain: String, message: String },
+
+    /// The domain does not publish an ads.txt file.
+    NotFound { domain: String },
+
+    /// A subdomain file refers to another subdomain, which the specification forbids.
+    NestedSubdomain { domain: String, subdomain: String },
+
+    /// A SUBDOMAIN variable points outside of the root domain declaring it.
+    SubdomainOutsideRoot { domain: String, subdomain: String },
+
+    /// The domain refers back to a domain on its own resolution path.
+    Loop { domain: String },
+
+    /// Following the referral would exceed the maximum depth.
+    MaxDepthExceeded { domain: String },
+}
+
+/// The merged authorisation set of a root domain.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct AdsTxtResolution {
+    /// Every record found, in crawl order.
+    pub systems: Vec<ResolvedAdsTxtSystem>,
+
+    /// Referrals that could not be followed.
+    pub issues: Vec<AdsTxtResolutionIssue>,
+}
+
+/// Follows the SUBDOMAIN and INVENTORYPARTNERDOMAIN referrals of an ads.txt file.
+///
+/// Starting from a root domain, the resolver fetches its ads.txt file, then the files of the
+/// subdomains it declares and of its inventory partners, breadth first. As required by the
+/// specification:
+/// - only the root domain may refer to subdomains, and only to subdomains of itself;
+/// - INVENTORYPARTNERDOMAIN references are followed to ads.txt files only;
+/// - each domain is fetched at most once and referrals are followed up to a maximum depth.
+///
+/// A domain reached again through another branch (a diamond) is silently skipped; only a
+/// referral back to a domain on its own resolution path is reported as a loop.
+///
+/// Invalid lines of fetched files are skipped, as with [`AdsTxt::parse_lenient`].
+pub struct AdsTxtResolver<F: AdsTxtFetcher> {
+    fetcher: F,
+    max_depth: usize,
+}
+
+impl<F: AdsTxtFetcher> AdsTxtResolver<F> {
+    /// Default number of referrals followed from the root domain.
+    pub const DEFAULT_MAX_DEPTH: usize = 3;
+
+    /// Creates a resolver retrieving files with `fetcher`.
+    pub fn new(fetcher: F) -> Self {
+        AdsTxtResolver {
+            fetcher,
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+        }
+    }
+
+    /// Sets the maximum number of referrals followed from the root domain.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Resolves the authorisation set of `root_domain`.
+    pub fn resolve(&self, root_domain: &str) -> AdsTxtResolution {
+        let mut resolution = AdsTxtResolution::default();
+        let mut visited: HashSet<String> = HashSet::new();
+        // Each queued domain carries the domains it was reached through.
+        let mut queue: VecDeque<(String, AdsTxtReferral, usize, Vec<String>)> = VecDeque::new();
+        queue.push_back((root_domain.to_lowercase(), AdsTxtReferral::Root, 0, vec![]));
+
+        while let Some((domain, referral, depth, ancestors)) = queue.pop_front() {
+            if !visited.insert(domain.clone()) {
+                continue;
+            }
+            let content = match self.fetcher.fetch(&domain) {
+                Ok(Some(content)) => content,
+                Ok(None) => {
+                    resolution
+                        .issues
+                        .push(AdsTxtResolutionIssue::NotFound { domain });
+                    continue;
+                }
+                Err(e) => {
+                    resolution.issues.push(AdsTxtResolutionIssue::FetchFailed {
+                        domain,
+                        message: e.to_string(),
+                    });
+                    continue;
+                }
+            };
+            let ads_txt: AdsTxt = AdsTxt::parse_lenient(&content).ads_txt;
+
+            let mut referrals = vec![];
+            for subdomain in ads_txt.subdomains.iter() {
+                if referral == AdsTxtReferral::Subdomain {
+                    resolution
+                        .issues
+                        .push(AdsTxtResolutionIssue::NestedSubdomain {
+                            domain: domain.clone(),
+                            subdomain: subdomain.clone(),
+                        });
+                } else if !is_subdomain_of(subdomain, &domain) {
+                    resolution
+                        .issues
+                        .push(AdsTxtResolutionIssue::SubdomainOutsideRoot {
+                            domain: domain.clone(),
+                            subdomain: subdomain.clone(),
+                        });
+                } else {
+                    referrals.push((subdomain.clone(), AdsTxtReferral::Subdomain));
+                }
+            }
+            for partner in ads_txt.inventory_partner_domains.iter() {
+                referrals.push((partner.clone(), AdsTxtReferral::InventoryPartnerDomain));
+            }
+            let mut path = ancestors;
+            path.push(domain.clone());
+            for (target, target_referral) in referrals {
+                if path.contains(&target) {
+                    resolution
+                        .issues
+                        .push(AdsTxtResolutionIssue::Loop { domain: target });
+                } else if depth + 1 > self.max_depth {
+                    resolution
+                        .issues
+                        .push(AdsTxtResolutionIssue::MaxDepthExceeded { domain: target });
+                } else {
+                    queue.push_back((target, target_referral, depth + 1, path.clone()));
+                }
+            }
+
+            let origin = AdsTxtOrigin {
+                domain,
+                referral,
+                depth,
+            };
+            resolution
+                .systems
+                .extend(
+                    ads_txt
+                        .systems
+                        .into_iter()
+                        .map(|system| ResolvedAdsTxtSystem {
+                            system,
+                            origin: origin.clone(),
+                        }),
+                );
+        }
+        resolution
+    }
+}
+
+/// Returns `true` if `subdomain` is a strict subdomain of `domain`.
+pub(crate) fn is_subdomain_of(subdomain: &str, domain: &str) -> bool {
+    subdomain
+        .strip_suffix(domain)
+        .is_some_and(|prefix| prefix.len() > 1 && prefix.ends_with('.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::Error;
+
+    fn fetcher(files: &[(&str, &str)]) -> HashMap<String, String> {
+        files
+            .iter()
+            .map(|(domain, content)| (domain.to_string(), content.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn resolve_subdomains_and_partners() {
+        let files = fetcher(&[
+            (
+                "example.com",
+                "subdomain=news.example.com\ninventorypartnerdomain=partner.com\nredssp.com, 1, DIRECT",
+            ),
+            ("news.example.com", "bluessp.com, 2, DIRECT"),
+            ("partner.com", "greenssp.com, 3, RESELLER"),
+        ]);
+        let resolution = AdsTxtResolver::new(files).resolve("example.com");
+        assert!(resolution.issues.is_empty());
+        let origins: Vec<_> = resolution
+            .systems
+            .iter()
+            .map(|r| {
+                (
+                    r.system.domain.as_str(),
+                    r.origin.domain.as_str(),
+                    r.origin.referral,
+                    r.origin.depth,
+                )
+            })
+            .collect();
+        assert_eq!(
+            origins,
+            vec![
+                ("redssp.com", "example.com", AdsTxtReferral::Root, 0),
+                (
+                    "bluessp.com",
+                    "news.example.com",
+                    AdsTxtReferral::Subdomain,
+                    1
+                ),
+                (
+                    "greenssp.com",
+                    "partner.com",
+                    AdsTxtReferral::InventoryPartnerDomain,
+                    1
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_enforces_subdomain_rules() {
+        let files = fetcher(&[
+            (
+                "example.com",
+                "subdomain=news.example.com\nsubdomain=other.com\nredssp.com, 1, DIRECT",
+            ),
+            (
+                "news.example.com",
+                "subdomain=sport.news.example.com\nbluessp.com, 2, DIRECT",
+            ),
+            ("sport.news.example.com", "greenssp.com, 3, DIRECT"),
+        ]);
+        let resolution = AdsTxtResolver::new(files).resolve("example.com");
+        assert_eq!(resolution.systems.len(), 2);
+        assert_eq!(
+            resolution.issues,
+            vec![
+                AdsTxtResolutionIssue::SubdomainOutsideRoot {
+                    domain: "example.com".to_string(),
+                    subdomain: "other.com".to_string(),
+                },
+                AdsTxtResolutionIssue::NestedSubdomain {
+                    domain: "news.example.com".to_string(),
+                    subdomain: "sport.news.example.com".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_bounds_loops_and_depth() {
+        let files = fetcher(&[
+            (
+                "a.com",
+                "inventorypartnerdomain=b.com\nredssp.com, 1, DIRECT",
+            ),
+            (
+                "b.com",
+                "inventorypartnerdomain=a.com\ninventorypartnerdomain=c.com",
+            ),
+            ("c.com", "inventorypartnerdomain=d.com"),
+        ]);
+        let resolution = AdsTxtResolver::new(files)
+            .with_max_depth(2)
+            .resolve("a.com");
+        assert_eq!(resolution.systems.len(), 1);
+        assert_eq!(
+            resolution.issues,
+            vec![
+                AdsTxtResolutionIssue::Loop {
+                    domain: "a.com".to_string()
+                },
+                AdsTxtResolutionIssue::MaxDepthExceeded {
+                    domain: "d.com".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_fetches_diamonds_once_without_loop() {
+        let files = fetcher(&[
+            (
+                "a.com",
+                "inventorypartnerdomain=b.com\ninventorypartnerdomain=c.com",
+            ),
+            ("b.com", "inventorypartnerdomain=d.com"),
+            ("c.com", "inventorypartnerdomain=d.com"),
+            ("d.com", "redssp.com, 1, DIRECT"),
+        ]);
+        let resolution = AdsTxtResolver::new(files).resolve("a.com");
+        assert!(resolution.issues.is_empty());
+        assert_eq!(resolution.systems.len(), 1);
+        assert_eq!(resolution.systems[0].origin.domain, "d.com");
+    }
+
+    #[test]
+    fn resolve_reports_fetch_failures() {
+        struct FailingFetcher;
+        impl AdsTxtFetcher for FailingFetcher {
+            fn fetch(&self, domain: &str) -> crate::Result<Option<String>> {
+                match domain {
+                    "example.com" => Ok(Some(
+                        "inventorypartnerdomain=down.com\ninventorypartnerdomain=none.com"
+                            .to_string(),
+                    )),
+                    "down.com" => Err(serde_plain::Error::custom("timeout").into()),
+                    _ => Ok(None),
+                }
+            }
+        }
+        let resolution = AdsTxtResolver::new(FailingFetcher).resolve("example.com");
+        assert!(resolution.systems.is_empty());
+        assert_eq!(
+            resolution.issues,
+            vec![
+                AdsTxtResolutionIssue::FetchFailed {
+                    domain: "down.com".to_string(),
+                    message: "timeout".to_string(),
+                },
+                AdsTxtResolutionIssue::NotFound {
+                    domain: "none.com".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn subdomain_of() {
+        assert!(is_subdomain_of("news.example.com", "example.com"));
+        assert!(!is_subdomain_of("example.com", "example.com"));
+        assert!(!is_subdomain_of("badexample.com", "example.com"));
+    }
+}
@@ -1,8 +1,10 @@
-use crate::{AdsTxtSystem, ManagerDomain};
+use crate::{
+    AdsTxtDiagnostic, AdsTxtDiagnosticCode, AdsTxtDiagnosticSeverity, AdsTxtDirective,
+    AdsTxtGrammar, AdsTxtParseReport, AdsTxtSystem, AdsTxtVariable, ManagerDomain,
+};
 use derive_builder::Builder;
-use iab_specs_core::slice_up_to;
-use serde::de::Error;
 use serde_with::{DeserializeFromStr, SerializeDisplay, serde_as};
+use std::collections::BTreeSet;
 use std::fmt;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
@@ -21,17 +23,19 @@ pub struct AdsTxt {
     #[builder(default)]
     pub contact: Option<String>,
 
-    /// Pointer to a subdomain file
+    /// Pointers to subdomain files
     ///
     /// A machine readable subdomain pointer to a subdomain within the root domain, on which an
     /// ads.txt can be found. The crawler should fetch and consume associate the data to the
     /// subdomain, not the current domain. This referral should be exempt from the public suffix
     /// truncation process. Only root domains should refer crawlers to subdomains. Subdomains
     /// should not refer to other subdomains.
+    ///
+    /// The variable may be declared several times, once per subdomain.
     #[builder(default)]
-    pub subdomain: Option<String>,
+    pub subdomains: Vec<String>,
 
-    /// A pointer to the domain of an entity that is explicitly authorized to monetize ads within
+    /// Pointers to the domains of entities that are explicitly authorized to monetize ads within
     /// the application's content.
     ///
     /// When a site or an app contains ad inventory that is owned by another partner - the app
@@ -40,8 +44,10 @@ pub struct AdsTxt {
     /// It is expected that the INVENTORYPARTNERDOMAIN reference is followed to an ads.txt
     /// file only (not app-ads.txt). See the implementers notes for more details as this feature
     /// requires coordination with your advertising system.
+    ///
+    /// The variable may be declared several times, once per partner.
     #[builder(default)]
-    pub inventory_partner_domain: Option<String>,
+    pub inventory_partner_domains: Vec<String>,
 
     /// The business domain of the business entity that owns the domain/site/app
     ///
@@ -84,6 +90,13 @@ pub struct AdsTxt {
     /// List of systems declared.
     #[builder(default)]
     pub systems: Vec<AdsTxtSystem>,
+
+    /// Variables not defined by the specification, as `(key, value)` pairs.
+    ///
+    /// The specification requires parsers to ignore unknown variables. They are kept here
+    /// verbatim and in declaration order, so that they are written back by `Display`.
+    #[builder(default)]
+    pub unknown_variables: Vec<(String, String)>,
 }
 
 impl AdsTxt {
@@ -91,6 +104,15 @@ impl AdsTxt {
     pub fn builder() -> AdsTxtBuilder {
         AdsTxtBuilder::create_empty()
     }
+
+    /// Iterates over the values of the unknown variable `key`, in declaration order.
+    /// Keys are case-insensitive.
+    pub fn unknown_variable<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> {
+        self.unknown_variables
+            .iter()
+            .filter(move |(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| v.as_str())
+    }
 }
 
 impl Display for AdsTxt {
@@ -99,10 +121,10 @@ impl Display for AdsTxt {
         if let Some(v) = &self.contact {
             seq.push(format!("contact={}", v))
         }
-        if let Some(v) = &self.subdomain {
+        for v in &self.subdomains {
             seq.push(format!("subdomain={}", v))
         }
-        if let Some(v) = &self.inventory_partner_domain {
+        for v in &self.inventory_partner_domains {
             seq.push(format!("inventorypartnerdomain={}", v))
         }
         if let Some(v) = &self.owner_domain {
@@ -111,6 +133,9 @@ impl Display for AdsTxt {
         for v in &self.manager_domains {
             seq.push(format!("managerdomain={}", v))
         }
+        for (key, value) in &self.unknown_variables {
+            seq.push(format!("{}={}", key, value))
+        }
         for v in &self.systems {
             seq.push(v.to_string())
         }
@@ -123,77 +148,206 @@ impl FromStr for AdsTxt {
     type Err = crate::Error;
 
     fn from_str(content: &str) -> Result<Self, Self::Err> {
-        const FIELDS: &[&str] = &[
-            "contact",
-            "subodmain",
-            "inventorypartnerdomain",
-            "ownerdomain",
-            "managerdomain",
-        ];
-        let mut contact = None;
-        let mut subdomain = None;
-        let mut inventory_partner_domain = None;
-        let mut owner_domain = None;
-        let mut manager_domains = vec![];
-        let mut systems = vec![];
-        for line in content.lines() {
-            let line = line.trim();
-            // Both empty lines and top of block comments are skipped.
-            if line.is_empty() || line.starts_with('#') {
-                continue;
-            } else {
-                // Variable
-                if line.contains('=') {
-                    let (key, value) = line
-                        .split_once("=")
-                        .ok_or_else(|| serde_plain::Error::unknown_field(&line[..100], FIELDS))?;
-                    let value = match value.contains("#") {
-                        true => {
-                            let (v, _) = value
-                                .split_once("#")
-                                .ok_or_else(|| serde_plain::Error::missing_field("comment"))?;
-                            v
-                        }
-                        false => value,
-                    };
-                    let value = value.trim().to_lowercase();
-                    match key.trim() {
-                        "contact" => contact = Some(value),
-                        "subdomain" => subdomain = Some(value),
-                        "inventorypartnerdomain" => inventory_partner_domain = Some(value),
-                        "ownerdomain" => {
-                            if owner_domain.is_none() {
-                                owner_domain = Some(value)
-                            }
+        AdsTxtGrammar::ADS_TXT_1_1.parse(content)
+    }
+}
+
+impl AdsTxt {
+    /// Parses an ads.txt file without failing on invalid lines.
+    ///
+    /// Crawled files often contain typos. Instead of rejecting the whole file on the first
+    /// invalid line like [`AdsTxt::from_str`], invalid lines are skipped and reported as
+    /// line-numbered [`AdsTxtDiagnostic`]s alongside the valid records. Unknown variables and
+    /// duplicate entries and variables without value are reported as warnings.
+    pub fn parse_lenient(content: &str) -> AdsTxtParseReport {
+        AdsTxtGrammar::ADS_TXT_1_1.parse_lenient(content)
+    }
+}
+
+impl AdsTxtGrammar {
+    /// Parses a file of this variant into the ads.txt model, failing on the first invalid line.
+    ///
+    /// Every variant shares the semantics of ads.txt: SUBDOMAIN, INVENTORYPARTNERDOMAIN and
+    /// MANAGERDOMAIN may be repeated, only the first OWNERDOMAIN is used and unknown
+    /// variables are kept if the grammar allows them.
+    pub fn parse(&self, content: &str) -> crate::Result<AdsTxt> {
+        match parse(*self, content, false) {
+            (_, Some(err)) => Err(err),
+            (report, None) => Ok(report.ads_txt),
+        }
+    }
+
+    /// Parses a file of this variant into the ads.txt model, skipping and reporting invalid
+    /// lines as [`AdsTxt::parse_lenient`] does.
+    pub fn parse_lenient(&self, content: &str) -> AdsTxtParseReport {
+        parse(*self, content, true).0
+    }
+}
+
+/// Parses `content`, stopping at the first error in strict mode or collecting diagnostics
+/// for every invalid line in lenient mode. The error is only returned in strict mode.
+fn parse(
+    grammar: AdsTxtGrammar,
+    content: &str,
+    lenient: bool,
+) -> (AdsTxtParseReport, Option<crate::Error>) {
+    let mut contact = None;
+    let mut subdomains: Vec<String> = vec![];
+    let mut inventory_partner_domains: Vec<String> = vec![];
+    let mut owner_domain = None;
+    let mut manager_domains: Vec<ManagerDomain> = vec![];
+    let mut systems: Vec<AdsTxtSystem> = vec![];
+    let mut record_keys = BTreeSet::new();
+    let mut unknown_variables: Vec<(String, String)> = vec![];
+    let mut diagnostics = vec![];
+    let mut error = None;
+
+    // Reports an invalid line: strict mode stops on it, lenient mode records it and moves on.
+    macro_rules! reject {
+        ($line_number:expr, $severity:expr, $code:expr, $err:expr) => {{
+            let err: crate::Error = $err;
+            if !lenient {
+                error = Some(err);
+                break;
+            }
+            diagnostics.push(AdsTxtDiagnostic::new(
+                $line_number,
+                $severity,
+                $code,
+                err.to_string(),
+            ));
+            continue;
+        }};
+    }
+
+    for token in grammar.tokenize(content) {
+        let line_number = token.line_number;
+        let directive = match token.directive {
+            Ok(directive) => directive,
+            Err(e) => reject!(
+                line_number,
+                AdsTxtDiagnosticSeverity::Error,
+                AdsTxtSystem::diagnose(token.line),
+                e
+            ),
+        };
+        match directive {
+            AdsTxtDirective::Variable { variable, value } => {
+                if value.is_empty() {
+                    diagnostics.push(AdsTxtDiagnostic::new(
+                        line_number,
+                        AdsTxtDiagnosticSeverity::Warning,
+                        AdsTxtDiagnosticCode::MissingField,
+                        format!("{} without value", variable.key()),
+                    ));
+                }
+                let value = value.to_lowercase();
+                match variable {
+                    AdsTxtVariable::Contact => contact = Some(value),
+                    AdsTxtVariable::Subdomain => {
+                        if !push_repeated(&mut subdomains, value) {
+                            diagnostics.push(AdsTxtDiagnostic::new(
+                                line_number,
+                                AdsTxtDiagnosticSeverity::Warning,
+                                AdsTxtDiagnosticCode::DuplicateEntry,
+                                "duplicate subdomain",
+                            ));
                         }
-                        "managerdomain" => {
-                            let manager_domain = ManagerDomain::from_str(&value)?;
-                            manager_domains.push(manager_domain);
+                    }
+                    AdsTxtVariable::InventoryPartnerDomain => {
+                        if !push_repeated(&mut inventory_partner_domains, value) {
+                            diagnostics.push(AdsTxtDiagnostic::new(
+                                line_number,
+                                AdsTxtDiagnosticSeverity::Warning,
+                                AdsTxtDiagnosticCode::DuplicateEntry,
+                                "duplicate inventorypartnerdomain",
+                            ));
                         }
-                        &_ => {
-                            return Err(serde_plain::Error::unknown_field(
-                                slice_up_to!(line, 100),
-                                FIELDS,
-                            )
-                            .into());
+                    }
+                    AdsTxtVariable::OwnerDomain => {
+                        if owner_domain.is_none() {
+                            owner_domain = Some(value)
+                        } else {
+                            diagnostics.push(AdsTxtDiagnostic::new(
+                                line_number,
+                                AdsTxtDiagnosticSeverity::Warning,
+                                AdsTxtDiagnosticCode::DuplicateEntry,
+                                "only the first ownerdomain is used",
+                            ));
                         }
                     }
-                } else {
-                    // System
-                    let system = AdsTxtSystem::from_str(line)?;
-                    systems.push(system);
+                    AdsTxtVariable::ManagerDomain => match ManagerDomain::from_str(&value) {
+                        Ok(manager_domain) => {
+                            if manager_domains.iter().any(|m| {
+                                m.domain == manager_domain.domain
+                                    && m.country_code == manager_domain.country_code
+                            }) {
+                                diagnostics.push(AdsTxtDiagnostic::new(
+                                    line_number,
+                                    AdsTxtDiagnosticSeverity::Warning,
+                                    AdsTxtDiagnosticCode::DuplicateEntry,
+                                    "duplicate managerdomain",
+                                ));
+                            }
+                            manager_domains.push(manager_domain);
+                        }
+                        Err(e) => reject!(
+                            line_number,
+                            AdsTxtDiagnosticSeverity::Error,
+                            AdsTxtDiagnosticCode::InvalidManagerDomain,
+                            e
+                        ),
+                    },
+                }
+            }
+            AdsTxtDirective::UnknownVariable { key, value } => {
+                diagnostics.push(AdsTxtDiagnostic::new(
+                    line_number,
+                    AdsTxtDiagnosticSeverity::Warning,
+                    AdsTxtDiagnosticCode::UnknownVariable,
+                    grammar.unknown_variable(key).to_string(),
+                ));
+                unknown_variables.push((key.to_string(), value.to_string()));
+            }
+            AdsTxtDirective::Record(system) => {
+                let system = system.into_owned();
+                if !record_keys.insert((
+                    system.domain.clone(),
+                    system.publisher_id.clone(),
+                    system.relation,
+                )) {
+                    diagnostics.push(AdsTxtDiagnostic::new(
+                        line_number,
+                        AdsTxtDiagnosticSeverity::Warning,
+                        AdsTxtDiagnosticCode::DuplicateEntry,
+                        "duplicate record",
+                    ));
                 }
+                systems.push(system);
             }
         }
-        AdsTxt::builder()
-            .contact(contact)
-            .subdomain(subdomain)
-            .inventory_partner_domain(inventory_partner_domain)
-            .owner_domain(owner_domain)
-            .manager_domains(manager_domains)
-            .systems(systems)
-            .build()
     }
+    let ads_txt = AdsTxt {
+        contact,
+        subdomains,
+        inventory_partner_domains,
+        owner_domain,
+        manager_domains,
+        systems,
+        unknown_variables,
+    };
+    let report = AdsTxtParseReport {
+        ads_txt,
+        diagnostics,
+    };
+    (report, error)
+}
+
+/// Appends `value`, returning `false` if it was already present.
+fn push_repeated(values: &mut Vec<String>, value: String) -> bool {
+    let distinct = !values.contains(&value);
+    values.push(value);
+    distinct
 }
 
 #[cfg(test)]
@@ -242,12 +396,10 @@ mod tests {
         let res = res.unwrap();
         assert!(res.contact.is_some());
         assert_eq!(res.contact.as_ref().unwrap().as_str(), "user@mydomain.com");
-        assert!(res.subdomain.is_some());
-        assert_eq!(res.subdomain.as_ref().unwrap().as_str(), "sub.mydomain.com");
-        assert!(res.inventory_partner_domain.is_some());
+        assert_eq!(res.subdomains, vec!["sub.mydomain.com".to_string()]);
         assert_eq!(
-            res.inventory_partner_domain.as_ref().unwrap().as_str(),
-            "mypartnerdomain.com"
+            res.inventory_partner_domains,
+            vec!["mypartnerdomain.com".to_string()]
         );
         assert!(res.owner_domain.is_some());
         assert_eq!(
@@ -270,8 +422,7 @@ mod tests {
         let res = AdsTxt::from_str(ads_txt);
         assert!(res.is_ok());
         let res = res.unwrap();
-        assert!(res.subdomain.is_some());
-        assert_eq!(res.subdomain.as_ref().unwrap().as_str(), "sub.mydomain.com");
+        assert_eq!(res.subdomains, vec!["sub.mydomain.com".to_string()]);
         assert!(!res.systems.is_empty());
         let system = res.systems.first().unwrap();
         assert_eq!(&system.domain, "greenadexchange.com");
@@ -344,8 +495,8 @@ mod tests {
         ];
         let v = AdsTxt::builder()
             .contact(Some("mycontact".to_string()))
-            .subdomain(Some("sub.domain.com".to_string()))
-            .inventory_partner_domain(Some("inv.domain.com".to_string()))
+            .subdomains(vec!["sub.domain.com".to_string()])
+            .inventory_partner_domains(vec!["inv.domain.com".to_string()])
             .owner_domain(Some("owner.domain.com".to_string()))
             .manager_domains(vec![
                 ManagerDomain::builder()
@@ -369,8 +520,54 @@ mod tests {
         unknownvariable=somevalue
         greenadexchange.com, XF7342, DIRECT
         "#;
-        let res = AdsTxt::from_str(ads_txt);
-        assert!(res.is_err());
+        let res = AdsTxt::from_str(ads_txt).unwrap();
+        assert_eq!(
+            res.unknown_variables,
+            vec![("unknownvariable".to_string(), "somevalue".to_string())]
+        );
+        assert_eq!(res.systems.len(), 1);
+    }
+
+    #[test]
+    // Spec: Section 3.2 — SUBDOMAIN and INVENTORYPARTNERDOMAIN may be declared several times
+    fn deserialize_with_multiple_subdomains_and_inventory_partner_domains() {
+        let ads_txt = r#"
+        subdomain=a.example.com
+        subdomain=b.example.com
+        subdomain=a.example.com
+        inventorypartnerdomain=partner1.com
+        inventorypartnerdomain=partner2.com
+        "#;
+        let res = AdsTxt::from_str(ads_txt).unwrap();
+        assert_eq!(
+            res.subdomains,
+            vec![
+                "a.example.com".to_string(),
+                "b.example.com".to_string(),
+                "a.example.com".to_string()
+            ]
+        );
+        assert_eq!(
+            res.inventory_partner_domains,
+            vec!["partner1.com".to_string(), "partner2.com".to_string()]
+        );
+    }
+
+    #[test]
+    fn roundtrip_unknown_variables() {
+        let ads_txt = "contact=adops@example.com\nZetaVar=One\nothervar=x\nzetavar=Two # comment\ngreenadexchange.com, XF7342, DIRECT";
+        let res = AdsTxt::from_str(ads_txt).unwrap();
+        assert_eq!(
+            res.unknown_variable("ZETAVAR").collect::<Vec<_>>(),
+            vec!["One", "Two"]
+        );
+        let serialized = res.to_string();
+        assert_eq!(
+            serialized,
+            "contact=adops@example.com\nZetaVar=One\nothervar=x\nzetavar=Two\ngreenadexchange.com,xf7342,direct"
+        );
+        let reparsed = AdsTxt::from_str(&serialized).unwrap();
+        assert_eq!(res.unknown_variables, reparsed.unknown_variables);
     }
 
     #[test]
@@ -397,7 +594,7 @@ silverssp.com, 9876, RESELLER
         let reparsed = AdsTxt::from_str(&serialized).unwrap();
 
         assert_eq!(ads_txt.contact, reparsed.contact);
-        assert_eq!(ads_txt.subdomain, reparsed.subdomain);
+        assert_eq!(ads_txt.subdomains, reparsed.subdomains);
         assert_eq!(ads_txt.owner_domain, reparsed.owner_domain);
         assert_eq!(ads_txt.systems.len(), reparsed.systems.len());
     }
@@ -408,8 +605,8 @@ silverssp.com, 9876, RESELLER
         let content = "# This is a comment\n# Another comment\n";
         let res = AdsTxt::from_str(content).unwrap();
         assert!(res.contact.is_none());
-        assert!(res.subdomain.is_none());
-        assert!(res.inventory_partner_domain.is_none());
+        assert!(res.subdomains.is_empty());
+        assert!(res.inventory_partner_domains.is_empty());
         assert!(res.owner_domain.is_none());
         assert!(res.manager_domains.is_empty());
         assert!(res.systems.is_empty());
@@ -426,21 +623,13 @@ silverssp.com, 9876, RESELLER
     }
 
     #[test]
-    // Spec: Section 3.1 — CR-only line endings behavior (Rust lines() does not split on bare CR)
+    // Spec: Section 3.1 — CR-only line endings are line separators
     fn parse_cr_only_line_endings() {
-        // Rust's str::lines() does NOT split on bare \r
-        // So the entire content becomes one line. Since it contains '=',
-        // it's treated as a variable. The key is "contact" and value includes \r and rest.
-        let content = "contact=test@example.com\rgreenadexchange.com, XF7342, DIRECT";
-        let res = AdsTxt::from_str(content);
-        // The value after = is "test@example.com\rgreenadexchange.com, XF7342, DIRECT"
-        // which gets trimmed and lowercased. No # so the full value is kept.
-        // This should parse as contact variable (key "contact" matches)
-        assert!(res.is_ok());
-        let ads = res.unwrap();
-        // Contact will contain the \r and everything after
-        assert!(ads.contact.is_some());
-        assert!(ads.systems.is_empty()); // No systems parsed since it's all one line
+        let content = "contact=test@example.com\rgreenadexchange.com, XF7342, DIRECT\r";
+        let res = AdsTxt::from_str(content).unwrap();
+        assert_eq!(res.contact.as_deref(), Some("test@example.com"));
+        assert_eq!(res.systems.len(), 1);
+        assert_eq!(&res.systems[0].domain, "greenadexchange.com");
     }
 
     #[test]
@@ -471,12 +660,141 @@ silverssp.com, 9876, RESELLER
     }
 
     #[test]
-    // Spec: Section 3.2 — Variable name case handling (parser is case-sensitive)
+    // Spec: Section 3.2 — Variable names are case-insensitive
     fn parse_variable_names_case_insensitive() {
-        // Parser does `match key.trim()` against lowercase "contact", "subdomain", etc.
-        // Uppercase "CONTACT" will NOT match, falling through to error.
-        let content = "CONTACT=upper@example.com\n";
-        let res = AdsTxt::from_str(content);
-        assert!(res.is_err());
+        let content = "CONTACT=upper@example.com\nSubDomain=divisionone.example.com\nFOO=bar\n";
+        let res = AdsTxt::from_str(content).unwrap();
+        assert_eq!(res.contact.as_deref(), Some("upper@example.com"));
+        assert_eq!(res.subdomains, vec!["divisionone.example.com"]);
+        assert_eq!(res.unknown_variable("foo").collect::<Vec<_>>(), vec!["bar"]);
+    }
+
+    #[test]
+    fn parse_lenient_collects_diagnostics() {
+        let content = "contact=adops@example.com
+unknownvariable=somevalue
+greenadexchange.com, XF7342, DIRECT
+greenadexchange.com, XF7343, DIRECTE
+greenadexchange.com, XF7344
+contact
+redssp.com, 1, RESELLER, abc, extra
+managerdomain=manager.com, ZZ
+greenadexchange.com, XF7342, DIRECT, 5jyxf8k54
+";
+        let report = AdsTxt::parse_lenient(content);
+        assert_eq!(report.ads_txt.contact.as_deref(), Some("adops@example.com"));
+        assert_eq!(report.ads_txt.systems.len(), 2);
+        assert!(report.ads_txt.manager_domains.is_empty());
+
+        let found: Vec<_> = report
+            .diagnostics
+            .iter()
+            .map(|d| (d.line, d.severity, d.code))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    2,
+                    AdsTxtDiagnosticSeverity::Warning,
+                    AdsTxtDiagnosticCode::UnknownVariable
+                ),
+                (
+                    4,
+                    AdsTxtDiagnosticSeverity::Error,
+                    AdsTxtDiagnosticCode::InvalidRelation
+                ),
+                (
+                    5,
+                    AdsTxtDiagnosticSeverity::Error,
+                    AdsTxtDiagnosticCode::MissingField
+                ),
+                (
+                    6,
+                    AdsTxtDiagnosticSeverity::Error,
+                    AdsTxtDiagnosticCode::MissingField
+                ),
+                (
+                    7,
+                    AdsTxtDiagnosticSeverity::Error,
+                    AdsTxtDiagnosticCode::TooManyFields
+                ),
+                (
+                    8,
+                    AdsTxtDiagnosticSeverity::Error,
+                    AdsTxtDiagnosticCode::InvalidManagerDomain
+                ),
+                (
+                    9,
+                    AdsTxtDiagnosticSeverity::Warning,
+                    AdsTxtDiagnosticCode::DuplicateEntry
+                ),
+            ]
+        );
+        assert!(report.has_errors());
+    }
+
+    #[test]
+    fn parse_lenient_valid_file_matches_strict() {
+        let content = r#"
+        contact=adops@example.com
+        ownerdomain=example.com
+        managerdomain=manager.com, FR
+        greenadexchange.com, XF7342, DIRECT, 5jyxf8k54 # comment
+        redssp.com, 57013, RESELLER
+        "#;
+        let strict = AdsTxt::from_str(content).unwrap();
+        let report = AdsTxt::parse_lenient(content);
+        assert!(report.diagnostics.is_empty());
+        assert!(!report.has_errors());
+        assert_eq!(report.ads_txt.to_string(), strict.to_string());
+    }
+
+    #[test]
+    fn parse_lenient_warns_on_variable_without_value() {
+        let content = "contact=\nsubdomain= # none\nmanagerdomain=\n";
+        let report = AdsTxt::parse_lenient(content);
+        let found: Vec<_> = report
+            .diagnostics
+            .iter()
+            .map(|d| (d.line, d.severity, d.code))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    1,
+                    AdsTxtDiagnosticSeverity::Warning,
+                    AdsTxtDiagnosticCode::MissingField
+                ),
+                (
+                    2,
+                    AdsTxtDiagnosticSeverity::Warning,
+                    AdsTxtDiagnosticCode::MissingField
+                ),
+                (
+                    3,
+                    AdsTxtDiagnosticSeverity::Warning,
+                    AdsTxtDiagnosticCode::MissingField
+                ),
+            ]
+        );
+        assert_eq!(report.diagnostics[0].message, "contact without value");
+        // Strict parsing keeps accepting empty values
+        assert!(AdsTxt::from_str("contact=\nsubdomain=").is_ok());
+    }
+
+    #[test]
+    fn parse_lenient_warns_on_duplicate_ownerdomain() {
+        let content = "ownerdomain=first.com\nownerdomain=second.com\n";
+        let report = AdsTxt::parse_lenient(content);
+        assert_eq!(report.ads_txt.owner_domain.as_deref(), Some("first.com"));
+        assert_eq!(report.diagnostics.len(), 1);
+        assert_eq!(report.diagnostics[0].line, 2);
+        assert_eq!(
+            report.diagnostics[0].code,
+            AdsTxtDiagnosticCode::DuplicateEntry
+        );
+        assert!(!report.has_errors());
     }
 }
@@ -0,0 +1,265 @@
+use crate::ads_txt_grammar::read_line;
+use crate::{AdsTxt, AdsTxtDirective, AdsTxtGrammar, AdsTxtSystemRef, AdsTxtToken, AdsTxtTokens};
+use serde::de::Error;
+use std::io;
+use std::io::BufRead;
+
+/// A variable or data record borrowed from the parsed content.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdsTxtEntryRef<'a> {
+    /// A `key=value` variable, with key and value trimmed and the trailing comment removed.
+    ///
+    /// Keys of the variables defined by the specification are lowercased, other keys are kept
+    /// verbatim. Unlike [`AdsTxt`], the value keeps the casing of the file.
+    Variable { key: &'a str, value: &'a str },
+    /// A data record.
+    System(AdsTxtSystemRef<'a>),
+}
+
+impl<'a> AdsTxtEntryRef<'a> {
+    /// Parses a single line, returning `None` for blank and comment-only lines.
+    pub(crate) fn parse(line: &'a str) -> Option<crate::Result<Self>> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            let value = value.split_once('#').map_or(value, |(v, _)| v);
+            Some(Ok(AdsTxtEntryRef::Variable {
+                key: key.trim(),
+                value: value.trim(),
+            }))
+        } else {
+            Some(AdsTxtSystemRef::parse(line).map(AdsTxtEntryRef::System))
+        }
+    }
+}
+
+/// Prefixes a parsing error with the number of the line it occurred on.
+fn line_error(line_number: usize, err: crate::Error) -> crate::Error {
+    serde_plain::Error::custom(format!("line {}: {}", line_number, err)).into()
+}
+
+/// Converts a token of the ads.txt 1.1 grammar into an entry.
+fn entry(token: AdsTxtToken<'_>) -> crate::Result<(usize, AdsTxtEntryRef<'_>)> {
+    let line_number = token.line_number;
+    let entry = match token.directive.map_err(|e| line_error(line_number, e))? {
+        AdsTxtDirective::Variable { variable, value } => AdsTxtEntryRef::Variable {
+            key: variable.key(),
+            value,
+        },
+        AdsTxtDirective::UnknownVariable { key, value } => AdsTxtEntryRef::Variable { key, value },
+        AdsTxtDirective::Record(system) => AdsTxtEntryRef::System(system),
+    };
+    Ok((line_number, entry))
+}
+
+/// Iterator over the entries of an ads.txt file held in memory.
+///
+/// Created by [`AdsTxt::entries`]. Each item is the 1-based line number together with the
+/// entry, or the error of an invalid line. The iterator keeps going after an error, so callers
+/// can choose between failing fast and skipping invalid lines.
+#[derive(Clone, Debug)]
+pub struct AdsTxtEntries<'a> {
+    tokens: AdsTxtTokens<'a>,
+}
+
+impl<'a> Iterator for AdsTxtEntries<'a> {
+    type Item = crate::Result<(usize, AdsTxtEntryRef<'a>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tokens.next().map(entry)
+    }
+}
+
+impl AdsTxt {
+    /// Iterates over the variables and data records of `content` without allocating.
+    ///
+    /// Lines are validated with the same rules as [`AdsTxt::from_str`](std::str::FromStr), but
+    /// records are borrowed from `content` instead of being collected into an [`AdsTxt`], which
+    /// suits files with hundreds of thousands of lines.
+    pub fn entries(content: &str) -> AdsTxtEntries<'_> {
+        AdsTxtEntries {
+            tokens: AdsTxtGrammar::ADS_TXT_1_1.tokenize(content),
+        }
+    }
+}
+
+/// Reads the entries of an ads.txt file one line at a time from a [`BufRead`].
+///
+/// Lines are split and interpreted as by [`AdsTxt::entries`]. Only the current line is held in
+/// memory. As entries borrow the reader's line buffer, they
+/// are returned by [`AdsTxtReader::next_entry`] rather than through [`Iterator`]:
+///
+/// ```
+/// use iab_specs_ads_txt::{AdsTxtEntryRef, AdsTxtReader};
+///
+/// let mut reader = AdsTxtReader::new("redssp.com, 1, DIRECT\nbluessp.com, 2, RESELLER".as_bytes());
+/// let mut count = 0;
+/// while let Some(entry) = reader.next_entry() {
+///     if let (_, AdsTxtEntryRef::System(_)) = entry.unwrap() {
+///         count += 1;
+///     }
+/// }
+/// assert_eq!(count, 2);
+/// ```
+#[derive(Debug)]
+pub struct AdsTxtReader<R: BufRead> {
+    reader: R,
+    buffer: Vec<u8>,
+    line_number: usize,
+}
+
+impl<R: BufRead> AdsTxtReader<R> {
+    /// Creates a reader over `reader`.
+    pub fn new(reader: R) -> Self {
+        AdsTxtReader {
+            reader,
+            buffer: vec![],
+            line_number: 0,
+        }
+    }
+
+    /// Reads the next entry, skipping blank and comment lines.
+    ///
+    /// Returns the 1-based line number with the entry, an error if the line is invalid or
+    /// cannot be read, and `None` at the end of the input.
+    pub fn next_entry(&mut self) -> Option<crate::Result<(usize, AdsTxtEntryRef<'_>)>> {
+        loop {
+            self.buffer.clear();
+            match read_line(&mut self.reader, &mut self.buffer) {
+                Ok(false) => return None,
+                Ok(true) => self.line_number += 1,
+                Err(e) => return Some(Err(e.into())),
+            }
+            match std::str::from_utf8(&self.buffer) {
+                Ok(line) if line.trim().is_empty() || line.trim().starts_with('#') => {}
+                Ok(_) => break,
+                Err(e) => return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e).into())),
+            }
+        }
+        let line = std::str::from_utf8(&self.buffer).unwrap_or_default();
+        AdsTxtGrammar::ADS_TXT_1_1
+            .token(self.line_number, line)
+            .map(entry)
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AdsTxtSystem, SellerRelationType};
+    use std::str::FromStr;
+
+    const CONTENT: &str = "# header\r\ncontact=AdOps@Example.com # ops\r\n\r\nGreenAdExchange.com, XF7342, DIRECT, 5jyxf8k54 # Main\r\nredssp.com, 57013, RESELLER\r\n";
+
+    #[test]
+    fn entries_borrow_from_content() {
+        let entries: Vec<_> = AdsTxt::entries(CONTENT)
+            .collect::<crate::Result<_>>()
+            .unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(
+            entries[0],
+            (
+                2,
+                AdsTxtEntryRef::Variable {
+                    key: "contact",
+                    value: "AdOps@Example.com"
+                }
+            )
+        );
+        match entries[1] {
+            (4, AdsTxtEntryRef::System(system)) => {
+                assert_eq!(system.domain, "GreenAdExchange.com");
+                assert_eq!(system.relation, SellerRelationType::Direct);
+                assert_eq!(system.comment, Some("Main"));
+            }
+            other => panic!("unexpected entry {:?}", other),
+        }
+    }
+
+    #[test]
+    fn entries_match_owned_parser() {
+        let ads_txt = AdsTxt::from_str(CONTENT).unwrap();
+        let systems: Vec<AdsTxtSystem> = AdsTxt::entries(CONTENT)
+            .filter_map(|entry| match entry.unwrap() {
+                (_, AdsTxtEntryRef::System(system)) => Some(system.into_owned()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(systems, ads_txt.systems);
+    }
+
+    #[test]
+    fn entries_report_invalid_lines_and_continue() {
+        let content = "redssp.com, 1, DIRECT\nnot a valid line\nbluessp.com, 2, DIRECTE\ngreenssp.com, 3, RESELLER";
+        let results: Vec<_> = AdsTxt::entries(content).collect();
+        assert_eq!(results.len(), 4);
+        assert!(results[0].is_ok());
+        let err = results[1].as_ref().unwrap_err().to_string();
+        assert!(err.starts_with("line 2: "), "{err}");
+        assert!(
+            results[2]
+                .as_ref()
+                .unwrap_err()
+                .to_string()
+                .starts_with("line 3: ")
+        );
+        assert!(matches!(results[3], Ok((4, AdsTxtEntryRef::System(_)))));
+        assert!(AdsTxt::from_str(content).is_err());
+    }
+
+    #[test]
+    fn reader_matches_entries() {
+        let mut reader = AdsTxtReader::new(CONTENT.as_bytes());
+        let mut from_reader = vec![];
+        while let Some(entry) = reader.next_entry() {
+            let (line_number, entry) = entry.unwrap();
+            let entry = match entry {
+                AdsTxtEntryRef::Variable { key, value } => format!("{key}={value}"),
+                AdsTxtEntryRef::System(system) => system.into_owned().to_string(),
+            };
+            from_reader.push((line_number, entry));
+        }
+        let from_entries: Vec<_> = AdsTxt::entries(CONTENT)
+            .map(|entry| match entry.unwrap() {
+                (n, AdsTxtEntryRef::Variable { key, value }) => (n, format!("{key}={value}")),
+                (n, AdsTxtEntryRef::System(system)) => (n, system.into_owned().to_string()),
+            })
+            .collect();
+        assert_eq!(from_reader, from_entries);
+        assert!(reader.next_entry().is_none());
+    }
+
+    #[test]
+    fn reader_splits_cr_line_endings() {
+        let content = "redssp.com, 1, DIRECT\rbluessp.com, 2, RESELLER";
+        let mut reader = AdsTxtReader::new(content.as_bytes());
+        let mut line_numbers = vec![];
+        while let Some(entry) = reader.next_entry() {
+            line_numbers.push(entry.unwrap().0);
+        }
+        assert_eq!(line_numbers, vec![1, 2]);
+        assert_eq!(AdsTxt::entries(content).count(), 2);
+    }
+
+    #[test]
+    fn reader_reports_invalid_lines_and_io_errors() {
+        let mut reader = AdsTxtReader::new("# c\nnot a valid line\n".as_bytes());
+        let err = reader.next_entry().unwrap().unwrap_err();
+        assert!(err.to_string().starts_with("line 2: "));
+        assert!(reader.next_entry().is_none());
+
+        let mut reader = AdsTxtReader::new(&[0xff, 0xfe, b'\n'][..]);
+        assert!(matches!(
+            reader.next_entry(),
+            Some(Err(crate::Error::StdIoError(_)))
+        ));
+    }
+}
@@ -1,7 +1,5 @@
-use crate::SellerRelationType;
+use crate::{AdsTxtDiagnosticCode, AdsTxtSystemRef, SellerRelationType};
 use derive_builder::Builder;
-use iab_specs_core::slice_up_to;
-use serde::de::{Error, Unexpected};
 use serde_with::{DeserializeFromStr, SerializeDisplay, serde_as};
 use std::fmt;
 use std::fmt::{Display, Formatter};
@@ -10,7 +8,7 @@ use std::str::FromStr;
 /// The following defines the contents within each field. We refer to the IAB OpenRTB [7]
 /// and IAB OpenDirect [9] specs as needed.
 #[serde_as]
-#[derive(Builder, DeserializeFromStr, SerializeDisplay, Clone, Debug)]
+#[derive(Builder, DeserializeFromStr, SerializeDisplay, Clone, Debug, PartialEq, Eq)]
 #[builder(build_fn(error = "crate::Error"))]
 pub struct AdsTxtSystem {
     /// Domain name of the advertising system
@@ -66,6 +64,22 @@ impl AdsTxtSystem {
     pub fn builder() -> AdsTxtSystemBuilder {
         AdsTxtSystemBuilder::create_empty()
     }
+
+    /// Classifies why `content` is not a valid record.
+    pub(crate) fn diagnose(content: &str) -> AdsTxtDiagnosticCode {
+        let data = content.split_once('#').map_or(content, |(data, _)| data);
+        let fields: Vec<&str> = data.split(',').map(str::trim).collect();
+        if fields.len() > 4 {
+            AdsTxtDiagnosticCode::TooManyFields
+        } else if fields.len() >= 3
+            && fields[..3].iter().all(|f| !f.is_empty())
+            && SellerRelationType::from_str(fields[2]).is_err()
+        {
+            AdsTxtDiagnosticCode::InvalidRelation
+        } else {
+            AdsTxtDiagnosticCode::MissingField
+        }
+    }
 }
 
 impl Display for AdsTxtSystem {
@@ -90,88 +104,7 @@ impl FromStr for AdsTxtSystem {
     type Err = crate::Error;
 
     fn from_str(content: &str) -> Result<Self, Self::Err> {
-        let mut domain = None;
-        let mut publisher_id = None;
-        let mut relation = None;
-        let mut cert_id = None;
-        let mut comment = None;
-        let mut s = 0;
-        let mut field_index = 0;
-        for (idx, c) in content.bytes().enumerate() {
-            if c == b',' {
-                let field_value = content[s..idx].trim().to_lowercase();
-                if field_value.is_empty() {
-                    return Err(serde_plain::Error::invalid_value(
-                        Unexpected::Str(slice_up_to!(content, 100)),
-                        &"'domain','publisher_id','relation'[,'cert_id'>][# comments]",
-                    )
-                    .into());
-                }
-
-                match field_index {
-                    0 => domain = Some(field_value),
-                    1 => publisher_id = Some(field_value),
-                    2 => {
-                        let rel = SellerRelationType::from_str(&field_value)?;
-                        relation = Some(rel)
-                    }
-                    _ => {
-                        return Err(serde_plain::Error::invalid_value(
-                            Unexpected::Str(slice_up_to!(field_value, 100)),
-                            &"'domain','publisher_id','relation'[,'cert_id'>][# comments]",
-                        )
-                        .into());
-                    }
-                }
-                field_index += 1;
-                s = idx + 1;
-            } else if c == b'#' {
-                if field_index == 2 {
-                    let field_value = content[s..idx].trim().to_lowercase();
-                    let rel = SellerRelationType::from_str(&field_value)?;
-                    relation = Some(rel);
-                    s = idx + 1;
-                } else if field_index == 3 {
-                    let field_value = content[s..idx].trim().to_lowercase();
-                    if !field_value.is_empty() {
-                        cert_id = Some(field_value);
-                    }
-                    s = idx + 1;
-                }
-                if s < content.len() {
-                    let field_value = content[s..].trim().to_lowercase();
-                    if !field_value.is_empty() {
-                        comment = Some(field_value.to_string());
-                    }
-                }
-                break;
-            }
-            if idx + 1 == content.len() {
-                let field_value = content[s..].trim().to_lowercase();
-                if !field_value.is_empty() {
-                    if field_index == 2 {
-                        let rel = SellerRelationType::from_str(&field_value)?;
-                        relation = Some(rel)
-                    } else {
-                        cert_id = Some(field_value);
-                    }
-                }
-                break;
-            }
-        }
-
-        let domain = domain.ok_or_else(|| serde_plain::Error::missing_field("domain"))?;
-        let publisher_id =
-            publisher_id.ok_or_else(|| serde_plain::Error::missing_field("publisher_id"))?;
-        let relation = relation.ok_or_else(|| serde_plain::Error::missing_field("relation"))?;
-
-        AdsTxtSystem::builder()
-            .domain(domain)
-            .publisher_id(publisher_id)
-            .relation(relation)
-            .cert_id(cert_id)
-            .comment(comment)
-            .build()
+        AdsTxtSystemRef::parse(content).map(AdsTxtSystemRef::into_owned)
     }
 }
 
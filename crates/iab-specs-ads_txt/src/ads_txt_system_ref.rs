@@ -0,0 +1,160 @@
+use crate::{AdsTxtSystem, SellerRelationType};
+use iab_specs_core::slice_up_to;
+use serde::de::{Error, Unexpected};
+use std::str::FromStr;
+
+/// A data record borrowed from the parsed content.
+///
+/// This is the allocation-free counterpart of [`AdsTxtSystem`], as yielded by
+/// [`AdsTxtEntries`](crate::AdsTxtEntries). Fields are trimmed but, unlike
+/// [`AdsTxtSystem`], keep the casing of the file; compare them with
+/// [`str::eq_ignore_ascii_case`] or convert the record with [`AdsTxtSystemRef::into_owned`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AdsTxtSystemRef<'a> {
+    /// Domain name of the advertising system.
+    pub domain: &'a str,
+
+    /// Publisher’s Account ID.
+    pub publisher_id: &'a str,
+
+    /// Type of Account/ Relationship.
+    pub relation: SellerRelationType,
+
+    /// Certification Authority ID.
+    pub cert_id: Option<&'a str>,
+
+    /// Comment following the record.
+    pub comment: Option<&'a str>,
+}
+
+impl<'a> AdsTxtSystemRef<'a> {
+    /// Parses a data record line with the same rules as [`AdsTxtSystem::from_str`].
+    pub fn parse(content: &'a str) -> crate::Result<Self> {
+        const EXPECTED: &str = "'domain','publisher_id','relation'[,'cert_id'>][# comments]";
+        let mut domain = None;
+        let mut publisher_id = None;
+        let mut relation = None;
+        let mut cert_id = None;
+        let mut comment = None;
+        let mut s = 0;
+        let mut field_index = 0;
+        for (idx, c) in content.bytes().enumerate() {
+            if c == b',' {
+                let field_value = content[s..idx].trim();
+                if field_value.is_empty() {
+                    return Err(serde_plain::Error::invalid_value(
+                        Unexpected::Str(slice_up_to!(content, 100)),
+                        &EXPECTED,
+                    )
+                    .into());
+                }
+
+                match field_index {
+                    0 => domain = Some(field_value),
+                    1 => publisher_id = Some(field_value),
+                    2 => relation = Some(SellerRelationType::from_str(field_value)?),
+                    _ => {
+                        return Err(serde_plain::Error::invalid_value(
+                            Unexpected::Str(slice_up_to!(field_value, 100)),
+                            &EXPECTED,
+                        )
+                        .into());
+                    }
+                }
+                field_index += 1;
+                s = idx + 1;
+            } else if c == b'#' {
+                if field_index == 2 {
+                    let field_value = content[s..idx].trim();
+                    relation = Some(SellerRelationType::from_str(field_value)?);
+                    s = idx + 1;
+                } else if field_index == 3 {
+                    let field_value = content[s..idx].trim();
+                    if !field_value.is_empty() {
+                        cert_id = Some(field_value);
+                    }
+                    s = idx + 1;
+                }
+                if s < content.len() {
+                    let field_value = content[s..].trim();
+                    if !field_value.is_empty() {
+                        comment = Some(field_value);
+                    }
+                }
+                break;
+            }
+            if idx + 1 == content.len() {
+                let field_value = content[s..].trim();
+                if !field_value.is_empty() {
+                    if field_index == 2 {
+                        relation = Some(SellerRelationType::from_str(field_value)?);
+                    } else {
+                        cert_id = Some(field_value);
+                    }
+                }
+                break;
+            }
+        }
+
+        let domain = domain.ok_or_else(|| serde_plain::Error::missing_field("domain"))?;
+        let publisher_id =
+            publisher_id.ok_or_else(|| serde_plain::Error::missing_field("publisher_id"))?;
+        let relation = relation.ok_or_else(|| serde_plain::Error::missing_field("relation"))?;
+
+        Ok(AdsTxtSystemRef {
+            domain,
+            publisher_id,
+            relation,
+            cert_id,
+            comment,
+        })
+    }
+
+    /// Converts the record into an [`AdsTxtSystem`], lowercasing its fields like
+    /// [`AdsTxtSystem::from_str`] does.
+    pub fn into_owned(self) -> AdsTxtSystem {
+        AdsTxtSystem {
+            domain: self.domain.to_lowercase(),
+            publisher_id: self.publisher_id.to_lowercase(),
+            relation: self.relation,
+            cert_id: self.cert_id.map(str::to_lowercase),
+            comment: self.comment.map(str::to_lowercase),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_borrows_fields() {
+        let line = "GreenAdExchange.com, XF7342, DIRECT, 5JYXF8K54 # My Comment";
+        let system = AdsTxtSystemRef::parse(line).unwrap();
+        assert_eq!(system.domain, "GreenAdExchange.com");
+        assert_eq!(system.publisher_id, "XF7342");
+        assert_eq!(system.relation, SellerRelationType::Direct);
+        assert_eq!(system.cert_id, Some("5JYXF8K54"));
+        assert_eq!(system.comment, Some("My Comment"));
+        assert!(std::ptr::eq(system.domain.as_ptr(), line.as_ptr()));
+
+        let owned = system.into_owned();
+        assert_eq!(owned, AdsTxtSystem::from_str(line).unwrap());
+        assert_eq!(owned.domain, "greenadexchange.com");
+    }
+
+    #[test]
+    fn parse_rejects_like_owned_parser() {
+        for line in [
+            "",
+            ",XF7342, DIRECT",
+            "greenadexchange.com,, DIRECT",
+            "greenadexchange.com, XF7342",
+            "greenadexchange.com, XF7342, DIRECTE",
+            "greenadexchange.com, XF7342, DIRECT, abc, extra",
+        ] {
+            assert!(AdsTxtSystemRef::parse(line).is_err(), "{line}");
+            assert!(AdsTxtSystem::from_str(line).is_err(), "{line}");
+        }
+    }
+}
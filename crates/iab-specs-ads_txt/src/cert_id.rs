@@ -0,0 +1,106 @@
+use crate::AdsTxtSystem;
+use iab_specs_core::slice_up_to;
+use serde::de::{Error, Unexpected};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A certification authority ID, as declared in the fourth field of an ads.txt record.
+///
+/// The only certification authority in use is the Trustworthy Accountability Group, whose
+/// TAG IDs are made of 16 hexadecimal characters. IDs are compared case-insensitively and
+/// stored lowercased.
+#[derive(
+    DeserializeFromStr, SerializeDisplay, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub struct CertId(String);
+
+impl CertId {
+    /// Length of a TAG ID.
+    pub const LEN: usize = 16;
+
+    /// The ID, lowercased.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for CertId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for CertId {
+    type Err = crate::Error;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let content = content.trim();
+        if content.len() != CertId::LEN || !content.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return Err(serde_plain::Error::invalid_value(
+                Unexpected::Str(slice_up_to!(content, 100)),
+                &"a TAG ID of 16 hexadecimal characters",
+            )
+            .into());
+        }
+        Ok(CertId(content.to_ascii_lowercase()))
+    }
+}
+
+impl AdsTxtSystem {
+    /// The certification authority ID of the record, validated as a [`CertId`].
+    ///
+    /// Returns `None` if the record has no certification authority ID.
+    pub fn tag_id(&self) -> Option<crate::Result<CertId>> {
+        self.cert_id.as_deref().map(CertId::from_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_valid_tag_ids() {
+        let cert_id = CertId::from_str("F08C47FEC0942FA0").unwrap();
+        assert_eq!(cert_id.as_str(), "f08c47fec0942fa0");
+        assert_eq!(cert_id, CertId::from_str(" f08c47fec0942fa0 ").unwrap());
+        assert_eq!(cert_id.to_string(), "f08c47fec0942fa0");
+    }
+
+    #[test]
+    fn reject_malformed_tag_ids() {
+        for content in [
+            "",
+            "f08c47fec0942fa",
+            "f08c47fec0942fa00",
+            "g08c47fec0942fa0",
+            "5jyxf8k54",
+        ] {
+            assert!(CertId::from_str(content).is_err(), "{content}");
+        }
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let cert_id: CertId = serde_json::from_str("\"F08C47FEC0942FA0\"").unwrap();
+        assert_eq!(
+            serde_json::to_string(&cert_id).unwrap(),
+            "\"f08c47fec0942fa0\""
+        );
+        assert!(serde_json::from_str::<CertId>("\"abc\"").is_err());
+    }
+
+    #[test]
+    fn system_tag_id() {
+        let system = AdsTxtSystem::from_str("google.com, pub-1, DIRECT, f08c47fec0942fa0").unwrap();
+        assert!(system.tag_id().unwrap().is_ok());
+
+        let system = AdsTxtSystem::from_str("greenadexchange.com, 1, DIRECT, 5jyxf8k54").unwrap();
+        assert!(system.tag_id().unwrap().is_err());
+
+        let system = AdsTxtSystem::from_str("google.com, pub-1, DIRECT").unwrap();
+        assert!(system.tag_id().is_none());
+    }
+}
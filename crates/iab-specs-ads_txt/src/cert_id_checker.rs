@@ -0,0 +1,199 @@
+use crate::{AdsTxtSystem, CertId};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::hash::BuildHasher;
+use std::str::FromStr;
+
+/// Table of the certification authority IDs expected for advertising systems, used by
+/// [`CertIdChecker`].
+///
+/// An in-memory implementation is provided for `HashMap<String, CertId>` keyed by lowercased
+/// advertising system domain.
+pub trait CertIdRegistry {
+    /// The ID `domain` is expected to declare, or `None` if the domain is unknown.
+    fn expected_cert_id(&self, domain: &str) -> Option<&CertId>;
+}
+
+impl<S: BuildHasher> CertIdRegistry for HashMap<String, CertId, S> {
+    fn expected_cert_id(&self, domain: &str) -> Option<&CertId> {
+        self.get(domain)
+    }
+}
+
+/// An inconsistency in the certification authority IDs of a set of records.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CertIdFinding {
+    /// A declared ID is not a valid TAG ID.
+    InvalidFormat { domain: String, cert_id: String },
+
+    /// The same advertising system is declared with several different IDs.
+    Inconsistent {
+        domain: String,
+        cert_ids: Vec<CertId>,
+    },
+
+    /// A declared ID differs from the one of the registry.
+    Unexpected {
+        domain: String,
+        expected: CertId,
+        found: CertId,
+    },
+}
+
+/// Checks that each advertising system always uses the same certification authority ID.
+///
+/// Records are usually gathered from many ads.txt files, for instance all the files of a
+/// publisher network. Domains with an entry in the registry are also checked against it.
+pub struct CertIdChecker<R: CertIdRegistry> {
+    registry: R,
+}
+
+impl CertIdChecker<HashMap<String, CertId>> {
+    /// Creates a checker without registry, only looking for inconsistent IDs.
+    pub fn new() -> Self {
+        CertIdChecker {
+            registry: HashMap::new(),
+        }
+    }
+}
+
+impl Default for CertIdChecker<HashMap<String, CertId>> {
+    fn default() -> Self {
+        CertIdChecker::new()
+    }
+}
+
+impl<R: CertIdRegistry> CertIdChecker<R> {
+    /// Creates a checker comparing declared IDs with `registry`.
+    pub fn with_registry(registry: R) -> Self {
+        CertIdChecker { registry }
+    }
+
+    /// Checks `systems`. Invalid IDs are reported first, in the order they are found, followed
+    /// by the other findings ordered by domain.
+    pub fn check<'a>(
+        &self,
+        systems: impl IntoIterator<Item = &'a AdsTxtSystem>,
+    ) -> Vec<CertIdFinding> {
+        let mut findings = vec![];
+        let mut cert_ids: BTreeMap<&str, BTreeSet<CertId>> = BTreeMap::new();
+        for system in systems {
+            let Some(cert_id) = system.cert_id.as_deref() else {
+                continue;
+            };
+            match CertId::from_str(cert_id) {
+                Ok(cert_id) => {
+                    cert_ids
+                        .entry(system.domain.as_str())
+                        .or_default()
+                        .insert(cert_id);
+                }
+                Err(_) => {
+                    let finding = CertIdFinding::InvalidFormat {
+                        domain: system.domain.clone(),
+                        cert_id: cert_id.to_string(),
+                    };
+                    if !findings.contains(&finding) {
+                        findings.push(finding);
+                    }
+                }
+            }
+        }
+
+        for (domain, ids) in cert_ids {
+            if ids.len() > 1 {
+                findings.push(CertIdFinding::Inconsistent {
+                    domain: domain.to_string(),
+                    cert_ids: ids.iter().cloned().collect(),
+                });
+            }
+            if let Some(expected) = self.registry.expected_cert_id(domain) {
+                for found in ids.into_iter().filter(|id| id != expected) {
+                    findings.push(CertIdFinding::Unexpected {
+                        domain: domain.to_string(),
+                        expected: expected.clone(),
+                        found,
+                    });
+                }
+            }
+        }
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AdsTxt;
+
+    const GOOGLE: &str = "f08c47fec0942fa0";
+    const OTHER: &str = "0123456789abcdef";
+
+    fn systems(contents: &[&str]) -> Vec<AdsTxtSystem> {
+        contents
+            .iter()
+            .flat_map(|content| AdsTxt::from_str(content).unwrap().systems)
+            .collect()
+    }
+
+    #[test]
+    fn consistent_ids_have_no_findings() {
+        let systems = systems(&[
+            "google.com, pub-1, DIRECT, F08C47FEC0942FA0\nredssp.com, 1, DIRECT",
+            "google.com, pub-2, RESELLER, f08c47fec0942fa0",
+        ]);
+        assert!(CertIdChecker::new().check(&systems).is_empty());
+    }
+
+    #[test]
+    fn flag_inconsistent_and_invalid_ids() {
+        let systems = systems(&[
+            "google.com, pub-1, DIRECT, f08c47fec0942fa0\ngreenadexchange.com, 1, DIRECT, 5jyxf8k54",
+            "google.com, pub-2, RESELLER, 0123456789abcdef\ngreenadexchange.com, 2, DIRECT, 5jyxf8k54",
+        ]);
+        let findings = CertIdChecker::default().check(&systems);
+        assert_eq!(
+            findings,
+            vec![
+                CertIdFinding::InvalidFormat {
+                    domain: "greenadexchange.com".to_string(),
+                    cert_id: "5jyxf8k54".to_string(),
+                },
+                CertIdFinding::Inconsistent {
+                    domain: "google.com".to_string(),
+                    cert_ids: vec![
+                        CertId::from_str(OTHER).unwrap(),
+                        CertId::from_str(GOOGLE).unwrap()
+                    ],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn compare_with_registry() {
+        let registry: HashMap<String, CertId> = [
+            ("google.com".to_string(), CertId::from_str(GOOGLE).unwrap()),
+            ("redssp.com".to_string(), CertId::from_str(GOOGLE).unwrap()),
+        ]
+        .into_iter()
+        .collect();
+        let systems = systems(&[
+            "google.com, pub-1, DIRECT, f08c47fec0942fa0\nredssp.com, 1, DIRECT, 0123456789abcdef",
+        ]);
+        let findings = CertIdChecker::with_registry(registry).check(&systems);
+        assert_eq!(
+            findings,
+            vec![CertIdFinding::Unexpected {
+                domain: "redssp.com".to_string(),
+                expected: CertId::from_str(GOOGLE).unwrap(),
+                found: CertId::from_str(OTHER).unwrap(),
+            }]
+        );
+        assert_eq!(
+            serde_json::to_value(&findings[0]).unwrap()["type"],
+            "unexpected"
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{AdsTxtSystem, ManagerDomain};
+use iab_specs_core::slice_up_to;
+use serde::de::{Error, Unexpected};
+use std::collections::HashMap;
+
+/// Reduces the many spellings of an advertising system domain to one canonical key.
+///
+/// Publishers write the same exchange as `google.com`, `www.google.com`, `Google.com.` or
+/// `https://google.com/`. Canonicalisation:
+/// - trims whitespace and removes the scheme, path, port and trailing dots;
+/// - converts internationalised domain names to their lowercase punycode (IDNA) form;
+/// - removes a leading `www.` label;
+/// - optionally reduces the domain to its registrable part, the public suffix plus one label,
+///   when the `psl` feature is enabled;
+/// - finally maps known alternate domains to their canonical domain through an alias table.
+///
+/// ```
+/// use iab_specs_ads_txt::DomainCanonicalizer;
+///
+/// let canonicalizer = DomainCanonicalizer::new()
+///     .with_alias("doubleclick.net", "google.com")
+///     .unwrap();
+/// assert_eq!(canonicalizer.canonicalize("https://WWW.Google.com./").unwrap(), "google.com");
+/// assert_eq!(canonicalizer.canonicalize("doubleclick.net").unwrap(), "google.com");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DomainCanonicalizer {
+    #[cfg(feature = "psl")]
+    registrable_domain: bool,
+    aliases: HashMap<String, String>,
+}
+
+impl DomainCanonicalizer {
+    /// Creates a canonicalizer without aliases.
+    pub fn new() -> Self {
+        DomainCanonicalizer::default()
+    }
+
+    /// Sets whether domains are reduced to their registrable part, for instance
+    /// `ads.example.co.uk` to `example.co.uk`.
+    #[cfg(feature = "psl")]
+    pub fn with_registrable_domain(mut self, registrable_domain: bool) -> Self {
+        self.registrable_domain = registrable_domain;
+        self
+    }
+
+    /// Maps `alias` to `canonical`. Both domains are normalised first.
+    ///
+    /// Aliases are looked up both before and after the reduction to the registrable domain.
+    pub fn with_alias(mut self, alias: &str, canonical: &str) -> crate::Result<Self> {
+        self.aliases
+            .insert(normalize(alias)?, self.canonicalize(canonical)?);
+        Ok(self)
+    }
+
+    /// Maps each alias to its canonical domain, as [`DomainCanonicalizer::with_alias`].
+    pub fn with_aliases<'a>(
+        self,
+        aliases: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> crate::Result<Self> {
+        aliases
+            .into_iter()
+            .try_fold(self, |canonicalizer, (alias, canonical)| {
+                canonicalizer.with_alias(alias, canonical)
+            })
+    }
+
+    /// Returns the canonical form of `domain`.
+    ///
+    /// Fails if `domain` is empty or is not a valid domain name.
+    pub fn canonicalize(&self, domain: &str) -> crate::Result<String> {
+        let domain = normalize(domain)?;
+        if let Some(canonical) = self.aliases.get(&domain) {
+            return Ok(canonical.clone());
+        }
+        #[cfg(feature = "psl")]
+        if self.registrable_domain {
+            if let Some(registrable) = psl::domain_str(&domain) {
+                return Ok(self
+                    .aliases
+                    .get(registrable)
+                    .cloned()
+                    .unwrap_or_else(|| registrable.to_string()));
+            }
+        }
+        Ok(domain)
+    }
+}
+
+/// Normalises the spelling of `domain`, without alias or registrable domain reduction.
+fn normalize(domain: &str) -> crate::Result<String> {
+    let invalid = || -> crate::Error {
+        serde_plain::Error::invalid_value(
+            Unexpected::Str(slice_up_to!(domain, 100)),
+            &"a domain name",
+        )
+        .into()
+    };
+    let mut host = domain.trim();
+    if let Some((_, rest)) = host.split_once("://") {
+        host = rest;
+    }
+    host = host.split(['/', '?', '#']).next().unwrap_or_default();
+    if let Some((_, rest)) = host.rsplit_once('@') {
+        host = rest;
+    }
+    if let Some((name, port)) = host.rsplit_once(':') {
+        if port.bytes().all(|c| c.is_ascii_digit()) {
+            host = name;
+        }
+    }
+    let host = host.trim_end_matches('.');
+    if host.is_empty() {
+        return Err(invalid());
+    }
+    let host = idna::domain_to_ascii_strict(host).map_err(|_| invalid())?;
+    match host.strip_prefix("www.") {
+        Some(rest) if rest.contains('.') => Ok(rest.to_string()),
+        _ => Ok(host),
+    }
+}
+
+impl AdsTxtSystem {
+    /// The canonical form of the advertising system domain.
+    pub fn canonical_domain(&self, canonicalizer: &DomainCanonicalizer) -> crate::Result<String> {
+        canonicalizer.canonicalize(&self.domain)
+    }
+}
+
+impl ManagerDomain {
+    /// The canonical form of the manager domain.
+    pub fn canonical_domain(&self, canonicalizer: &DomainCanonicalizer) -> crate::Result<String> {
+        canonicalizer.canonicalize(&self.domain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn normalize_spellings() {
+        let canonicalizer = DomainCanonicalizer::new();
+        for domain in [
+            "google.com",
+            " Google.com ",
+            "google.com.",
+            "www.google.com",
+            "WWW.GOOGLE.COM.",
+            "https://www.google.com/ads.txt",
+            "http://google.com:8080?x=1",
+        ] {
+            assert_eq!(
+                canonicalizer.canonicalize(domain).unwrap(),
+                "google.com",
+                "{domain}"
+            );
+        }
+        assert_eq!(canonicalizer.canonicalize("www.com").unwrap(), "www.com");
+        assert_eq!(
+            canonicalizer.canonicalize("ads.example.co.uk").unwrap(),
+            "ads.example.co.uk"
+        );
+    }
+
+    #[test]
+    fn convert_idna_to_punycode() {
+        let canonicalizer = DomainCanonicalizer::new();
+        assert_eq!(
+            canonicalizer.canonicalize("Bücher.example").unwrap(),
+            "xn--bcher-kva.example"
+        );
+        assert_eq!(
+            canonicalizer.canonicalize("xn--bcher-kva.example").unwrap(),
+            "xn--bcher-kva.example"
+        );
+    }
+
+    #[test]
+    fn reject_invalid_domains() {
+        let canonicalizer = DomainCanonicalizer::new();
+        for domain in ["", " . ", "https://", "exa mple.com"] {
+            assert!(canonicalizer.canonicalize(domain).is_err(), "{domain}");
+        }
+    }
+
+    #[test]
+    fn collapse_aliases() {
+        let canonicalizer = DomainCanonicalizer::new()
+            .with_aliases([
+                ("doubleclick.net", "google.com"),
+                ("https://www.AppNexus.com", "xandr.com"),
+            ])
+            .unwrap();
+        assert_eq!(
+            canonicalizer.canonicalize("DoubleClick.net.").unwrap(),
+            "google.com"
+        );
+        assert_eq!(
+            canonicalizer.canonicalize("appnexus.com").unwrap(),
+            "xandr.com"
+        );
+
+        let system = AdsTxtSystem::from_str("www.doubleclick.net, pub-1, DIRECT").unwrap();
+        assert_eq!(
+            system.canonical_domain(&canonicalizer).unwrap(),
+            "google.com"
+        );
+        let manager_domain = ManagerDomain::from_str("WWW.APPNEXUS.COM, FR").unwrap();
+        assert_eq!(
+            manager_domain.canonical_domain(&canonicalizer).unwrap(),
+            "xandr.com"
+        );
+    }
+
+    #[cfg(feature = "psl")]
+    #[test]
+    fn reduce_to_registrable_domain() {
+        let canonicalizer = DomainCanonicalizer::new()
+            .with_registrable_domain(true)
+            .with_alias("doubleclick.net", "google.com")
+            .unwrap();
+        assert_eq!(
+            canonicalizer.canonicalize("ads.example.co.uk").unwrap(),
+            "example.co.uk"
+        );
+        assert_eq!(
+            canonicalizer
+                .canonicalize("securepubads.g.doubleclick.net")
+                .unwrap(),
+            "google.com"
+        );
+    }
+}
@@ -1,11 +1,35 @@
 pub use iab_specs_core::{DefaultExt, Error, Extension, Result};
 
+mod ads_txt_crawl;
+mod ads_txt_diagnostic;
+mod ads_txt_diff;
+mod ads_txt_document;
+mod ads_txt_grammar;
+mod ads_txt_lint;
+mod ads_txt_resolver;
 mod ads_txt_root;
+mod ads_txt_stream;
 mod ads_txt_system;
+mod ads_txt_system_ref;
+mod cert_id;
+mod cert_id_checker;
+mod domain_canonicalizer;
 mod manager_domain;
 mod seller_relation_type;
 
+pub use ads_txt_crawl::*;
+pub use ads_txt_diagnostic::*;
+pub use ads_txt_diff::*;
+pub use ads_txt_document::*;
+pub use ads_txt_grammar::*;
+pub use ads_txt_lint::*;
+pub use ads_txt_resolver::*;
 pub use ads_txt_root::*;
+pub use ads_txt_stream::*;
 pub use ads_txt_system::*;
+pub use ads_txt_system_ref::*;
+pub use cert_id::*;
+pub use cert_id_checker::*;
+pub use domain_canonicalizer::*;
 pub use manager_domain::*;
 pub use seller_relation_type::*;
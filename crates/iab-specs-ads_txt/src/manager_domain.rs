@@ -8,7 +8,7 @@ use std::str::FromStr;
 
 /// The business domain of a primary or exclusive monetization
 /// partner of the publishers inventory.
-#[derive(Builder, DeserializeFromStr, SerializeDisplay, Clone, Debug)]
+#[derive(Builder, DeserializeFromStr, SerializeDisplay, Clone, Debug, PartialEq, Eq)]
 #[builder(build_fn(error = "crate::Error"))]
 pub struct ManagerDomain {
     #[builder(setter(into))]
@@ -5,7 +5,9 @@ use std::fmt;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
-#[derive(Clone, DeserializeFromStr, SerializeDisplay, Debug, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(
+    Clone, Copy, DeserializeFromStr, SerializeDisplay, Debug, Ord, PartialOrd, Eq, PartialEq,
+)]
 pub enum SellerRelationType {
     // A value of ‘DIRECT’ indicates that the
     // Publisher (content owner) directly controls the
@@ -108,7 +108,7 @@ mod tests {
 
         for (json_str, expected) in values {
             let result: EmbeddingType = serde_json::from_str(&format!("\"{}\"", json_str))
-                .expect(&format!("Failed to deserialize: {}", json_str));
+                .unwrap_or_else(|_| panic!("Failed to deserialize: {}", json_str));
             assert_eq!(
                 result, expected,
                 "Failed for value: {} (expected {:?}, got {:?})",
@@ -155,9 +155,9 @@ mod tests {
 
         for original in values {
             let json = serde_json::to_string(&original)
-                .expect(&format!("Failed to serialize: {:?}", original));
-            let deserialized: EmbeddingType =
-                serde_json::from_str(&json).expect(&format!("Failed to deserialize: {}", json));
+                .unwrap_or_else(|_| panic!("Failed to serialize: {:?}", original));
+            let deserialized: EmbeddingType = serde_json::from_str(&json)
+                .unwrap_or_else(|_| panic!("Failed to deserialize: {}", json));
             assert_eq!(
                 original, deserialized,
                 "Serialization roundtrip failed for {:?}",
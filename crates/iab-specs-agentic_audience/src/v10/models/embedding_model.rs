@@ -187,9 +187,8 @@ mod tests {
         // Spec: required fields must be present in JSON
         let json = r#"{"id":"m","version":"1.0","type":"encoder","dimension":384,"embedding_space_id":"s"}"#;
         let result: Result<EmbeddingModel, _> = serde_json::from_str(json);
-        match result {
-            Ok(m) => assert_eq!(m.metric, DistanceMetric::default()),
-            Err(_) => {}
+        if let Ok(m) = result {
+            assert_eq!(m.metric, DistanceMetric::default())
         }
     }
 }
@@ -0,0 +1,437 @@
+use crate::{AppAdsTxt, AppAdsTxtVersion, AppStore, AppStoreListing};
+use iab_specs_ads_txt::{AdsTxtDiagnostic, DomainCanonicalizer, SellerRelationType};
+use serde::{Deserialize, Serialize};
+
+/// Source of store listings and app-ads.txt files used by [`AppAdsTxtDiscoverer`].
+///
+/// Implementations perform the actual retrieval: querying or scraping the store for the
+/// listing, and an HTTPS request to `https://<domain>/app-ads.txt` for the file.
+pub trait AppAdsTxtFetcher {
+    /// Fetches the listing of `bundle` in `store`.
+    ///
+    /// Returns `Ok(None)` if the store has no such app.
+    fn fetch_listing(
+        &self,
+        store: &AppStore,
+        bundle: &str,
+    ) -> crate::Result<Option<AppStoreListing>>;
+
+    /// Fetches the app-ads.txt content published on `domain`.
+    ///
+    /// Returns `Ok(None)` if the domain does not publish an app-ads.txt file.
+    fn fetch_app_ads_txt(&self, domain: &str) -> crate::Result<Option<String>>;
+}
+
+/// Outcome of the discovery of the app-ads.txt file of an app.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AppAdsTxtDiscovery {
+    /// The file was found and parsed. `app_ads_txt` holds the valid records, the lines
+    /// skipped as invalid are reported in `diagnostics`.
+    Found {
+        listing: AppStoreListing,
+        domain: String,
+        app_ads_txt: AppAdsTxt,
+        diagnostics: Vec<AdsTxtDiagnostic>,
+    },
+
+    /// The store has no listing for the bundle.
+    ListingNotFound { store: AppStore, bundle: String },
+
+    /// The listing does not declare a developer website.
+    NoDeveloperUrl { listing: AppStoreListing },
+
+    /// The developer website does not publish an app-ads.txt file.
+    AppAdsTxtNotFound {
+        listing: AppStoreListing,
+        domain: String,
+    },
+}
+
+/// Follows the app-ads.txt discovery chain: from the store bundle to the store listing, from
+/// the developer URL of the listing to the app-ads.txt file on that domain.
+pub struct AppAdsTxtDiscoverer<F: AppAdsTxtFetcher> {
+    fetcher: F,
+    canonicalizer: DomainCanonicalizer,
+}
+
+impl<F: AppAdsTxtFetcher> AppAdsTxtDiscoverer<F> {
+    /// Creates a discoverer retrieving listings and files with `fetcher`.
+    pub fn new(fetcher: F) -> Self {
+        AppAdsTxtDiscoverer {
+            fetcher,
+            canonicalizer: DomainCanonicalizer::new(),
+        }
+    }
+
+    /// Sets the canonicalizer reducing developer URLs to the domain hosting app-ads.txt.
+    pub fn with_canonicalizer(mut self, canonicalizer: DomainCanonicalizer) -> Self {
+        self.canonicalizer = canonicalizer;
+        self
+    }
+
+    /// Discovers the app-ads.txt file of `bundle` in `store`.
+    ///
+    /// Files are parsed leniently as [`AppAdsTxtVersion::V1_1`], which also accepts
+    /// app-ads.txt 1.0 files: as the specification requires, invalid lines are ignored and
+    /// reported as diagnostics. Fails if fetching fails or if the developer URL is not a
+    /// valid URL.
+    pub fn discover(&self, store: &AppStore, bundle: &str) -> crate::Result<AppAdsTxtDiscovery> {
+        let Some(listing) = self.fetcher.fetch_listing(store, bundle)? else {
+            return Ok(AppAdsTxtDiscovery::ListingNotFound {
+                store: store.clone(),
+                bundle: bundle.to_string(),
+            });
+        };
+        let domain = match listing.app_ads_txt_domain(&self.canonicalizer) {
+            Some(domain) => domain?,
+            None => return Ok(AppAdsTxtDiscovery::NoDeveloperUrl { listing }),
+        };
+        match self.fetcher.fetch_app_ads_txt(&domain)? {
+            Some(content) => {
+                let report = AppAdsTxtVersion::V1_1.grammar().parse_lenient(&content);
+                Ok(AppAdsTxtDiscovery::Found {
+                    listing,
+                    domain,
+                    app_ads_txt: AppAdsTxt::from(report.ads_txt),
+                    diagnostics: report.diagnostics,
+                })
+            }
+            None => Ok(AppAdsTxtDiscovery::AppAdsTxtNotFound { listing, domain }),
+        }
+    }
+}
+
+impl AppAdsTxt {
+    /// The relationship under which the account `publisher_id` of the advertising system
+    /// `domain` is authorised to sell the app, or `None` if it is not.
+    ///
+    /// DIRECT takes precedence when the account is declared with both relationships.
+    pub fn authorization(&self, domain: &str, publisher_id: &str) -> Option<SellerRelationType> {
+        self.systems
+            .iter()
+            .filter(|s| {
+                s.domain.eq_ignore_ascii_case(domain)
+                    && s.publisher_id.eq_ignore_ascii_case(publisher_id)
+            })
+            .map(|s| s.relation)
+            .min()
+    }
+}
+
+#[cfg(feature = "openrtb_25")]
+mod openrtb {
+    use super::*;
+    use iab_specs_core::Extension;
+    use iab_specs_openrtb::v25::App;
+
+    /// Result of checking an OpenRTB [`App`] against its discovered app-ads.txt file.
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    pub enum AppAdsTxtVerdict {
+        /// The seller is declared in the app-ads.txt file.
+        Authorized { relation: SellerRelationType },
+
+        /// The seller is not declared in the app-ads.txt file.
+        Unauthorized,
+
+        /// `app.publisher.id` is missing, the seller cannot be identified.
+        MissingPublisherId,
+
+        /// `app.bundle` is not the bundle of the discovered listing.
+        BundleMismatch {
+            expected: String,
+            found: Option<String>,
+        },
+
+        /// `app.storeurl` points to another store than the discovered listing.
+        StoreMismatch {
+            expected: AppStore,
+            found: Option<AppStore>,
+        },
+
+        /// No app-ads.txt file was discovered for the app.
+        NotDiscovered,
+    }
+
+    impl AppAdsTxtDiscovery {
+        /// Checks that the seller of `app`, the account `app.publisher.id` on the advertising
+        /// system `exchange_domain`, is authorised by the discovered file.
+        pub fn check_app<Ext: Extension>(
+            &self,
+            app: &App<Ext>,
+            exchange_domain: &str,
+        ) -> AppAdsTxtVerdict {
+            let AppAdsTxtDiscovery::Found {
+                listing,
+                app_ads_txt,
+                ..
+            } = self
+            else {
+                return AppAdsTxtVerdict::NotDiscovered;
+            };
+            if app.bundle.as_deref() != Some(listing.bundle.as_str()) {
+                return AppAdsTxtVerdict::BundleMismatch {
+                    expected: listing.bundle.clone(),
+                    found: app.bundle.clone(),
+                };
+            }
+            if let Some(store_url) = &app.storeurl {
+                let found = AppStore::from_store_url(store_url);
+                if found.as_ref() != Some(&listing.store) {
+                    return AppAdsTxtVerdict::StoreMismatch {
+                        expected: listing.store.clone(),
+                        found,
+                    };
+                }
+            }
+            let Some(publisher_id) = app.publisher.as_ref().and_then(|p| p.id.as_deref()) else {
+                return AppAdsTxtVerdict::MissingPublisherId;
+            };
+            match app_ads_txt.authorization(exchange_domain, publisher_id) {
+                Some(relation) => AppAdsTxtVerdict::Authorized { relation },
+                None => AppAdsTxtVerdict::Unauthorized,
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use iab_specs_openrtb::v25::Publisher;
+        use std::str::FromStr;
+
+        fn discovery() -> AppAdsTxtDiscovery {
+            AppAdsTxtDiscovery::Found {
+                listing: AppStoreListing {
+                    store: AppStore::GooglePlay,
+                    bundle: "com.example.app".to_string(),
+                    developer_url: Some("https://example.com".to_string()),
+                },
+                domain: "example.com".to_string(),
+                app_ads_txt: AppAdsTxt::from_str("redssp.com, pub-1, DIRECT").unwrap(),
+                diagnostics: vec![],
+            }
+        }
+
+        fn app(bundle: &str, storeurl: Option<&str>, publisher_id: Option<&str>) -> App {
+            App::builder()
+                .bundle(Some(bundle.to_string()))
+                .storeurl(storeurl.map(str::to_string))
+                .publisher(Some(
+                    Publisher::builder()
+                        .id(publisher_id.map(str::to_string))
+                        .build()
+                        .unwrap(),
+                ))
+                .build()
+                .unwrap()
+        }
+
+        #[test]
+        fn check_authorized_and_unauthorized_sellers() {
+            let storeurl = "https://play.google.com/store/apps/details?id=com.example.app";
+            let discovery = discovery();
+            assert_eq!(
+                discovery.check_app(
+                    &app("com.example.app", Some(storeurl), Some("PUB-1")),
+                    "RedSSP.com"
+                ),
+                AppAdsTxtVerdict::Authorized {
+                    relation: SellerRelationType::Direct
+                }
+            );
+            assert_eq!(
+                discovery.check_app(&app("com.example.app", None, Some("pub-2")), "redssp.com"),
+                AppAdsTxtVerdict::Unauthorized
+            );
+            assert_eq!(
+                discovery.check_app(&app("com.example.app", None, None), "redssp.com"),
+                AppAdsTxtVerdict::MissingPublisherId
+            );
+        }
+
+        #[test]
+        fn check_bundle_and_store() {
+            let discovery = discovery();
+            assert_eq!(
+                discovery.check_app(&app("com.other.app", None, Some("pub-1")), "redssp.com"),
+                AppAdsTxtVerdict::BundleMismatch {
+                    expected: "com.example.app".to_string(),
+                    found: Some("com.other.app".to_string()),
+                }
+            );
+            assert_eq!(
+                discovery.check_app(
+                    &app(
+                        "com.example.app",
+                        Some("https://apps.apple.com/app/id1"),
+                        Some("pub-1")
+                    ),
+                    "redssp.com"
+                ),
+                AppAdsTxtVerdict::StoreMismatch {
+                    expected: AppStore::GooglePlay,
+                    found: Some(AppStore::AppleAppStore),
+                }
+            );
+            let not_found = AppAdsTxtDiscovery::ListingNotFound {
+                store: AppStore::Roku,
+                bundle: "1".to_string(),
+            };
+            assert_eq!(
+                not_found.check_app(&app("1", None, Some("pub-1")), "redssp.com"),
+                AppAdsTxtVerdict::NotDiscovered
+            );
+        }
+    }
+}
+
+#[cfg(feature = "openrtb_25")]
+pub use openrtb::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::Error;
+    use std::collections::HashMap;
+
+    struct Fetcher {
+        listings: Vec<AppStoreListing>,
+        files: HashMap<String, String>,
+    }
+
+    impl AppAdsTxtFetcher for Fetcher {
+        fn fetch_listing(
+            &self,
+            store: &AppStore,
+            bundle: &str,
+        ) -> crate::Result<Option<AppStoreListing>> {
+            if bundle == "down" {
+                return Err(serde_plain::Error::custom("timeout").into());
+            }
+            Ok(self
+                .listings
+                .iter()
+                .find(|l| &l.store == store && l.bundle == bundle)
+                .cloned())
+        }
+
+        fn fetch_app_ads_txt(&self, domain: &str) -> crate::Result<Option<String>> {
+            Ok(self.files.get(domain).cloned())
+        }
+    }
+
+    fn fetcher() -> Fetcher {
+        Fetcher {
+            listings: vec![
+                AppStoreListing {
+                    store: AppStore::GooglePlay,
+                    bundle: "com.example.app".to_string(),
+                    developer_url: Some("https://www.example.com/games".to_string()),
+                },
+                AppStoreListing {
+                    store: AppStore::Roku,
+                    bundle: "12345".to_string(),
+                    developer_url: None,
+                },
+                AppStoreListing {
+                    store: AppStore::AppleAppStore,
+                    bundle: "1234567890".to_string(),
+                    developer_url: Some("https://other.com".to_string()),
+                },
+                AppStoreListing {
+                    store: AppStore::GooglePlay,
+                    bundle: "com.broken.app".to_string(),
+                    developer_url: Some("https://broken.com".to_string()),
+                },
+            ],
+            files: [
+                (
+                    "example.com".to_string(),
+                    "ownerdomain=example.com\nredssp.com, pub-1, RESELLER\nredssp.com, pub-1, DIRECT"
+                        .to_string(),
+                ),
+                (
+                    "broken.com".to_string(),
+                    "redssp.com, pub-1, DIRECT\nredssp.com, pub-2, DIRECTE\nbluessp.com, 7, RESELLER"
+                        .to_string(),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        }
+    }
+
+    #[test]
+    fn discover_app_ads_txt() {
+        let discoverer = AppAdsTxtDiscoverer::new(fetcher());
+        let discovery = discoverer
+            .discover(&AppStore::GooglePlay, "com.example.app")
+            .unwrap();
+        match discovery {
+            AppAdsTxtDiscovery::Found {
+                domain,
+                app_ads_txt,
+                diagnostics,
+                ..
+            } => {
+                assert_eq!(domain, "example.com");
+                assert_eq!(diagnostics, vec![]);
+                assert_eq!(app_ads_txt.owner_domain.as_deref(), Some("example.com"));
+                assert_eq!(
+                    app_ads_txt.authorization("redssp.com", "pub-1"),
+                    Some(SellerRelationType::Direct)
+                );
+                assert_eq!(app_ads_txt.authorization("redssp.com", "pub-2"), None);
+            }
+            other => panic!("unexpected discovery {:?}", other),
+        }
+    }
+
+    #[test]
+    // Spec: app-ads.txt 1.0 — invalid lines are ignored
+    fn discover_skips_invalid_lines() {
+        let discoverer = AppAdsTxtDiscoverer::new(fetcher());
+        let discovery = discoverer
+            .discover(&AppStore::GooglePlay, "com.broken.app")
+            .unwrap();
+        match discovery {
+            AppAdsTxtDiscovery::Found {
+                app_ads_txt,
+                diagnostics,
+                ..
+            } => {
+                assert_eq!(app_ads_txt.systems.len(), 2);
+                assert_eq!(
+                    app_ads_txt.authorization("bluessp.com", "7"),
+                    Some(SellerRelationType::Reseller)
+                );
+                assert_eq!(app_ads_txt.authorization("redssp.com", "pub-2"), None);
+                assert_eq!(diagnostics.len(), 1);
+                assert_eq!(diagnostics[0].line, 2);
+            }
+            other => panic!("unexpected discovery {:?}", other),
+        }
+    }
+
+    #[test]
+    fn discovery_chain_breaks() {
+        let discoverer = AppAdsTxtDiscoverer::new(fetcher());
+        assert!(matches!(
+            discoverer
+                .discover(&AppStore::GooglePlay, "com.unknown")
+                .unwrap(),
+            AppAdsTxtDiscovery::ListingNotFound { .. }
+        ));
+        assert!(matches!(
+            discoverer.discover(&AppStore::Roku, "12345").unwrap(),
+            AppAdsTxtDiscovery::NoDeveloperUrl { .. }
+        ));
+        assert!(matches!(
+            discoverer.discover(&AppStore::AppleAppStore, "1234567890").unwrap(),
+            AppAdsTxtDiscovery::AppAdsTxtNotFound { domain, .. } if domain == "other.com"
+        ));
+        assert!(discoverer.discover(&AppStore::GooglePlay, "down").is_err());
+    }
+}
@@ -1,8 +1,9 @@
+use crate::AppAdsTxtVersion;
 use derive_builder::Builder;
-use iab_specs_ads_txt::AdsTxtSystem;
-use iab_specs_core::slice_up_to;
+use iab_specs_ads_txt::{AdsTxt, AdsTxtSystem, ManagerDomain};
 use serde::de::Error;
-use serde_with::{DeserializeFromStr, SerializeDisplay, serde_as};
+use serde::{Deserialize, Deserializer};
+use serde_with::{SerializeDisplay, serde_as};
 use std::fmt;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
@@ -30,9 +31,15 @@ use std::str::FromStr;
 /// - `OWNERDOMAIN` directive (not in app-ads.txt v1.0)
 /// - `MANAGERDOMAIN` directive (not in app-ads.txt v1.0)
 ///
-/// Attempting to parse an app-ads.txt file containing these directives will result in an error.
+/// Attempting to parse an app-ads.txt file containing these directives with [`AppAdsTxt::from_str`]
+/// will result in an error. Most files now follow ads.txt 1.1 and can be parsed with
+/// [`AppAdsTxt::parse_version`] and [`AppAdsTxtVersion::V1_1`], which fills `owner_domain` and
+/// `manager_domains`.
+///
+/// Serde deserialization parses [`AppAdsTxtVersion::V1_1`] files, so that any serialized
+/// `AppAdsTxt` can be read back.
 #[serde_as]
-#[derive(Builder, DeserializeFromStr, SerializeDisplay, Clone, Debug)]
+#[derive(Builder, SerializeDisplay, Clone, Debug)]
 #[builder(build_fn(error = "crate::Error"))]
 pub struct AppAdsTxt {
     /// Contact information
@@ -43,17 +50,19 @@ pub struct AppAdsTxt {
     #[builder(default)]
     pub contact: Option<String>,
 
-    /// Pointer to a subdomain file
+    /// Pointers to subdomain files
     ///
     /// A machine readable subdomain pointer to a subdomain within the root domain, on which an
     /// ads.txt can be found. The crawler should fetch and consume associate the data to the
     /// subdomain, not the current domain. This referral should be exempt from the public suffix
     /// truncation process. Only root domains should refer crawlers to subdomains. Subdomains
     /// should not refer to other subdomains.
+    ///
+    /// The variable may be declared several times, once per subdomain.
     #[builder(default)]
-    pub subdomain: Option<String>,
+    pub subdomains: Vec<String>,
 
-    /// A pointer to the domain of an entity that is explicitly authorized to monetize ads within
+    /// Pointers to the domains of entities that are explicitly authorized to monetize ads within
     /// the application's content.
     ///
     /// When an app contains ad inventory that is owned by another partner - the app
@@ -62,8 +71,23 @@ pub struct AppAdsTxt {
     /// It is expected that the INVENTORYPARTNERDOMAIN reference is followed to an ads.txt
     /// file only (not app-ads.txt). See the implementers notes for more details as this feature
     /// requires coordination with your advertising system.
+    ///
+    /// The variable may be declared several times, once per partner.
+    #[builder(default)]
+    pub inventory_partner_domains: Vec<String>,
+
+    /// Business domain of the owner of the app, see `AdsTxt::owner_domain`.
+    ///
+    /// Only set when parsing with [`AppAdsTxtVersion::V1_1`]. If the variable is declared
+    /// several times, only the first is used.
     #[builder(default)]
-    pub inventory_partner_domain: Option<String>,
+    pub owner_domain: Option<String>,
+
+    /// Primary or exclusive monetization partners of the app, see `AdsTxt::manager_domains`.
+    ///
+    /// Only set when parsing with [`AppAdsTxtVersion::V1_1`].
+    #[builder(default)]
+    pub manager_domains: Vec<ManagerDomain>,
 
     /// List of authorized advertising systems
     ///
@@ -71,6 +95,11 @@ pub struct AppAdsTxt {
     /// Format: `<domain>, <publisher_account_id>, <account_type>, [<certification_authority_id>]`
     #[builder(default)]
     pub systems: Vec<AdsTxtSystem>,
+
+    /// Variables not defined by the specification, as `(key, value)` pairs, see
+    /// `AdsTxt::unknown_variables`.
+    #[builder(default)]
+    pub unknown_variables: Vec<(String, String)>,
 }
 
 impl AppAdsTxt {
@@ -86,12 +115,21 @@ impl Display for AppAdsTxt {
         if let Some(v) = &self.contact {
             seq.push(format!("contact={}", v))
         }
-        if let Some(v) = &self.subdomain {
+        for v in &self.subdomains {
             seq.push(format!("subdomain={}", v))
         }
-        if let Some(v) = &self.inventory_partner_domain {
+        for v in &self.inventory_partner_domains {
             seq.push(format!("inventorypartnerdomain={}", v))
         }
+        if let Some(v) = &self.owner_domain {
+            seq.push(format!("ownerdomain={}", v))
+        }
+        for v in &self.manager_domains {
+            seq.push(format!("managerdomain={}", v))
+        }
+        for (key, value) in &self.unknown_variables {
+            seq.push(format!("{}={}", key, value))
+        }
         for v in &self.systems {
             seq.push(v.to_string())
         }
@@ -104,119 +142,62 @@ impl FromStr for AppAdsTxt {
     type Err = crate::Error;
 
     fn from_str(content: &str) -> Result<Self, Self::Err> {
-        const FIELDS: &[&str] = &["contact", "subdomain", "inventorypartnerdomain"];
-        let mut contact = None;
-        let mut subdomain = None;
-        let mut inventory_partner_domain = None;
-        let mut systems = vec![];
+        AppAdsTxt::parse_version(content, AppAdsTxtVersion::V1_0)
+    }
+}
 
-        // Implementer notes: "parsers should liberally interpret CR, CRLF etc as a line separator"
-        // Normalize line endings by replacing CR and CRLF with LF
-        let normalized_content = content.replace("\r\n", "\n").replace("\r", "\n");
-
-        for line in normalized_content.lines() {
-            let line = line.trim();
-            // Both empty lines and top of block comments are skipped.
-            if line.is_empty() || line.starts_with('#') {
-                continue;
-            } else {
-                // Variable
-                if line.contains('=') {
-                    let (key, value) = line.split_once("=").ok_or_else(|| {
-                        serde_plain::Error::unknown_field(&line[..line.len().min(100)], FIELDS)
-                    })?;
-                    let value = match value.contains("#") {
-                        true => {
-                            let (v, _) = value
-                                .split_once("#")
-                                .ok_or_else(|| serde_plain::Error::missing_field("comment"))?;
-                            v
-                        }
-                        false => value,
-                    };
-                    let value = value.trim().to_lowercase();
-                    let key_lower = key.trim().to_lowercase();
-                    match key_lower.as_str() {
-                        "contact" => contact = Some(value),
-                        "subdomain" => subdomain = Some(value),
-                        "inventorypartnerdomain" => inventory_partner_domain = Some(value),
-                        "ownerdomain" => {
-                            return Err(serde_plain::Error::custom(
-                                "OWNERDOMAIN is not supported in app-ads.txt v1.0 (added in ads.txt 1.1)"
-                            ).into());
-                        }
-                        "managerdomain" => {
-                            return Err(serde_plain::Error::custom(
-                                "MANAGERDOMAIN is not supported in app-ads.txt v1.0 (added in ads.txt 1.1)"
-                            ).into());
-                        }
-                        _ => {
-                            return Err(serde_plain::Error::unknown_field(
-                                slice_up_to!(line, 100),
-                                FIELDS,
-                            )
-                            .into());
-                        }
-                    }
-                } else {
-                    // System
-                    let system = AdsTxtSystem::from_str(line)?;
-                    systems.push(system);
-                }
-            }
-        }
-        AppAdsTxt::builder()
-            .contact(contact)
-            .subdomain(subdomain)
-            .inventory_partner_domain(inventory_partner_domain)
-            .systems(systems)
-            .build()
+impl<'de> Deserialize<'de> for AppAdsTxt {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let content = String::deserialize(deserializer)?;
+        AppAdsTxt::parse_version(&content, AppAdsTxtVersion::V1_1).map_err(D::Error::custom)
     }
 }
 
-// Conversion from AdsTxt (drops 1.1 fields with validation)
-impl TryFrom<iab_specs_ads_txt::AdsTxt> for AppAdsTxt {
-    type Error = crate::Error;
-
-    fn try_from(ads_txt: iab_specs_ads_txt::AdsTxt) -> Result<Self, Self::Error> {
-        // Validate that no 1.1-only fields are present
-        if ads_txt.owner_domain.is_some() {
-            return Err(serde_plain::Error::custom(
-                "Cannot convert ads.txt with OWNERDOMAIN to app-ads.txt v1.0",
-            )
-            .into());
-        }
-        if !ads_txt.manager_domains.is_empty() {
-            return Err(serde_plain::Error::custom(
-                "Cannot convert ads.txt with MANAGERDOMAIN to app-ads.txt v1.0",
-            )
-            .into());
-        }
+impl AppAdsTxt {
+    /// Parses an app-ads.txt file following `version` of the specification.
+    ///
+    /// The file is parsed with the same grammar and semantics as ads.txt, see
+    /// `AdsTxtGrammar::parse`. [`AppAdsTxt::from_str`] parses app-ads.txt 1.0 files.
+    pub fn parse_version(content: &str, version: AppAdsTxtVersion) -> crate::Result<Self> {
+        version.grammar().parse(content).map(AppAdsTxt::from)
+    }
+}
 
-        Ok(AppAdsTxt {
+// Lossless conversion from AdsTxt, both formats sharing the same model
+impl From<AdsTxt> for AppAdsTxt {
+    fn from(ads_txt: AdsTxt) -> Self {
+        AppAdsTxt {
             contact: ads_txt.contact,
-            subdomain: ads_txt.subdomain,
-            inventory_partner_domain: ads_txt.inventory_partner_domain,
+            subdomains: ads_txt.subdomains,
+            inventory_partner_domains: ads_txt.inventory_partner_domains,
+            owner_domain: ads_txt.owner_domain,
+            manager_domains: ads_txt.manager_domains,
             systems: ads_txt.systems,
-        })
+            unknown_variables: ads_txt.unknown_variables,
+        }
+    }
+}
+
+impl From<AppAdsTxt> for AdsTxt {
+    fn from(app_ads_txt: AppAdsTxt) -> Self {
+        AdsTxt {
+            contact: app_ads_txt.contact,
+            subdomains: app_ads_txt.subdomains,
+            inventory_partner_domains: app_ads_txt.inventory_partner_domains,
+            owner_domain: app_ads_txt.owner_domain,
+            manager_domains: app_ads_txt.manager_domains,
+            systems: app_ads_txt.systems,
+            unknown_variables: app_ads_txt.unknown_variables,
+        }
     }
 }
 
 impl AppAdsTxt {
     /// Convert this AppAdsTxt into an AdsTxt.
     ///
-    /// The ads.txt 1.1 fields (`owner_domain`, `manager_domains`) are set to `None`/empty
-    /// since app-ads.txt v1.0 does not support them.
-    pub fn into_ads_txt(self) -> iab_specs_ads_txt::AdsTxt {
-        iab_specs_ads_txt::AdsTxt::builder()
-            .contact(self.contact)
-            .subdomain(self.subdomain)
-            .inventory_partner_domain(self.inventory_partner_domain)
-            .systems(self.systems)
-            .owner_domain(None)
-            .manager_domains(vec![])
-            .build()
-            .expect("Valid conversion from AppAdsTxt to AdsTxt")
+    /// The conversion is lossless, see `From<AppAdsTxt> for AdsTxt`.
+    pub fn into_ads_txt(self) -> AdsTxt {
+        self.into()
     }
 }
 
@@ -233,8 +214,8 @@ mod tests {
         assert!(res.is_ok());
         let app_ads = res.unwrap();
         assert!(app_ads.contact.is_none());
-        assert!(app_ads.subdomain.is_none());
-        assert!(app_ads.inventory_partner_domain.is_none());
+        assert!(app_ads.subdomains.is_empty());
+        assert!(app_ads.inventory_partner_domains.is_empty());
         assert!(app_ads.systems.is_empty());
     }
 
@@ -290,7 +271,7 @@ greenadexchange.com, 12345, DIRECT
         let res = AppAdsTxt::from_str(content);
         assert!(res.is_ok());
         let app_ads = res.unwrap();
-        assert_eq!(app_ads.subdomain, Some("mobile.example.com".to_string()));
+        assert_eq!(app_ads.subdomains, vec!["mobile.example.com".to_string()]);
     }
 
     #[test]
@@ -303,8 +284,8 @@ greenadexchange.com, 12345, DIRECT
         assert!(res.is_ok());
         let app_ads = res.unwrap();
         assert_eq!(
-            app_ads.inventory_partner_domain,
-            Some("partner.example.com".to_string())
+            app_ads.inventory_partner_domains,
+            vec!["partner.example.com".to_string()]
         );
     }
 
@@ -327,7 +308,7 @@ bluessp.com, 54321, DIRECT # No TAG ID
         assert!(res.is_ok());
         let app_ads = res.unwrap();
         assert_eq!(app_ads.contact, Some("monetization@mygame.com".to_string()));
-        assert_eq!(app_ads.subdomain, Some("games.mygame.com".to_string()));
+        assert_eq!(app_ads.subdomains, vec!["games.mygame.com".to_string()]);
         assert_eq!(app_ads.systems.len(), 3);
     }
 
@@ -344,10 +325,10 @@ GreenAdExchange.COM, ABC123, DIRECT, TAG12345
         let app_ads = res.unwrap();
         // Values should be lowercased
         assert_eq!(app_ads.contact, Some("adops@example.com".to_string()));
-        assert_eq!(app_ads.subdomain, Some("mobile.example.com".to_string()));
+        assert_eq!(app_ads.subdomains, vec!["mobile.example.com".to_string()]);
         assert_eq!(
-            app_ads.inventory_partner_domain,
-            Some("partner.example.com".to_string())
+            app_ads.inventory_partner_domains,
+            vec!["partner.example.com".to_string()]
         );
     }
 
@@ -382,13 +363,18 @@ greenadexchange.com, 12345, DIRECT
     }
 
     #[test]
-    fn reject_unknown_variable() {
+    fn keep_unknown_variable() {
         let content = r#"
-unknownfield=somevalue
+unknownfield=SomeValue
 greenadexchange.com, 12345, DIRECT
 "#;
-        let res = AppAdsTxt::from_str(content);
-        assert!(res.is_err());
+        let app_ads = AppAdsTxt::from_str(content).unwrap();
+        assert_eq!(
+            app_ads.unknown_variables,
+            vec![("unknownfield".to_string(), "SomeValue".to_string())]
+        );
+        assert_eq!(app_ads.systems.len(), 1);
+        assert!(app_ads.to_string().contains("unknownfield=SomeValue"));
     }
 
     #[test]
@@ -434,8 +420,8 @@ greenadexchange.com, 12345, DIRECT
     fn serialize_with_all_fields() {
         let app_ads = AppAdsTxt::builder()
             .contact(Some("adops@example.com".to_string()))
-            .subdomain(Some("mobile.example.com".to_string()))
-            .inventory_partner_domain(Some("partner.example.com".to_string()))
+            .subdomains(vec!["mobile.example.com".to_string()])
+            .inventory_partner_domains(vec!["partner.example.com".to_string()])
             .systems(vec![
                 AdsTxtSystem::builder()
                     .domain("greenadexchange.com")
@@ -467,7 +453,7 @@ silverssp.com, 9876, RESELLER
         let reparsed = AppAdsTxt::from_str(&serialized).unwrap();
 
         assert_eq!(app_ads.contact, reparsed.contact);
-        assert_eq!(app_ads.subdomain, reparsed.subdomain);
+        assert_eq!(app_ads.subdomains, reparsed.subdomains);
         assert_eq!(app_ads.systems.len(), reparsed.systems.len());
     }
 
@@ -477,47 +463,43 @@ silverssp.com, 9876, RESELLER
     fn convert_to_ads_txt() {
         let app_ads = AppAdsTxt::builder()
             .contact(Some("adops@example.com".to_string()))
-            .subdomain(Some("mobile.example.com".to_string()))
+            .subdomains(vec!["mobile.example.com".to_string()])
             .build()
             .unwrap();
 
         let ads_txt = app_ads.into_ads_txt();
         assert_eq!(ads_txt.contact, Some("adops@example.com".to_string()));
-        assert_eq!(ads_txt.subdomain, Some("mobile.example.com".to_string()));
+        assert_eq!(ads_txt.subdomains, vec!["mobile.example.com".to_string()]);
         assert_eq!(ads_txt.owner_domain, None);
         assert!(ads_txt.manager_domains.is_empty());
     }
 
     #[test]
-    fn try_convert_from_ads_txt_compatible() {
+    fn convert_from_ads_txt_compatible() {
         let ads_txt = iab_specs_ads_txt::AdsTxt::builder()
             .contact(Some("adops@example.com".to_string()))
-            .subdomain(Some("mobile.example.com".to_string()))
+            .subdomains(vec!["mobile.example.com".to_string()])
             .build()
             .unwrap();
 
-        let result = AppAdsTxt::try_from(ads_txt);
-        assert!(result.is_ok());
-        let app_ads = result.unwrap();
+        let app_ads = AppAdsTxt::from(ads_txt);
         assert_eq!(app_ads.contact, Some("adops@example.com".to_string()));
-        assert_eq!(app_ads.subdomain, Some("mobile.example.com".to_string()));
+        assert_eq!(app_ads.subdomains, vec!["mobile.example.com".to_string()]);
     }
 
     #[test]
-    fn try_convert_from_ads_txt_with_owner_domain_fails() {
+    fn convert_from_ads_txt_keeps_owner_domain() {
         let ads_txt = iab_specs_ads_txt::AdsTxt::builder()
             .owner_domain(Some("example.com".to_string()))
             .build()
             .unwrap();
 
-        let result = AppAdsTxt::try_from(ads_txt);
-        assert!(result.is_err());
-        let err_msg = result.unwrap_err().to_string();
-        assert!(err_msg.contains("OWNERDOMAIN"));
+        let app_ads = AppAdsTxt::from(ads_txt);
+        assert_eq!(app_ads.owner_domain, Some("example.com".to_string()));
     }
 
     #[test]
-    fn try_convert_from_ads_txt_with_manager_domain_fails() {
+    fn convert_from_ads_txt_keeps_manager_domains() {
         use iab_specs_ads_txt::ManagerDomain;
 
         let ads_txt = iab_specs_ads_txt::AdsTxt::builder()
@@ -530,10 +512,26 @@ silverssp.com, 9876, RESELLER
             .build()
             .unwrap();
 
-        let result = AppAdsTxt::try_from(ads_txt);
-        assert!(result.is_err());
-        let err_msg = result.unwrap_err().to_string();
-        assert!(err_msg.contains("MANAGERDOMAIN"));
+        let app_ads = AppAdsTxt::from(ads_txt);
+        assert_eq!(app_ads.manager_domains.len(), 1);
+        assert_eq!(app_ads.manager_domains[0].domain, "manager.example.com");
+    }
+
+    #[test]
+    fn convert_from_ads_txt_keeps_several_subdomains() {
+        let ads_txt = iab_specs_ads_txt::AdsTxt::builder()
+            .subdomains(vec![
+                "a.example.com".to_string(),
+                "b.example.com".to_string(),
+            ])
+            .build()
+            .unwrap();
+
+        let app_ads = AppAdsTxt::from(ads_txt);
+        assert_eq!(
+            app_ads.subdomains,
+            vec!["a.example.com".to_string(), "b.example.com".to_string()]
+        );
     }
 
     // WHITESPACE HANDLING TESTS - From Implementer Notes
@@ -571,7 +569,7 @@ greenadexchange.com, 12345, DIRECT
         let app_ads = res.unwrap();
         // Values should have whitespace trimmed and lowercased
         assert_eq!(app_ads.contact, Some("adops@example.com".to_string()));
-        assert_eq!(app_ads.subdomain, Some("mobile.example.com".to_string()));
+        assert_eq!(app_ads.subdomains, vec!["mobile.example.com".to_string()]);
     }
 
     // LINE ENDING TESTS - From Implementer Notes
@@ -604,7 +602,7 @@ greenadexchange.com, 12345, DIRECT
         assert!(res.is_ok());
         let app_ads = res.unwrap();
         assert_eq!(app_ads.contact, Some("adops@example.com".to_string()));
-        assert_eq!(app_ads.subdomain, Some("mobile.example.com".to_string()));
+        assert_eq!(app_ads.subdomains, vec!["mobile.example.com".to_string()]);
         assert_eq!(app_ads.systems.len(), 1);
     }
 
@@ -649,8 +647,14 @@ greenadexchange.com, 12345, DIRECT
         let res = AppAdsTxt::from_str(content);
         assert!(res.is_ok());
         let app_ads = res.unwrap();
-        // Last value wins
-        assert_eq!(app_ads.subdomain, Some("second.example.com".to_string()));
+        // Every subdomain is kept, as in ads.txt
+        assert_eq!(
+            app_ads.subdomains,
+            vec![
+                "first.example.com".to_string(),
+                "second.example.com".to_string()
+            ]
+        );
     }
 
     // COMMENT EDGE CASES - From Implementer Notes
@@ -683,7 +687,7 @@ greenadexchange.com, 12345, DIRECT
         let app_ads = res.unwrap();
         // Comment should be stripped from value
         assert_eq!(app_ads.contact, Some("adops@example.com".to_string()));
-        assert_eq!(app_ads.subdomain, Some("mobile.example.com".to_string()));
+        assert_eq!(app_ads.subdomains, vec!["mobile.example.com".to_string()]);
     }
 
     #[test]
@@ -755,8 +759,8 @@ orangessp.com, 11111, DIRECT
             Some("monetization@awesome-game.com".to_string())
         );
         assert_eq!(
-            app_ads.subdomain,
-            Some("games.awesome-game.com".to_string())
+            app_ads.subdomains,
+            vec!["games.awesome-game.com".to_string()]
         );
         assert_eq!(app_ads.systems.len(), 5);
 
@@ -788,8 +792,8 @@ greenadexchange.com, 12345, DIRECT, d75815a79
         assert!(res.is_ok());
         let app_ads = res.unwrap();
         assert_eq!(
-            app_ads.inventory_partner_domain,
-            Some("partner-network.com".to_string())
+            app_ads.inventory_partner_domains,
+            vec!["partner-network.com".to_string()]
         );
         assert_eq!(app_ads.systems.len(), 1);
     }
@@ -850,8 +854,8 @@ greenadexchange.com, 12345, DIRECT
         assert!(res.is_ok());
         let app_ads = res.unwrap();
         assert!(app_ads.contact.is_none());
-        assert!(app_ads.subdomain.is_none());
-        assert!(app_ads.inventory_partner_domain.is_none());
+        assert!(app_ads.subdomains.is_empty());
+        assert!(app_ads.inventory_partner_domains.is_empty());
         assert!(app_ads.systems.is_empty());
     }
 
@@ -888,23 +892,23 @@ greenadexchange.com, 12345, DIRECT
         assert!(res.is_ok());
         let app_ads = res.unwrap();
         assert_eq!(app_ads.contact, Some("test@example.com".to_string()));
-        assert_eq!(app_ads.subdomain, Some("sub.example.com".to_string()));
+        assert_eq!(app_ads.subdomains, vec!["sub.example.com".to_string()]);
         assert_eq!(
-            app_ads.inventory_partner_domain,
-            Some("partner.com".to_string())
+            app_ads.inventory_partner_domains,
+            vec!["partner.com".to_string()]
         );
     }
 
     #[test]
-    // Spec: Section 3.1.2 — Duplicate INVENTORYPARTNERDOMAIN overwrites (last wins)
+    // Spec: Section 3.1.2 — INVENTORYPARTNERDOMAIN may be declared once per partner
     fn test_duplicate_inventorypartnerdomain() {
         let content = "inventorypartnerdomain=first.com\ninventorypartnerdomain=second.com\ngreenadexchange.com, 12345, DIRECT";
         let res = AppAdsTxt::from_str(content);
         assert!(res.is_ok());
         let app_ads = res.unwrap();
         assert_eq!(
-            app_ads.inventory_partner_domain,
-            Some("second.com".to_string())
+            app_ads.inventory_partner_domains,
+            vec!["first.com".to_string(), "second.com".to_string()]
         );
     }
 
@@ -933,8 +937,8 @@ greenadexchange.com, 12345, DIRECT
     fn test_roundtrip_all_variables() {
         let app_ads = AppAdsTxt::builder()
             .contact(Some("adops@example.com".to_string()))
-            .subdomain(Some("mobile.example.com".to_string()))
-            .inventory_partner_domain(Some("partner.example.com".to_string()))
+            .subdomains(vec!["mobile.example.com".to_string()])
+            .inventory_partner_domains(vec!["partner.example.com".to_string()])
             .systems(vec![
                 AdsTxtSystem::builder()
                     .domain("greenadexchange.com")
@@ -957,10 +961,10 @@ greenadexchange.com, 12345, DIRECT
         let reparsed = AppAdsTxt::from_str(&serialized).unwrap();
 
         assert_eq!(app_ads.contact, reparsed.contact);
-        assert_eq!(app_ads.subdomain, reparsed.subdomain);
+        assert_eq!(app_ads.subdomains, reparsed.subdomains);
         assert_eq!(
-            app_ads.inventory_partner_domain,
-            reparsed.inventory_partner_domain
+            app_ads.inventory_partner_domains,
+            reparsed.inventory_partner_domains
         );
         assert_eq!(app_ads.systems.len(), reparsed.systems.len());
         for (orig, re) in app_ads.systems.iter().zip(reparsed.systems.iter()) {
@@ -980,12 +984,12 @@ greenadexchange.com, 12345, DIRECT
         let serialized = app_ads.to_string();
         let reparsed = AppAdsTxt::from_str(&serialized).unwrap();
         assert_eq!(
-            app_ads.inventory_partner_domain,
-            reparsed.inventory_partner_domain
+            app_ads.inventory_partner_domains,
+            reparsed.inventory_partner_domains
         );
         assert_eq!(
-            reparsed.inventory_partner_domain,
-            Some("partner.example.com".to_string())
+            reparsed.inventory_partner_domains,
+            vec!["partner.example.com".to_string()]
         );
     }
 
@@ -1018,7 +1022,7 @@ greenadexchange.com, 12345, DIRECT
         assert_eq!(ads_txt.systems[1].domain, "silverssp.com");
 
         // AdsTxt → AppAdsTxt
-        let back = AppAdsTxt::try_from(ads_txt).unwrap();
+        let back = AppAdsTxt::from(ads_txt);
         assert_eq!(back.systems.len(), 2);
         assert_eq!(back.systems[0].domain, "greenadexchange.com");
         assert_eq!(back.systems[0].relation, SellerRelationType::Direct);
@@ -1030,22 +1034,22 @@ greenadexchange.com, 12345, DIRECT
     // Spec: Conversion — inventory_partner_domain preserved during conversion
     fn test_conversion_preserves_inventorypartnerdomain() {
         let app_ads = AppAdsTxt::builder()
-            .inventory_partner_domain(Some("partner.example.com".to_string()))
+            .inventory_partner_domains(vec!["partner.example.com".to_string()])
             .build()
             .unwrap();
 
         // AppAdsTxt → AdsTxt
         let ads_txt = app_ads.clone().into_ads_txt();
         assert_eq!(
-            ads_txt.inventory_partner_domain,
-            Some("partner.example.com".to_string())
+            ads_txt.inventory_partner_domains,
+            vec!["partner.example.com".to_string()]
         );
 
         // AdsTxt → AppAdsTxt
-        let back = AppAdsTxt::try_from(ads_txt).unwrap();
+        let back = AppAdsTxt::from(ads_txt);
         assert_eq!(
-            back.inventory_partner_domain,
-            Some("partner.example.com".to_string())
+            back.inventory_partner_domains,
+            vec!["partner.example.com".to_string()]
         );
     }
 
@@ -1054,8 +1058,8 @@ greenadexchange.com, 12345, DIRECT
     fn test_conversion_roundtrip() {
         let original = AppAdsTxt::builder()
             .contact(Some("adops@example.com".to_string()))
-            .subdomain(Some("mobile.example.com".to_string()))
-            .inventory_partner_domain(Some("partner.example.com".to_string()))
+            .subdomains(vec!["mobile.example.com".to_string()])
+            .inventory_partner_domains(vec!["partner.example.com".to_string()])
             .systems(vec![
                 AdsTxtSystem::builder()
                     .domain("greenadexchange.com")
@@ -1068,13 +1072,13 @@ greenadexchange.com, 12345, DIRECT
             .unwrap();
 
         let ads_txt = original.clone().into_ads_txt();
-        let roundtripped = AppAdsTxt::try_from(ads_txt).unwrap();
+        let roundtripped = AppAdsTxt::from(ads_txt);
 
         assert_eq!(original.contact, roundtripped.contact);
-        assert_eq!(original.subdomain, roundtripped.subdomain);
+        assert_eq!(original.subdomains, roundtripped.subdomains);
         assert_eq!(
-            original.inventory_partner_domain,
-            roundtripped.inventory_partner_domain
+            original.inventory_partner_domains,
+            roundtripped.inventory_partner_domains
         );
         assert_eq!(original.systems.len(), roundtripped.systems.len());
     }
@@ -1099,4 +1103,81 @@ greenadexchange.com, 12345, DIRECT
         let app_ads = res.unwrap();
         assert_eq!(app_ads.contact, Some("adops@example.com".to_string()));
     }
+
+    #[test]
+    // Spec: ads.txt 1.1 alignment — OWNERDOMAIN and MANAGERDOMAIN accepted in version 1.1
+    fn test_parse_version_1_1_accepts_ads_txt_1_1_directives() {
+        let content = "contact=adops@example.com\r\nOwnerDomain=Example.com\rmanagerdomain=manager.com\nmanagerdomain=other.com, FR\ngreenadexchange.com, 12345, DIRECT";
+        let app_ads = AppAdsTxt::parse_version(content, AppAdsTxtVersion::V1_1).unwrap();
+        assert_eq!(app_ads.owner_domain, Some("example.com".to_string()));
+        assert_eq!(app_ads.manager_domains.len(), 2);
+        assert_eq!(app_ads.manager_domains[1].domain, "other.com");
+        assert_eq!(app_ads.systems.len(), 1);
+
+        assert!(AppAdsTxt::parse_version(content, AppAdsTxtVersion::V1_0).is_err());
+        assert!(AppAdsTxt::from_str(content).is_err());
+
+        let reparsed =
+            AppAdsTxt::parse_version(&app_ads.to_string(), AppAdsTxtVersion::V1_1).unwrap();
+        assert_eq!(reparsed.owner_domain, app_ads.owner_domain);
+        assert_eq!(reparsed.manager_domains, app_ads.manager_domains);
+    }
+
+    #[test]
+    // Spec: ads.txt 1.1 alignment — unknown variables are ignored, and only the first
+    // OWNERDOMAIN is used
+    fn test_parse_version_1_1_aligns_with_ads_txt() {
+        let content = "foo=bar\nownerdomain=first.com\nownerdomain=second.com";
+        let app_ads = AppAdsTxt::parse_version(content, AppAdsTxtVersion::V1_1).unwrap();
+        assert_eq!(
+            app_ads.unknown_variables,
+            vec![("foo".to_string(), "bar".to_string())]
+        );
+        assert_eq!(app_ads.owner_domain, Some("first.com".to_string()));
+        let ads_txt = iab_specs_ads_txt::AdsTxt::from_str(content).unwrap();
+        assert_eq!(app_ads.into_ads_txt().to_string(), ads_txt.to_string());
+    }
+
+    #[test]
+    // Spec: Conversion — From<AppAdsTxt> for AdsTxt keeps every field
+    fn test_lossless_conversion_to_ads_txt() {
+        let content = "contact=adops@example.com\nsubdomain=mobile.example.com\ninventorypartnerdomain=partner.com\nownerdomain=example.com\nmanagerdomain=manager.com, FR\ngreenadexchange.com, 12345, DIRECT, d75815a79 # comment";
+        let app_ads = AppAdsTxt::parse_version(content, AppAdsTxtVersion::V1_1).unwrap();
+        let ads_txt: iab_specs_ads_txt::AdsTxt = app_ads.clone().into();
+        assert_eq!(ads_txt.contact, app_ads.contact);
+        assert_eq!(ads_txt.subdomains, vec!["mobile.example.com".to_string()]);
+        assert_eq!(
+            ads_txt.inventory_partner_domains,
+            vec!["partner.com".to_string()]
+        );
+        assert_eq!(ads_txt.owner_domain, app_ads.owner_domain);
+        assert_eq!(ads_txt.manager_domains, app_ads.manager_domains);
+        assert_eq!(ads_txt.systems, app_ads.systems);
+        assert!(ads_txt.unknown_variables.is_empty());
+        assert_eq!(
+            ads_txt.to_string(),
+            iab_specs_ads_txt::AdsTxt::from_str(content)
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_serde_roundtrip_version_1_1() {
+        let content = "ownerdomain=example.com\nmanagerdomain=manager.com, FR\ngreenadexchange.com, 12345, DIRECT";
+        let app_ads = AppAdsTxt::parse_version(content, AppAdsTxtVersion::V1_1).unwrap();
+        let json = serde_json::to_string(&app_ads).unwrap();
+        let reparsed: AppAdsTxt = serde_json::from_str(&json).unwrap();
+        assert_eq!(reparsed.owner_domain, app_ads.owner_domain);
+        assert_eq!(reparsed.manager_domains, app_ads.manager_domains);
+        assert_eq!(reparsed.systems, app_ads.systems);
+    }
+
+    #[test]
+    fn test_lossless_conversion_from_ads_txt() {
+        let content = "contact=adops@example.com\nownerdomain=example.com\nmanagerdomain=manager.com, FR\ngreenadexchange.com, 12345, DIRECT";
+        let ads_txt = iab_specs_ads_txt::AdsTxt::from_str(content).unwrap();
+        let app_ads = AppAdsTxt::from(ads_txt.clone());
+        assert_eq!(app_ads.into_ads_txt().to_string(), ads_txt.to_string());
+    }
 }
@@ -0,0 +1,49 @@
+use iab_specs_ads_txt::AdsTxtGrammar;
+use serde::{Deserialize, Serialize};
+
+/// Version of the app-ads.txt specification a file is parsed against.
+#[derive(
+    Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord,
+)]
+pub enum AppAdsTxtVersion {
+    /// app-ads.txt 1.0 (March 2019), based on ads.txt 1.0.2.
+    ///
+    /// OWNERDOMAIN and MANAGERDOMAIN are rejected.
+    #[default]
+    #[serde(rename = "1.0")]
+    V1_0,
+
+    /// app-ads.txt aligned with ads.txt 1.1 (August 2022).
+    ///
+    /// OWNERDOMAIN and MANAGERDOMAIN are accepted.
+    #[serde(rename = "1.1")]
+    V1_1,
+}
+
+impl AppAdsTxtVersion {
+    /// The grammar of files following this version.
+    pub fn grammar(&self) -> AdsTxtGrammar {
+        match self {
+            AppAdsTxtVersion::V1_0 => AdsTxtGrammar::APP_ADS_TXT_1_0,
+            AppAdsTxtVersion::V1_1 => AdsTxtGrammar::APP_ADS_TXT_1_1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_roundtrip() {
+        assert_eq!(
+            serde_json::to_string(&AppAdsTxtVersion::V1_1).unwrap(),
+            "\"1.1\""
+        );
+        assert_eq!(
+            serde_json::from_str::<AppAdsTxtVersion>("\"1.0\"").unwrap(),
+            AppAdsTxtVersion::V1_0
+        );
+        assert_eq!(AppAdsTxtVersion::default(), AppAdsTxtVersion::V1_0);
+    }
+}
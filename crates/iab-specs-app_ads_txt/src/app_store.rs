@@ -0,0 +1,268 @@
+use iab_specs_ads_txt::DomainCanonicalizer;
+use serde::{Deserialize, Serialize};
+
+/// The store distributing an app.
+///
+/// Stores are identified from the host of `app.storeurl` in OpenRTB bid requests, following
+/// the IAB Tech Lab guidelines for store-assigned app identification.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AppStore {
+    /// Google Play, bundles are package names such as `com.example.app`.
+    GooglePlay,
+    /// Apple App Store, bundles are numeric app IDs such as `1234567890`.
+    AppleAppStore,
+    /// Roku Channel Store, bundles are channel IDs.
+    Roku,
+    /// Amazon Appstore, including Fire TV, bundles are ASINs such as `B00EXAMPLE`.
+    AmazonAppstore,
+    /// Samsung Galaxy Store and Samsung Smart TV apps.
+    Samsung,
+    /// LG Content Store for webOS TVs.
+    LgContentStore,
+    /// Vizio SmartCast apps.
+    Vizio,
+    /// Another store, identified by the host of its listing URLs.
+    Other(String),
+}
+
+/// Public suffixes of the Amazon marketplaces, whose registrable domains are `amazon.<suffix>`.
+const AMAZON_SUFFIXES: &[&str] = &[
+    "com", "ca", "com.mx", "com.br", "co.uk", "de", "fr", "it", "es", "nl", "se", "pl", "com.be",
+    "com.tr", "ae", "sa", "eg", "in", "co.jp", "com.au", "sg", "cn",
+];
+
+impl AppStore {
+    /// Identifies the store of a listing URL, for instance `app.storeurl`.
+    ///
+    /// Only the hosts serving store listings are recognised, and on the corporate sites of
+    /// Samsung and Vizio only their app sections. Any other URL is identified as
+    /// [`AppStore::Other`]. Returns `None` if `store_url` has no host.
+    pub fn from_store_url(store_url: &str) -> Option<AppStore> {
+        let host = DomainCanonicalizer::new().canonicalize(store_url).ok()?;
+        let is = |domain: &str| host == domain || host.ends_with(&format!(".{}", domain));
+        let in_section = |sections: &[&str]| {
+            path_segments(store_url).any(|segment| {
+                sections
+                    .iter()
+                    .any(|section| segment.eq_ignore_ascii_case(section))
+            })
+        };
+        let store = if host == "play.google.com" {
+            AppStore::GooglePlay
+        } else if host == "apps.apple.com" || host == "itunes.apple.com" {
+            AppStore::AppleAppStore
+        } else if host == "channelstore.roku.com" {
+            AppStore::Roku
+        } else if AMAZON_SUFFIXES
+            .iter()
+            .any(|suffix| is(&format!("amazon.{}", suffix)))
+        {
+            AppStore::AmazonAppstore
+        } else if host == "galaxystore.samsung.com"
+            || is("samsungapps.com")
+            || (host == "samsung.com" && in_section(&["apps", "appstore"]))
+        {
+            AppStore::Samsung
+        } else if is("lgappstv.com") {
+            AppStore::LgContentStore
+        } else if host == "vizio.com" && in_section(&["apps", "smartcast-apps"]) {
+            AppStore::Vizio
+        } else {
+            AppStore::Other(host)
+        };
+        Some(store)
+    }
+
+    /// The URL of the listing of `bundle`, for stores with a public listing URL scheme.
+    pub fn listing_url(&self, bundle: &str) -> Option<String> {
+        match self {
+            AppStore::GooglePlay => Some(format!(
+                "https://play.google.com/store/apps/details?id={}",
+                bundle
+            )),
+            AppStore::AppleAppStore => Some(format!(
+                "https://apps.apple.com/app/id{}",
+                bundle.trim_start_matches("id")
+            )),
+            AppStore::Roku => Some(format!("https://channelstore.roku.com/details/{}", bundle)),
+            AppStore::AmazonAppstore => Some(format!("https://www.amazon.com/dp/{}", bundle)),
+            AppStore::Samsung => Some(format!("https://galaxystore.samsung.com/detail/{}", bundle)),
+            AppStore::LgContentStore | AppStore::Vizio | AppStore::Other(_) => None,
+        }
+    }
+}
+
+/// The segments of the path of `url`, without scheme, host, query or fragment.
+fn path_segments(url: &str) -> impl Iterator<Item = &str> {
+    let url = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let url = url.split(['?', '#']).next().unwrap_or_default();
+    url.split('/').skip(1).filter(|segment| !segment.is_empty())
+}
+
+/// The store listing of an app, the first link of the app-ads.txt discovery chain.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AppStoreListing {
+    /// The store distributing the app.
+    pub store: AppStore,
+
+    /// The store-assigned identifier of the app, as sent in `app.bundle`.
+    pub bundle: String,
+
+    /// The developer website declared in the listing.
+    pub developer_url: Option<String>,
+}
+
+impl AppStoreListing {
+    /// The domain hosting the app-ads.txt file of the app.
+    ///
+    /// As required by the specification, the developer URL is reduced to its host, without
+    /// scheme, path, `www.` or `m.` prefixes. `canonicalizer` can further reduce it to the
+    /// public suffix plus one label. Returns `None` if the listing has no developer URL.
+    pub fn app_ads_txt_domain(
+        &self,
+        canonicalizer: &DomainCanonicalizer,
+    ) -> Option<crate::Result<String>> {
+        self.developer_url.as_deref().map(|developer_url| {
+            let domain = canonicalizer.canonicalize(developer_url)?;
+            Ok(match domain.strip_prefix("m.") {
+                Some(rest) if rest.contains('.') => rest.to_string(),
+                _ => domain,
+            })
+        })
+    }
+
+    /// The URL of the app-ads.txt file of the app.
+    pub fn app_ads_txt_url(
+        &self,
+        canonicalizer: &DomainCanonicalizer,
+    ) -> Option<crate::Result<String>> {
+        self.app_ads_txt_domain(canonicalizer)
+            .map(|domain| domain.map(|domain| format!("https://{}/app-ads.txt", domain)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identify_stores() {
+        let cases = [
+            (
+                "https://play.google.com/store/apps/details?id=com.example.app",
+                AppStore::GooglePlay,
+            ),
+            (
+                "https://apps.apple.com/us/app/example/id1234567890",
+                AppStore::AppleAppStore,
+            ),
+            (
+                "https://itunes.apple.com/app/id1234567890",
+                AppStore::AppleAppStore,
+            ),
+            (
+                "https://channelstore.roku.com/details/12345",
+                AppStore::Roku,
+            ),
+            (
+                "https://www.amazon.com/dp/B00EXAMPLE",
+                AppStore::AmazonAppstore,
+            ),
+            (
+                "https://www.amazon.co.uk/dp/B00EXAMPLE",
+                AppStore::AmazonAppstore,
+            ),
+            (
+                "https://www.samsung.com/us/appstore/app/G15147002586",
+                AppStore::Samsung,
+            ),
+            (
+                "https://us.lgappstv.com/main/tvapp/detail?appId=1",
+                AppStore::LgContentStore,
+            ),
+            (
+                "https://amazon.evil.com/dp/B00EXAMPLE",
+                AppStore::Other("amazon.evil.com".to_string()),
+            ),
+            ("https://www.vizio.com/en/smartcast-apps", AppStore::Vizio),
+            (
+                "https://www.samsung.com/us/tvs",
+                AppStore::Other("samsung.com".to_string()),
+            ),
+            (
+                "https://developer.roku.com/docs",
+                AppStore::Other("developer.roku.com".to_string()),
+            ),
+            (
+                "https://www.lg.com/us/tvs",
+                AppStore::Other("lg.com".to_string()),
+            ),
+            (
+                "https://www.vizio.com/en/tv?ref=apps",
+                AppStore::Other("vizio.com".to_string()),
+            ),
+            (
+                "https://store.example.tv/app/1",
+                AppStore::Other("store.example.tv".to_string()),
+            ),
+        ];
+        for (url, store) in cases {
+            assert_eq!(AppStore::from_store_url(url), Some(store), "{url}");
+        }
+        assert_eq!(AppStore::from_store_url(""), None);
+    }
+
+    #[test]
+    fn listing_urls() {
+        assert_eq!(
+            AppStore::GooglePlay
+                .listing_url("com.example.app")
+                .as_deref(),
+            Some("https://play.google.com/store/apps/details?id=com.example.app")
+        );
+        assert_eq!(
+            AppStore::AppleAppStore
+                .listing_url("id1234567890")
+                .as_deref(),
+            Some("https://apps.apple.com/app/id1234567890")
+        );
+        assert_eq!(AppStore::Vizio.listing_url("x"), None);
+        for store in [
+            AppStore::GooglePlay,
+            AppStore::AppleAppStore,
+            AppStore::Roku,
+            AppStore::AmazonAppstore,
+            AppStore::Samsung,
+        ] {
+            let url = store.listing_url("bundle").unwrap();
+            assert_eq!(AppStore::from_store_url(&url), Some(store));
+        }
+    }
+
+    #[test]
+    fn derive_app_ads_txt_url() {
+        let canonicalizer = DomainCanonicalizer::new();
+        let mut listing = AppStoreListing {
+            store: AppStore::GooglePlay,
+            bundle: "com.example.app".to_string(),
+            developer_url: Some("https://www.Example.com/games/".to_string()),
+        };
+        assert_eq!(
+            listing.app_ads_txt_url(&canonicalizer).unwrap().unwrap(),
+            "https://example.com/app-ads.txt"
+        );
+
+        listing.developer_url = Some("http://m.example.com".to_string());
+        assert_eq!(
+            listing.app_ads_txt_domain(&canonicalizer).unwrap().unwrap(),
+            "example.com"
+        );
+
+        listing.developer_url = Some("https://".to_string());
+        assert!(listing.app_ads_txt_url(&canonicalizer).unwrap().is_err());
+
+        listing.developer_url = None;
+        assert!(listing.app_ads_txt_url(&canonicalizer).is_none());
+    }
+}
@@ -1,6 +1,12 @@
-pub use iab_specs_ads_txt::{AdsTxtSystem, SellerRelationType};
+pub use iab_specs_ads_txt::{AdsTxtSystem, ManagerDomain, SellerRelationType};
 pub use iab_specs_core::{DefaultExt, Error, Extension, Result};
 
+mod app_ads_txt_discovery;
 mod app_ads_txt_root;
+mod app_ads_txt_version;
+mod app_store;
 
+pub use app_ads_txt_discovery::*;
 pub use app_ads_txt_root::*;
+pub use app_ads_txt_version::*;
+pub use app_store::*;
@@ -255,7 +255,7 @@ mod tests {
 
         assert_eq!(offer.price, 3.75);
         assert_eq!(offer.round, 2);
-        assert_eq!(offer.from_buyer, true);
+        assert!(offer.from_buyer);
         assert!(offer.accepted.is_none());
         assert!(offer.counter_price.is_none());
         assert!(offer.ext.is_none());
@@ -274,7 +274,7 @@ mod tests {
 
         assert_eq!(offer.price, 3.75);
         assert_eq!(offer.round, 2);
-        assert_eq!(offer.from_buyer, true);
+        assert!(offer.from_buyer);
         assert_eq!(offer.accepted, Some(false));
         assert_eq!(offer.counter_price, Some(4.00));
         assert!(offer.ext.is_none());
@@ -296,7 +296,7 @@ mod tests {
 
         assert_eq!(parsed.price, 3.75);
         assert_eq!(parsed.round, 2);
-        assert_eq!(parsed.from_buyer, true);
+        assert!(parsed.from_buyer);
         assert_eq!(parsed.accepted, Some(false));
         assert_eq!(parsed.counter_price, Some(4.00));
     }
@@ -312,7 +312,7 @@ mod tests {
 
         assert_eq!(offer.price, 4.50);
         assert_eq!(offer.round, 1);
-        assert_eq!(offer.from_buyer, false);
+        assert!(!offer.from_buyer);
     }
 
     #[test]
@@ -379,7 +379,7 @@ mod tests {
         let offer: NegotiationOffer = serde_json::from_str(json).unwrap();
         assert_eq!(offer.price, 3.50);
         assert_eq!(offer.round, 2);
-        assert_eq!(offer.from_buyer, false);
+        assert!(!offer.from_buyer);
         assert_eq!(offer.accepted, Some(true));
     }
 
@@ -77,7 +77,7 @@ mod tests {
 
     #[test]
     fn test_every_valid_campaign_transition_returns_true() {
-        for (from, to) in VALID_CAMPAIGN_TRANSITIONS.iter().copied() {
+        for &(from, to) in VALID_CAMPAIGN_TRANSITIONS {
             assert!(
                 can_transition_campaign(&from, &to),
                 "expected transition {:?} -> {:?} to be valid",
@@ -73,7 +73,7 @@ mod tests {
 
     #[test]
     fn test_every_valid_deal_transition_returns_true() {
-        for (from, to) in VALID_DEAL_TRANSITIONS.iter().copied() {
+        for &(from, to) in VALID_DEAL_TRANSITIONS {
             assert!(
                 can_transition_deal(&from, &to),
                 "expected transition {:?} -> {:?} to be valid",
@@ -0,0 +1,95 @@
+//! List Code
+//!
+//! The IAB specifications carry values of enumerated lists as integer codes, and reserve
+//! ranges of those lists for exchange-specific values. This type decodes such a code to the
+//! matching enum when it is known, so that other codes survive a roundtrip.
+
+use serde::de::value::{Error, I32Deserializer};
+use serde::de::{DeserializeOwned, IntoDeserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Integer code of an enumerated list, decoded to the enum `T`.
+///
+/// Codes not defined by `T` are preserved as [`Code::Unknown`] rather than rejected, and
+/// serialized back unchanged.
+///
+/// ## Example
+///
+/// ```
+/// use iab_specs_core::Code;
+///
+/// assert_eq!(Code::<u8>::from(3), Code::Known(3));
+/// assert_eq!(Code::<u8>::from(500), Code::Unknown(500));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Code<T> {
+    /// Code defined by the list.
+    Known(T),
+    /// Code not defined by the list, such as an exchange-specific value.
+    Unknown(i32),
+}
+
+impl<T: DeserializeOwned> Code<T> {
+    /// Decodes a list of codes, preserving their order.
+    pub fn from_codes(codes: &[i32]) -> Vec<Self> {
+        codes.iter().copied().map(Code::from).collect()
+    }
+}
+
+impl<T> Code<T> {
+    /// The enum value, if the code is known.
+    pub fn known(self) -> Option<T> {
+        match self {
+            Code::Known(value) => Some(value),
+            Code::Unknown(_) => None,
+        }
+    }
+
+    /// Whether the code is defined by the list.
+    pub fn is_known(&self) -> bool {
+        matches!(self, Code::Known(_))
+    }
+}
+
+impl<T: DeserializeOwned> From<i32> for Code<T> {
+    fn from(code: i32) -> Self {
+        let deserializer: I32Deserializer<Error> = code.into_deserializer();
+        T::deserialize(deserializer).map_or(Code::Unknown(code), Code::Known)
+    }
+}
+
+impl<T: Serialize> Serialize for Code<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Code::Known(value) => value.serialize(serializer),
+            Code::Unknown(code) => serializer.serialize_i32(*code),
+        }
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Code<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        i32::deserialize(deserializer).map(Code::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_serde_roundtrip() {
+        let codes: Vec<Code<u8>> = serde_json::from_str("[1,500,-1]").unwrap();
+        assert_eq!(
+            codes,
+            vec![Code::Known(1), Code::Unknown(500), Code::Unknown(-1)]
+        );
+        assert_eq!(serde_json::to_string(&codes).unwrap(), "[1,500,-1]");
+    }
+
+    #[test]
+    fn test_code_rejects_non_integers() {
+        assert!(serde_json::from_str::<Code<u8>>(r#""1""#).is_err());
+        assert!(serde_json::from_str::<Code<u8>>("1.5").is_err());
+    }
+}
@@ -16,6 +16,9 @@ pub enum Error {
     #[error("{0}")]
     StdFmtError(#[from] std::fmt::Error),
 
+    #[error("{0}")]
+    StdIoError(#[from] std::io::Error),
+
     #[error("Invalid transition from '{from}' to '{to}'")]
     InvalidTransition { from: String, to: String },
 }
@@ -64,6 +67,13 @@ mod tests {
         assert_eq!(err.to_string(), "field_name");
     }
 
+    #[test]
+    fn test_std_io_error() {
+        let io_err = std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "early eof");
+        let err: Error = io_err.into();
+        assert_eq!(err.to_string(), "early eof");
+    }
+
     #[test]
     fn test_invalid_transition() {
         let err = Error::InvalidTransition {
@@ -0,0 +1,61 @@
+//! Host Name Validation
+//!
+//! Syntax checks shared by the validators of the iab-specs sub-crates, for instance the
+//! contact fields of ads.txt and sellers.json files.
+
+/// Returns `true` if `host` is a fully qualified host name: dot separated labels of ASCII
+/// letters, digits and hyphens, a label neither starting nor ending with a hyphen.
+pub fn is_host_name(host: &str) -> bool {
+    host.contains('.')
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .bytes()
+                    .all(|c| c.is_ascii_alphanumeric() || c == b'-')
+        })
+}
+
+/// Returns `true` if `email` is an email address on a valid host name.
+///
+/// Only the shape `local@host` is checked, the local part being non-empty and without
+/// whitespace.
+pub fn is_email_address(email: &str) -> bool {
+    email.split_once('@').is_some_and(|(local, host)| {
+        !local.is_empty() && !local.contains(char::is_whitespace) && is_host_name(host)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn host_names() {
+        assert!(is_host_name("example.com"));
+        assert!(is_host_name("ads-1.example.co.uk"));
+        for host in [
+            "example",
+            "example..com",
+            "-example.com",
+            "example-.com",
+            "exa mple.com",
+        ] {
+            assert!(!is_host_name(host), "{host}");
+        }
+    }
+
+    #[test]
+    fn email_addresses() {
+        assert!(is_email_address("adops@example.com"));
+        for email in [
+            "adops",
+            "@example.com",
+            "ad ops@example.com",
+            "adops@-example.com",
+        ] {
+            assert!(!is_email_address(email), "{email}");
+        }
+    }
+}
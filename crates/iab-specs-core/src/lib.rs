@@ -4,13 +4,19 @@
 //! - [`Extension`] trait for type-safe extension fields
 //! - [`DefaultExt`] type alias (`Vec<u8>`) for opaque byte extensions
 //! - [`Error`] and [`Result`] types for error handling
+//! - [`Code`] for integer codes of enumerated lists, with unknown codes preserved
+//! - [`is_host_name`] and [`is_email_address`] for host name and email address validation
 //! - [`slice_up_to!`] macro for safe string slicing
 
+mod code;
 mod errors;
 mod extension;
+mod host;
 
+pub use code::*;
 pub use errors::*;
 pub use extension::*;
+pub use host::*;
 
 #[macro_export]
 macro_rules! slice_up_to {
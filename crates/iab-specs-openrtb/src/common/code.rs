@@ -0,0 +1,57 @@
+//! List Code
+//!
+//! OpenRTB 2.x objects carry values of the AdCOM enumerated lists as raw integer codes, so
+//! that codes added by later revisions of the lists or defined by exchanges survive a
+//! roundtrip. [`Code`] decodes such a code to the matching AdCOM enum when it is known.
+//!
+//! Reference: <https://github.com/InteractiveAdvertisingBureau/AdCOM/blob/main/AdCOM%20v1.0%20FINAL.md#enumerated-lists>
+
+pub use iab_specs_core::Code;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iab_specs_adcom::enums::{CreativeAttribute, LocationService, SlotPosition};
+
+    #[test]
+    fn test_code_known_and_unknown() {
+        let code: Code<CreativeAttribute> = Code::from(1);
+        assert_eq!(code, Code::Known(CreativeAttribute::AudioAuto));
+        assert!(code.is_known());
+        assert_eq!(code.known(), Some(CreativeAttribute::AudioAuto));
+
+        let code: Code<CreativeAttribute> = Code::from(500);
+        assert_eq!(code, Code::Unknown(500));
+        assert!(!code.is_known());
+        assert_eq!(code.known(), None);
+    }
+
+    #[test]
+    fn test_code_out_of_repr_range() {
+        // Codes outside of the enum representation are unknown, not truncated
+        assert_eq!(
+            Code::<SlotPosition>::from(-1),
+            Code::Known(SlotPosition::Last)
+        );
+        assert_eq!(Code::<SlotPosition>::from(-129), Code::Unknown(-129));
+        assert_eq!(
+            Code::<LocationService>::from(511),
+            Code::Known(LocationService::FiftyOneDegreesHigh)
+        );
+        assert_eq!(Code::<LocationService>::from(-1), Code::Unknown(-1));
+        assert_eq!(Code::<LocationService>::from(70_000), Code::Unknown(70_000));
+    }
+
+    #[test]
+    fn test_code_from_codes() {
+        let codes = Code::<CreativeAttribute>::from_codes(&[1, 999, 3]);
+        assert_eq!(
+            codes,
+            vec![
+                Code::Known(CreativeAttribute::AudioAuto),
+                Code::Unknown(999),
+                Code::Known(CreativeAttribute::ExpandableAuto),
+            ]
+        );
+    }
+}
@@ -4,9 +4,12 @@
 ///
 /// Some objects are specific to the OpenRTB transaction protocol and are not part of AdCOM:
 /// - `SupplyChain` / `SupplyChainNode`: Supply chain transparency objects
+/// - `Code`: Integer code of an AdCOM enumerated list, with unknown codes preserved
 // OpenRTB-specific common types
+mod code;
 mod supply_chain;
 mod supply_chain_node;
 
+pub use code::*;
 pub use supply_chain::*;
 pub use supply_chain_node::*;
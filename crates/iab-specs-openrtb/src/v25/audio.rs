@@ -1,10 +1,15 @@
 use super::banner::Banner;
+use crate::Code;
 use crate::Extension;
 /// OpenRTB 2.5/2.6 Audio Ad Object
 ///
 /// This module implements the Audio object for OpenRTB 2.5 and 2.6.
 /// OpenRTB 2.6 fields (podid, podseq, slotinpod, durfloors) are included.
 use derive_builder::Builder;
+use iab_specs_adcom::enums::{
+    ApiFramework, ContentDeliveryMethod, CreativeAttribute, FeedType, Protocol, SlotPosition,
+    StartDelay, VolumeNormalizationMode,
+};
 use serde::{Deserialize, Serialize};
 
 // Import DurFloors from v26 when openrtb_26 feature is enabled
@@ -201,6 +206,48 @@ impl Audio {
     }
 }
 
+impl<Ext: Extension> Audio<Ext> {
+    /// Supported audio protocols, `protocols` decoded as [`Protocol`].
+    pub fn supported_protocols(&self) -> Option<Vec<Code<Protocol>>> {
+        self.protocols.as_deref().map(Code::from_codes)
+    }
+
+    /// Start delay of the placement, `startdelay` decoded as [`StartDelay`].
+    pub fn start_delay(&self) -> Option<StartDelay> {
+        self.startdelay.map(StartDelay)
+    }
+
+    /// Guaranteed position of the slot in the pod, `slotinpod` decoded as [`SlotPosition`].
+    pub fn slot_position(&self) -> Code<SlotPosition> {
+        Code::from(self.slotinpod)
+    }
+
+    /// Blocked creative attributes, `battr` decoded as [`CreativeAttribute`].
+    pub fn creative_attributes(&self) -> Option<Vec<Code<CreativeAttribute>>> {
+        self.battr.as_deref().map(Code::from_codes)
+    }
+
+    /// Supported delivery methods, `delivery` decoded as [`ContentDeliveryMethod`].
+    pub fn delivery_methods(&self) -> Option<Vec<Code<ContentDeliveryMethod>>> {
+        self.delivery.as_deref().map(Code::from_codes)
+    }
+
+    /// Supported API frameworks, `api` decoded as [`ApiFramework`].
+    pub fn api_frameworks(&self) -> Option<Vec<Code<ApiFramework>>> {
+        self.api.as_deref().map(Code::from_codes)
+    }
+
+    /// Type of audio feed, `feed` decoded as [`FeedType`].
+    pub fn feed_type(&self) -> Option<Code<FeedType>> {
+        self.feed.map(Code::from)
+    }
+
+    /// Volume normalization mode, `nvol` decoded as [`VolumeNormalizationMode`].
+    pub fn volume_normalization_mode(&self) -> Option<Code<VolumeNormalizationMode>> {
+        self.nvol.map(Code::from)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -521,4 +568,35 @@ mod tests {
         assert_eq!(audio.nvol, deserialized.nvol);
         assert_eq!(audio, deserialized);
     }
+
+    #[test]
+    fn test_audio_typed_codes() {
+        let json = r#"{
+            "mimes": ["audio/mp4"],
+            "protocols": [9],
+            "startdelay": 0,
+            "feed": 3,
+            "nvol": 3,
+            "battr": [2]
+        }"#;
+        let audio: Audio = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            audio.supported_protocols(),
+            Some(vec![Code::Known(Protocol::Daast1)])
+        );
+        assert_eq!(audio.start_delay(), Some(StartDelay::PRE_ROLL));
+        assert_eq!(audio.slot_position(), Code::Known(SlotPosition::Any));
+        assert_eq!(audio.feed_type(), Some(Code::Known(FeedType::Podcast)));
+        assert_eq!(
+            audio.volume_normalization_mode(),
+            Some(Code::Known(VolumeNormalizationMode::Loudness))
+        );
+        assert_eq!(
+            audio.creative_attributes(),
+            Some(vec![Code::Known(CreativeAttribute::AudioUser)])
+        );
+        assert_eq!(audio.delivery_methods(), None);
+        assert_eq!(audio.api_frameworks(), None);
+    }
 }
@@ -1,10 +1,14 @@
 use super::Format;
 use crate::Extension;
 
+use crate::Code;
 /// OpenRTB 2.5 Banner Ad Objects
 ///
 /// This module implements Banner and Format objects for OpenRTB 2.5.
 use derive_builder::Builder;
+use iab_specs_adcom::enums::{
+    AdPosition, ApiFramework, BannerAdType, CreativeAttribute, ExpandableDirection,
+};
 use serde::{Deserialize, Serialize};
 
 /// Banner ad impression (OpenRTB 2.5 Section 3.2.6)
@@ -119,6 +123,33 @@ impl Banner {
     }
 }
 
+impl<Ext: Extension> Banner<Ext> {
+    /// Blocked banner ad types, `btype` decoded as [`BannerAdType`].
+    pub fn banner_ad_types(&self) -> Option<Vec<Code<BannerAdType>>> {
+        self.btype.as_deref().map(Code::from_codes)
+    }
+
+    /// Blocked creative attributes, `battr` decoded as [`CreativeAttribute`].
+    pub fn creative_attributes(&self) -> Option<Vec<Code<CreativeAttribute>>> {
+        self.battr.as_deref().map(Code::from_codes)
+    }
+
+    /// Ad position on screen, `pos` decoded as [`AdPosition`].
+    pub fn ad_position(&self) -> Option<Code<AdPosition>> {
+        self.pos.map(Code::from)
+    }
+
+    /// Directions in which the banner may expand, `expdir` decoded as [`ExpandableDirection`].
+    pub fn expandable_directions(&self) -> Option<Vec<Code<ExpandableDirection>>> {
+        self.expdir.as_deref().map(Code::from_codes)
+    }
+
+    /// Supported API frameworks, `api` decoded as [`ApiFramework`].
+    pub fn api_frameworks(&self) -> Option<Vec<Code<ApiFramework>>> {
+        self.api.as_deref().map(Code::from_codes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -538,4 +569,35 @@ mod tests {
         assert_eq!(banner.vcm, deserialized.vcm);
         assert_eq!(banner, deserialized);
     }
+
+    #[test]
+    fn test_banner_typed_codes() {
+        let banner: Banner =
+            serde_json::from_str(r#"{"btype":[3],"battr":[1,42],"pos":1,"expdir":[5],"api":[5]}"#)
+                .unwrap();
+
+        assert_eq!(
+            banner.banner_ad_types(),
+            Some(vec![Code::Known(BannerAdType::JavaScriptAd)])
+        );
+        assert_eq!(
+            banner.creative_attributes(),
+            Some(vec![
+                Code::Known(CreativeAttribute::AudioAuto),
+                Code::Unknown(42)
+            ])
+        );
+        assert_eq!(
+            banner.ad_position(),
+            Some(Code::Known(AdPosition::AboveTheFold))
+        );
+        assert_eq!(
+            banner.expandable_directions(),
+            Some(vec![Code::Known(ExpandableDirection::FullScreen)])
+        );
+        assert_eq!(
+            banner.api_frameworks(),
+            Some(vec![Code::Known(ApiFramework::Mraid2)])
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use crate::Code;
 use crate::Extension;
 /// OpenRTB 2.5 Bid Objects
 ///
@@ -13,6 +14,7 @@ use crate::Extension;
 /// bidding. The request flows from publisher to exchange to bidder, and the
 /// response flows back with bid prices and creative information.
 use derive_builder::Builder;
+use iab_specs_adcom::enums::{ApiFramework, CreativeAttribute, Protocol, QagMediaRating};
 use serde::{Deserialize, Serialize};
 
 /// Individual bid for a specific impression (OpenRTB 2.5 Section 4.2.3)
@@ -141,6 +143,21 @@ pub struct Bid<Ext: Extension = crate::DefaultExt> {
     #[builder(default)]
     pub attr: Option<Vec<i32>>,
 
+    /// API required by the markup if applicable. Refer to AdCOM `ApiFramework`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub api: Option<i32>,
+
+    /// Video response protocol of the markup if applicable. Refer to AdCOM `Protocol`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub protocol: Option<i32>,
+
+    /// Creative media rating per IQG guidelines. Refer to AdCOM `QagMediaRating`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub qagmediarating: Option<i32>,
+
     /// Language of the creative using ISO-639-1-alpha-2. In OpenRTB 2.5, only one
     /// language is supported per bid. OpenRTB 2.6 introduced `langb` for BCP 47.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -178,6 +195,28 @@ impl Bid {
     }
 }
 
+impl<Ext: Extension> Bid<Ext> {
+    /// Attributes describing the creative, `attr` decoded as [`CreativeAttribute`].
+    pub fn creative_attributes(&self) -> Option<Vec<Code<CreativeAttribute>>> {
+        self.attr.as_deref().map(Code::from_codes)
+    }
+
+    /// API required by the markup, `api` decoded as [`ApiFramework`].
+    pub fn api_framework(&self) -> Option<Code<ApiFramework>> {
+        self.api.map(Code::from)
+    }
+
+    /// Video response protocol of the markup, `protocol` decoded as [`Protocol`].
+    pub fn response_protocol(&self) -> Option<Code<Protocol>> {
+        self.protocol.map(Code::from)
+    }
+
+    /// Media rating of the creative, `qagmediarating` decoded as [`QagMediaRating`].
+    pub fn qag_media_rating(&self) -> Option<Code<QagMediaRating>> {
+        self.qagmediarating.map(Code::from)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -497,4 +536,38 @@ mod tests {
         assert_eq!(bid.h, deserialized.h);
         assert_eq!(bid.ext, deserialized.ext);
     }
+
+    #[test]
+    fn test_bid_creative_attributes() {
+        let bid: Bid =
+            serde_json::from_str(r#"{"id":"b1","impid":"1","price":1.5,"attr":[6,300]}"#).unwrap();
+
+        assert_eq!(
+            bid.creative_attributes(),
+            Some(vec![
+                Code::Known(CreativeAttribute::VideoBannerAuto),
+                Code::Unknown(300)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_bid_markup_codes() {
+        // Spec: Section 4.2.3 — api, protocol and qagmediarating of the markup
+        let json = r#"{"id":"b1","impid":"1","price":1.5,"api":7,"protocol":3,"qagmediarating":1}"#;
+        let bid: Bid = serde_json::from_str(json).unwrap();
+
+        assert_eq!(bid.api_framework(), Some(Code::Known(ApiFramework::Omid1)));
+        assert_eq!(bid.response_protocol(), Some(Code::Known(Protocol::Vast3)));
+        assert_eq!(
+            bid.qag_media_rating(),
+            Some(Code::Known(QagMediaRating::AllAudiences))
+        );
+        assert_eq!(serde_json::to_string(&bid).unwrap(), json);
+
+        let bid: Bid =
+            serde_json::from_str(r#"{"id":"b1","impid":"1","price":1.5,"api":500}"#).unwrap();
+        assert_eq!(bid.api_framework(), Some(Code::Unknown(500)));
+        assert_eq!(bid.response_protocol(), None);
+    }
 }
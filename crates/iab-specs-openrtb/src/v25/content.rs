@@ -1,10 +1,12 @@
 use super::Data;
 use super::producer::Producer;
+use crate::Code;
 use crate::Extension;
 /// OpenRTB 2.5 Content Object
 ///
 /// This module implements the Content object for content metadata.
 use derive_builder::Builder;
+use iab_specs_adcom::enums::{ContentContext, ProductionQuality, QagMediaRating};
 use serde::{Deserialize, Serialize};
 
 /// Default category taxonomy (1 = IAB Content Category Taxonomy 1.0)
@@ -227,6 +229,25 @@ impl Content {
     }
 }
 
+impl<Ext: Extension, NetworkExt: Extension, ChannelExt: Extension>
+    Content<Ext, NetworkExt, ChannelExt>
+{
+    /// Production quality, `prodq` decoded as [`ProductionQuality`].
+    pub fn production_quality(&self) -> Option<Code<ProductionQuality>> {
+        self.prodq.map(Code::from)
+    }
+
+    /// Type of content, `context` decoded as [`ContentContext`].
+    pub fn content_context(&self) -> Option<Code<ContentContext>> {
+        self.context.map(Code::from)
+    }
+
+    /// Media rating per IQG guidelines, `qagmediarating` decoded as [`QagMediaRating`].
+    pub fn qag_media_rating(&self) -> Option<Code<QagMediaRating>> {
+        self.qagmediarating.map(Code::from)
+    }
+}
+
 impl<Ext: Extension, NetworkExt: Extension, ChannelExt: Extension> Default
     for Content<Ext, NetworkExt, ChannelExt>
 {
@@ -475,4 +496,20 @@ mod tests {
         assert_eq!(content.langb, deserialized.langb);
         assert_eq!(content.embeddable, deserialized.embeddable);
     }
+
+    #[test]
+    fn test_content_typed_codes() {
+        let content: Content =
+            serde_json::from_str(r#"{"prodq":1,"context":1,"qagmediarating":4}"#).unwrap();
+
+        assert_eq!(
+            content.production_quality(),
+            Some(Code::Known(ProductionQuality::Professional))
+        );
+        assert_eq!(
+            content.content_context(),
+            Some(Code::Known(ContentContext::Video))
+        );
+        assert_eq!(content.qag_media_rating(), Some(Code::Unknown(4)));
+    }
 }
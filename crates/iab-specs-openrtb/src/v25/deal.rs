@@ -0,0 +1,271 @@
+use crate::Code;
+use crate::Extension;
+/// OpenRTB 2.5/2.6 Deal Object
+///
+/// This module implements the Deal object for private marketplace transactions.
+/// OpenRTB 2.6 fields (guar, mincpmpersec, durfloors) are included.
+use derive_builder::Builder;
+use iab_specs_adcom::enums::AuctionType;
+use serde::{Deserialize, Serialize};
+
+// Import DurFloors from v26 when openrtb_26 feature is enabled
+#[cfg(feature = "openrtb_26")]
+use crate::v26::DurFloors;
+
+/// Default currency for bid floor (USD per OpenRTB 2.5 spec)
+fn default_bidfloorcur() -> String {
+    "USD".to_string()
+}
+
+/// Deal object (OpenRTB 2.5 Section 3.2.12)
+///
+/// A `Deal` object constitutes a specific deal that was struck a priori between a buyer
+/// and a seller. Its presence within the [`Pmp`](super::Pmp) collection indicates that this
+/// impression is available under the terms of that deal.
+///
+/// # Generic Parameters
+///
+/// * `Ext` - Extension object type (must implement [`Extension`]). Defaults to [`DefaultExt`](crate::DefaultExt).
+///
+/// # Example
+///
+/// ```
+/// use iab_specs_openrtb::v25::Deal;
+///
+/// let deal = Deal::builder()
+///     .id("AB-Agency1-0001".to_string())
+///     .bidfloor(2.5)
+///     .at(Some(1))
+///     .wseat(Some(vec!["Agency1".to_string()]))
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(deal.bidfloorcur, "USD");
+/// ```
+#[derive(Builder, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[builder(build_fn(error = "crate::Error"), default)]
+#[serde(bound(serialize = "Ext: Extension", deserialize = "Ext: Extension"))]
+pub struct Deal<Ext: Extension = crate::DefaultExt> {
+    /// A unique identifier for the direct deal.
+    /// **Required field**.
+    #[builder(setter(into))]
+    pub id: String,
+
+    /// Minimum bid for this impression expressed in CPM.
+    /// Default is 0.
+    #[serde(default)]
+    #[builder(default)]
+    pub bidfloor: f64,
+
+    /// Currency for bid floor using ISO-4217 alpha codes.
+    /// Default is "USD".
+    #[serde(default = "default_bidfloorcur")]
+    #[builder(default = "default_bidfloorcur()")]
+    pub bidfloorcur: String,
+
+    /// Optional override of the overall auction type of the bid request:
+    /// - 1 = First Price
+    /// - 2 = Second Price Plus
+    /// - 3 = the value passed in `bidfloor` is the agreed upon deal price
+    ///
+    /// Additional auction types can be defined by the exchange.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub at: Option<i32>,
+
+    /// Allowed list of buyer seats (e.g., advertisers, agencies) allowed to bid on this deal.
+    /// Omission implies no seat restrictions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub wseat: Option<Vec<String>>,
+
+    /// Array of advertiser domains (e.g., advertiser.com) allowed to bid on this deal.
+    /// Omission implies no advertiser restrictions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub wadomain: Option<Vec<String>>,
+
+    /// Indicates that the deal is of type guaranteed and the bidder must bid on the deal:
+    /// - 0 = not a guaranteed deal (default)
+    /// - 1 = guaranteed deal
+    #[serde(default)]
+    #[builder(default)]
+    pub guar: i32,
+
+    /// Minimum CPM per second, the price for video or audio impressions of this deal with
+    /// variable durations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub mincpmpersec: Option<f64>,
+
+    /// Array of DurFloors objects defining duration-based floor prices for this deal
+    /// (OpenRTB 2.6+).
+    #[cfg(feature = "openrtb_26")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub durfloors: Option<Vec<DurFloors>>,
+
+    /// Array of DurFloors objects defining duration-based floor prices (placeholder for v2.5).
+    /// When using openrtb_26 feature, use the typed DurFloors version instead.
+    #[cfg(not(feature = "openrtb_26"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub durfloors: Option<Vec<crate::DefaultExt>>,
+
+    /// Extension object for exchange-specific extensions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub ext: Option<Box<Ext>>,
+}
+
+impl Deal {
+    /// Convenience method to create a new instance using the builder pattern.
+    pub fn builder() -> DealBuilder {
+        DealBuilder::create_empty()
+    }
+}
+
+impl<Ext: Extension> Deal<Ext> {
+    /// Auction type override of the deal, `at` decoded as [`AuctionType`].
+    pub fn auction_type(&self) -> Option<Code<AuctionType>> {
+        self.at.map(Code::from)
+    }
+}
+
+impl<Ext: Extension> Default for Deal<Ext> {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            bidfloor: 0.0,
+            bidfloorcur: default_bidfloorcur(),
+            at: None,
+            wseat: None,
+            wadomain: None,
+            guar: 0,
+            mincpmpersec: None,
+            durfloors: None,
+            ext: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deal_defaults() {
+        // Spec: Section 3.2.12 — bidfloor defaults to 0, bidfloorcur to USD, guar to 0
+        let deal: Deal = serde_json::from_str(r#"{"id":"deal-1"}"#).unwrap();
+
+        assert_eq!(deal.id, "deal-1");
+        assert_eq!(deal.bidfloor, 0.0);
+        assert_eq!(deal.bidfloorcur, "USD");
+        assert_eq!(deal.at, None);
+        assert_eq!(deal.guar, 0);
+        assert_eq!(deal, Deal::builder().id("deal-1").build().unwrap());
+    }
+
+    #[test]
+    fn test_deal_missing_required_id() {
+        let result: Result<Deal, _> = serde_json::from_str(r#"{"bidfloor":2.5}"#);
+        assert!(
+            result.is_err(),
+            "Deal without required 'id' field should fail deserialization"
+        );
+    }
+
+    #[test]
+    fn test_deal_spec_example_roundtrip() {
+        // Spec: Section 6.2.1 — deal example of the private marketplace bid request
+        let json = r#"{
+            "id": "AB-Agency1-0001",
+            "at": 1,
+            "bidfloor": 2.5,
+            "wseat": ["Agency1"]
+        }"#;
+        let deal: Deal = serde_json::from_str(json).unwrap();
+
+        assert_eq!(deal.id, "AB-Agency1-0001");
+        assert_eq!(deal.at, Some(1));
+        assert_eq!(deal.bidfloor, 2.5);
+        assert_eq!(deal.wseat, Some(vec!["Agency1".to_string()]));
+
+        let roundtrip: Deal = serde_json::from_str(&serde_json::to_string(&deal).unwrap()).unwrap();
+        assert_eq!(deal, roundtrip);
+    }
+
+    #[test]
+    fn test_deal_guaranteed_with_mincpmpersec() {
+        // Spec: OpenRTB 2.6 Section 3.2.12 — guar and mincpmpersec
+        let deal = Deal::builder()
+            .id("pg-1")
+            .bidfloor(12.0)
+            .bidfloorcur("EUR".to_string())
+            .at(Some(3))
+            .wadomain(Some(vec!["advertiser.com".to_string()]))
+            .guar(1)
+            .mincpmpersec(Some(0.5))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_string(&deal).unwrap();
+        assert!(json.contains("\"guar\":1"));
+        assert!(json.contains("\"mincpmpersec\":0.5"));
+        assert!(json.contains("\"bidfloorcur\":\"EUR\""));
+
+        let deserialized: Deal = serde_json::from_str(&json).unwrap();
+        assert_eq!(deal, deserialized);
+    }
+
+    #[test]
+    fn test_deal_with_extension() {
+        let deal = DealBuilder::<serde_json::Value>::default()
+            .id("deal-ext")
+            .ext(Some(Box::new(serde_json::json!({"priority": 1}))))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_string(&deal).unwrap();
+        let deserialized: Deal<serde_json::Value> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.ext.unwrap()["priority"], 1);
+    }
+
+    #[test]
+    fn test_deal_auction_type() {
+        let deal = Deal::builder().id("d1").at(Some(3)).build().unwrap();
+        assert_eq!(
+            deal.auction_type(),
+            Some(Code::Known(AuctionType::FixedPrice))
+        );
+        assert_eq!(
+            Deal::builder().id("d2").build().unwrap().auction_type(),
+            None
+        );
+    }
+
+    // === Feature Flag Tests (openrtb_26) ===
+
+    #[cfg(feature = "openrtb_26")]
+    #[test]
+    fn test_deal_with_durfloors() {
+        // Spec: OpenRTB 2.6 Section 3.2.12 — durfloors
+        let json = r#"{
+            "id": "ctv-deal",
+            "bidfloor": 10.0,
+            "durfloors": [
+                {"maxduration": 15, "bidfloor": 10.0},
+                {"minduration": 16, "maxduration": 30, "bidfloor": 18.0}
+            ]
+        }"#;
+        let deal: Deal = serde_json::from_str(json).unwrap();
+
+        let durfloors = deal.durfloors.as_ref().unwrap();
+        assert_eq!(durfloors.len(), 2);
+        assert_eq!(durfloors[1].minduration, Some(16));
+        assert_eq!(durfloors[1].bidfloor, Some(18.0));
+
+        let roundtrip: Deal = serde_json::from_str(&serde_json::to_string(&deal).unwrap()).unwrap();
+        assert_eq!(deal, roundtrip);
+    }
+}
@@ -3,11 +3,13 @@
 /// This module implements the Device object for device information.
 /// OpenRTB 2.6 adds the sua (structured user-agent) field.
 use derive_builder::Builder;
+use iab_specs_adcom::enums::{ConnectionType, DeviceType};
 use serde::{Deserialize, Serialize};
 
 use super::geo::Geo;
 
 // Import UserAgent from AdCOM when openrtb_26 feature is enabled
+use crate::Code;
 use crate::Extension;
 #[cfg(feature = "openrtb_26")]
 use iab_specs_adcom::context::UserAgent;
@@ -226,6 +228,18 @@ impl Device {
     }
 }
 
+impl<Ext: Extension> Device<Ext> {
+    /// General type of device, `devicetype` decoded as [`DeviceType`].
+    pub fn device_type(&self) -> Option<Code<DeviceType>> {
+        self.devicetype.map(Code::from)
+    }
+
+    /// Network connection type, `connectiontype` decoded as [`ConnectionType`].
+    pub fn connection_type(&self) -> Option<Code<ConnectionType>> {
+        self.connectiontype.map(Code::from)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -634,4 +648,18 @@ mod tests {
         assert_eq!(device.macmd5, deserialized.macmd5);
         assert_eq!(device.geo, deserialized.geo);
     }
+
+    #[test]
+    fn test_device_typed_codes() {
+        // Spec: Section 3.2.18 — devicetype and connectiontype
+        let device: Device =
+            serde_json::from_str(r#"{"devicetype":3,"connectiontype":99}"#).unwrap();
+
+        assert_eq!(
+            device.device_type(),
+            Some(Code::Known(DeviceType::ConnectedTv))
+        );
+        assert_eq!(device.connection_type(), Some(Code::Unknown(99)));
+        assert_eq!(Device::builder().build().unwrap().device_type(), None);
+    }
 }
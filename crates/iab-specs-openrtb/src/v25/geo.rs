@@ -1,8 +1,10 @@
+use crate::Code;
 use crate::Extension;
 /// OpenRTB 2.5 Geo Object
 ///
 /// This module implements the Geo object for geographic location data.
 use derive_builder::Builder;
+use iab_specs_adcom::enums::{LocationService, LocationType};
 use serde::{Deserialize, Serialize};
 
 /// Geo object representing geographic location (OpenRTB 2.5 Section 3.2.19)
@@ -128,6 +130,18 @@ impl Geo {
     }
 }
 
+impl<Ext: Extension> Geo<Ext> {
+    /// Source of the location data, `type_` decoded as [`LocationType`].
+    pub fn location_type(&self) -> Option<Code<LocationType>> {
+        self.type_.map(Code::from)
+    }
+
+    /// Service or provider used to determine the geolocation from the IP address, `ipservice` decoded as [`LocationService`].
+    pub fn location_service(&self) -> Option<Code<LocationService>> {
+        self.ipservice.map(Code::from)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -300,4 +314,18 @@ mod tests {
         assert_eq!(geo.zip, deserialized.zip);
         assert_eq!(geo.utcoffset, deserialized.utcoffset);
     }
+
+    #[test]
+    fn test_geo_typed_codes() {
+        let geo: Geo = serde_json::from_str(r#"{"type":2,"ipservice":3}"#).unwrap();
+
+        assert_eq!(
+            geo.location_type(),
+            Some(Code::Known(LocationType::IpAddress))
+        );
+        assert_eq!(
+            geo.location_service(),
+            Some(Code::Known(LocationService::MaxMind))
+        );
+    }
 }
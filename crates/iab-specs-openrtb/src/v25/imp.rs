@@ -1,6 +1,8 @@
 use super::audio::Audio;
 use super::banner::Banner;
+use super::metric::Metric;
 use super::native::Native;
+use super::pmp::Pmp;
 use super::video::Video;
 use crate::Extension;
 /// OpenRTB 2.5/2.6 Impression Object
@@ -27,8 +29,8 @@ fn default_bidfloorcur() -> String {
 /// # Generic Parameters
 ///
 /// * `Ext` - Extension object type (must implement [`Extension`]). Defaults to [`DefaultExt`](crate::DefaultExt).
-/// * `MetricExt` - Extension object type (must implement [`Extension`]). Defaults to [`DefaultExt`](crate::DefaultExt).
-/// * `PmpExt` - Extension object type (must implement [`Extension`]). Defaults to [`DefaultExt`](crate::DefaultExt).
+/// * `MetricExt` - Extension object type of the [`Metric`] objects (must implement [`Extension`]). Defaults to [`DefaultExt`](crate::DefaultExt).
+/// * `PmpExt` - Extension object type of the [`Pmp`] and its deals (must implement [`Extension`]). Defaults to [`DefaultExt`](crate::DefaultExt).
 #[derive(Builder, Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[builder(build_fn(error = "crate::Error"), default)]
 #[serde(bound(
@@ -46,10 +48,9 @@ pub struct Imp<
     pub id: String,
 
     /// Array of Metric objects for viewability or verification measurement.
-    /// Uses placeholder until Metric is implemented.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default)]
-    pub metric: Option<Vec<Box<MetricExt>>>,
+    pub metric: Option<Vec<Metric<MetricExt>>>,
 
     /// Banner object indicating a banner impression is offered.
     /// At least one of banner, video, audio, or native must be present.
@@ -75,11 +76,10 @@ pub struct Imp<
     #[builder(default)]
     pub native: Option<Native>,
 
-    /// Pmp object containing private marketplace deals.
-    /// Uses placeholder until Pmp is implemented.
+    /// Pmp object containing any private marketplace deals in effect for this impression.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default)]
-    pub pmp: Option<Box<PmpExt>>,
+    pub pmp: Option<Pmp<PmpExt>>,
 
     /// Name of ad mediation partner, SDK technology, or player responsible for rendering.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -200,6 +200,19 @@ impl Imp {
     }
 }
 
+impl<Ext: Extension, MetricExt: Extension, PmpExt: Extension> Imp<Ext, MetricExt, PmpExt> {
+    /// The first metric of type `type_`, compared case-insensitively.
+    ///
+    /// `type_` is usually a [`MetricType`](super::MetricType), or an exchange specific name.
+    pub fn find_metric(&self, type_: impl AsRef<str>) -> Option<&Metric<MetricExt>> {
+        let type_ = type_.as_ref();
+        self.metric
+            .iter()
+            .flatten()
+            .find(|metric| metric.has_type(type_))
+    }
+}
+
 impl<Ext: Extension, MetricExt: Extension, PmpExt: Extension> Default
     for Imp<Ext, MetricExt, PmpExt>
 {
@@ -303,6 +316,64 @@ mod tests {
         assert_eq!(imp.bidfloor, 1.5);
     }
 
+    #[test]
+    fn test_imp_with_pmp() {
+        // Spec: Section 6.2.1 — impression of the private marketplace bid request example
+        let json = r#"{
+            "id": "1",
+            "bidfloor": 0.03,
+            "banner": {"h": 250, "w": 300, "pos": 0},
+            "pmp": {
+                "private_auction": 1,
+                "deals": [
+                    {"id": "AB-Agency1-0001", "at": 1, "bidfloor": 2.5, "wseat": ["Agency1"]},
+                    {"id": "XY-Agency2-0001", "at": 2, "bidfloor": 2, "wseat": ["Agency2"]}
+                ]
+            }
+        }"#;
+        let imp: Imp = serde_json::from_str(json).unwrap();
+
+        let pmp = imp.pmp.as_ref().unwrap();
+        assert_eq!(pmp.private_auction, 1);
+        assert_eq!(pmp.deals.as_ref().unwrap()[0].id, "AB-Agency1-0001");
+
+        let roundtrip: Imp = serde_json::from_str(&serde_json::to_string(&imp).unwrap()).unwrap();
+        assert_eq!(imp, roundtrip);
+    }
+
+    #[test]
+    fn test_imp_find_metric() {
+        use crate::v25::MetricType;
+
+        // Spec: Section 3.2.5 — metrics offering insight into the impression
+        let json = r#"{
+            "id": "1",
+            "metric": [
+                {"type": "viewability", "value": 0.85, "vendor": "EXCHANGE"},
+                {"type": "session_depth", "value": 4},
+                {"type": "viewability", "value": 0.6, "vendor": "vendor.com"}
+            ]
+        }"#;
+        let imp: Imp = serde_json::from_str(json).unwrap();
+
+        let viewability = imp.find_metric(MetricType::Viewability).unwrap();
+        assert_eq!(viewability.value, 0.85);
+        assert_eq!(viewability.vendor.as_deref(), Some("EXCHANGE"));
+        assert_eq!(imp.find_metric("SESSION_DEPTH").unwrap().value, 4.0);
+        assert!(imp.find_metric(MetricType::ClickThroughRate).is_none());
+        assert!(
+            Imp::builder()
+                .id("2")
+                .build()
+                .unwrap()
+                .find_metric("viewability")
+                .is_none()
+        );
+
+        let roundtrip: Imp = serde_json::from_str(&serde_json::to_string(&imp).unwrap()).unwrap();
+        assert_eq!(imp, roundtrip);
+    }
+
     // === Phase 1.2: Required Field Validation Tests ===
 
     #[test]
@@ -0,0 +1,178 @@
+use crate::Extension;
+/// OpenRTB 2.5/2.6 Metric Object
+///
+/// This module implements the Metric object for impression measurement signals.
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// Well-known metric types.
+///
+/// Metric types are exchange curated names published to bidders beforehand. These are the
+/// names given as examples by the specification and commonly used by exchanges.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MetricType {
+    /// Probability that the impression will be viewable, `viewability`.
+    Viewability,
+    /// Depth of the impression within the user session, `session_depth`.
+    SessionDepth,
+    /// Probability of a click, `click_through_rate`.
+    ClickThroughRate,
+    /// Probability that a video is watched to completion, `video_completion_rate`.
+    VideoCompletionRate,
+}
+
+impl MetricType {
+    /// The name of the metric type in the `type` field.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MetricType::Viewability => "viewability",
+            MetricType::SessionDepth => "session_depth",
+            MetricType::ClickThroughRate => "click_through_rate",
+            MetricType::VideoCompletionRate => "video_completion_rate",
+        }
+    }
+}
+
+impl AsRef<str> for MetricType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for MetricType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Metric object (OpenRTB 2.5 Section 3.2.5)
+///
+/// A `Metric` object is associated with an impression as an array of metrics. These metrics
+/// can offer insight into the impression to assist with decisioning such as average recent
+/// viewability, click-through rate, etc. Each metric is identified by its type, reports the
+/// value of the metric, and optionally identifies the source or vendor measuring the value.
+///
+/// # Generic Parameters
+///
+/// * `Ext` - Extension object type (must implement [`Extension`]). Defaults to [`DefaultExt`](crate::DefaultExt).
+///
+/// # Example
+///
+/// ```
+/// use iab_specs_openrtb::v25::{Metric, MetricType};
+///
+/// let metric = Metric::builder()
+///     .type_(MetricType::Viewability.to_string())
+///     .value(0.85)
+///     .vendor(Some("EXCHANGE".to_string()))
+///     .build()
+///     .unwrap();
+///
+/// assert!(metric.has_type(MetricType::Viewability));
+/// ```
+#[derive(Builder, Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[builder(build_fn(error = "crate::Error"), default)]
+#[serde(bound(serialize = "Ext: Extension", deserialize = "Ext: Extension"))]
+pub struct Metric<Ext: Extension = crate::DefaultExt> {
+    /// Type of metric being presented using exchange curated string names which should be
+    /// published to bidders a priori, see [`MetricType`].
+    /// **Required field**.
+    #[serde(rename = "type")]
+    #[builder(setter(into))]
+    pub type_: String,
+
+    /// Number representing the value of the metric.
+    /// Probabilities must be in the range 0.0 – 1.0.
+    /// **Required field**.
+    pub value: f64,
+
+    /// Source of the value using exchange curated string names which should be published
+    /// to bidders a priori. If the exchange itself is the source versus a third party,
+    /// "EXCHANGE" is recommended.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub vendor: Option<String>,
+
+    /// Extension object for exchange-specific extensions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub ext: Option<Box<Ext>>,
+}
+
+impl Metric {
+    /// Convenience method to create a new instance using the builder pattern.
+    pub fn builder() -> MetricBuilder {
+        MetricBuilder::create_empty()
+    }
+}
+
+impl<Ext: Extension> Metric<Ext> {
+    /// Whether the metric is of type `type_`, compared case-insensitively.
+    pub fn has_type(&self, type_: impl AsRef<str>) -> bool {
+        self.type_.eq_ignore_ascii_case(type_.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metric_spec_example_roundtrip() {
+        // Spec: Section 3.2.5 — type, value and vendor
+        let json = r#"{"type":"viewability","value":0.85,"vendor":"EXCHANGE"}"#;
+        let metric: Metric = serde_json::from_str(json).unwrap();
+
+        assert_eq!(metric.type_, "viewability");
+        assert_eq!(metric.value, 0.85);
+        assert_eq!(metric.vendor.as_deref(), Some("EXCHANGE"));
+        assert_eq!(serde_json::to_string(&metric).unwrap(), json);
+    }
+
+    #[test]
+    fn test_metric_missing_required_fields() {
+        // Spec: Section 3.2.5 — type and value are required
+        assert!(serde_json::from_str::<Metric>(r#"{"value":0.5}"#).is_err());
+        assert!(serde_json::from_str::<Metric>(r#"{"type":"viewability"}"#).is_err());
+    }
+
+    #[test]
+    fn test_metric_has_type() {
+        let metric = Metric::builder()
+            .type_("Session_Depth")
+            .value(3.0)
+            .build()
+            .unwrap();
+
+        assert!(metric.has_type(MetricType::SessionDepth));
+        assert!(metric.has_type("session_depth"));
+        assert!(!metric.has_type(MetricType::Viewability));
+    }
+
+    #[test]
+    fn test_metric_type_names() {
+        assert_eq!(MetricType::Viewability.as_str(), "viewability");
+        assert_eq!(MetricType::SessionDepth.to_string(), "session_depth");
+        assert_eq!(MetricType::ClickThroughRate.as_str(), "click_through_rate");
+        assert_eq!(
+            MetricType::VideoCompletionRate.as_str(),
+            "video_completion_rate"
+        );
+    }
+
+    #[test]
+    fn test_metric_with_extension() {
+        let metric = MetricBuilder::<serde_json::Value>::default()
+            .type_("viewability")
+            .value(0.7)
+            .ext(Some(Box::new(serde_json::json!({"window": "30d"}))))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_string(&metric).unwrap();
+        let deserialized: Metric<serde_json::Value> = serde_json::from_str(&json).unwrap();
+        assert_eq!(metric, deserialized);
+    }
+}
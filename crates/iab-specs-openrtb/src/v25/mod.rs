@@ -91,9 +91,12 @@ mod seat_bid;
 // Impression and media objects
 mod audio;
 mod banner;
+mod deal;
 mod format;
 mod imp;
+mod metric;
 mod native;
+mod pmp;
 mod video;
 
 // Context objects
@@ -121,16 +124,21 @@ pub use seat_bid::SeatBid;
 // Re-export impression and media types
 pub use audio::Audio;
 pub use banner::Banner;
+pub use deal::Deal;
 pub use format::Format;
 pub use imp::Imp;
+pub use metric::{Metric, MetricType};
 pub use native::Native;
+pub use pmp::Pmp;
 pub use video::Video;
 
 // Re-export context types
 pub use app::App;
 pub use content::Content;
 pub use iab_specs_adcom::context::Data;
+pub use iab_specs_adcom::context::Eid;
 pub use iab_specs_adcom::context::Segment;
+pub use iab_specs_adcom::context::Uid;
 pub use producer::Producer;
 pub use publisher::Publisher;
 pub use site::Site;
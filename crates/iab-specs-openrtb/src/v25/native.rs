@@ -1,8 +1,10 @@
+use crate::Code;
 use crate::Extension;
 /// OpenRTB 2.5 Native Ad Object
 ///
 /// This module implements the Native object for OpenRTB 2.5.
 use derive_builder::Builder;
+use iab_specs_adcom::enums::{ApiFramework, CreativeAttribute};
 use serde::{Deserialize, Serialize};
 
 /// Native ad impression (OpenRTB 2.5 Section 3.2.9)
@@ -66,6 +68,18 @@ impl Native {
     }
 }
 
+impl<Ext: Extension> Native<Ext> {
+    /// Supported API frameworks, `api` decoded as [`ApiFramework`].
+    pub fn api_frameworks(&self) -> Option<Vec<Code<ApiFramework>>> {
+        self.api.as_deref().map(Code::from_codes)
+    }
+
+    /// Blocked creative attributes, `battr` decoded as [`CreativeAttribute`].
+    pub fn creative_attributes(&self) -> Option<Vec<Code<CreativeAttribute>>> {
+        self.battr.as_deref().map(Code::from_codes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -201,4 +215,19 @@ mod tests {
         assert_eq!(native.battr, deserialized.battr);
         assert_eq!(native, deserialized);
     }
+
+    #[test]
+    fn test_native_typed_codes() {
+        let native: Native =
+            serde_json::from_str(r#"{"request":"{}","api":[3],"battr":[8]}"#).unwrap();
+
+        assert_eq!(
+            native.api_frameworks(),
+            Some(vec![Code::Known(ApiFramework::Mraid1)])
+        );
+        assert_eq!(
+            native.creative_attributes(),
+            Some(vec![Code::Known(CreativeAttribute::Pop)])
+        );
+    }
 }
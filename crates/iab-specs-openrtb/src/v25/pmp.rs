@@ -0,0 +1,128 @@
+use super::deal::Deal;
+use crate::Extension;
+/// OpenRTB 2.5/2.6 Private Marketplace Object
+///
+/// This module implements the Pmp object for private marketplace deals.
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// Private marketplace object (OpenRTB 2.5 Section 3.2.11)
+///
+/// A `Pmp` object is the private marketplace container for direct deals between buyers and
+/// sellers that may pertain to this impression. The actual deals are represented as a
+/// collection of [`Deal`] objects.
+///
+/// # Generic Parameters
+///
+/// * `Ext` - Extension object type (must implement [`Extension`]). Defaults to [`DefaultExt`](crate::DefaultExt).
+///
+/// # Example
+///
+/// ```
+/// use iab_specs_openrtb::v25::{Deal, Pmp};
+///
+/// let pmp = Pmp::builder()
+///     .private_auction(1)
+///     .deals(Some(vec![
+///         Deal::builder()
+///             .id("AB-Agency1-0001".to_string())
+///             .bidfloor(2.5)
+///             .build()
+///             .unwrap(),
+///     ]))
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(pmp.deals.unwrap().len(), 1);
+/// ```
+#[derive(Builder, Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[builder(build_fn(error = "crate::Error"), default)]
+#[serde(bound(serialize = "Ext: Extension", deserialize = "Ext: Extension"))]
+pub struct Pmp<Ext: Extension = crate::DefaultExt> {
+    /// Indicator of auction eligibility to seats named in the Direct Deals object:
+    /// - 0 = all bids are accepted (default)
+    /// - 1 = bids are restricted to the deals specified and the terms thereof
+    #[serde(default)]
+    #[builder(default)]
+    pub private_auction: i32,
+
+    /// Array of Deal objects that convey the specific deals applicable to this impression.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub deals: Option<Vec<Deal<Ext>>>,
+
+    /// Extension object for exchange-specific extensions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub ext: Option<Box<Ext>>,
+}
+
+impl Pmp {
+    /// Convenience method to create a new instance using the builder pattern.
+    pub fn builder() -> PmpBuilder {
+        PmpBuilder::create_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pmp_defaults() {
+        // Spec: Section 3.2.11 — private_auction defaults to 0
+        let pmp: Pmp = serde_json::from_str("{}").unwrap();
+
+        assert_eq!(pmp.private_auction, 0);
+        assert_eq!(pmp.deals, None);
+        assert_eq!(pmp, Pmp::builder().build().unwrap());
+    }
+
+    #[test]
+    fn test_pmp_spec_example_roundtrip() {
+        // Spec: Section 6.2.1 — private marketplace bid request example
+        let json = r#"{
+            "private_auction": 1,
+            "deals": [
+                {
+                    "id": "AB-Agency1-0001",
+                    "at": 1,
+                    "bidfloor": 2.5,
+                    "wseat": ["Agency1"]
+                },
+                {
+                    "id": "XY-Agency2-0001",
+                    "at": 2,
+                    "bidfloor": 2,
+                    "wseat": ["Agency2"]
+                }
+            ]
+        }"#;
+        let pmp: Pmp = serde_json::from_str(json).unwrap();
+
+        assert_eq!(pmp.private_auction, 1);
+        let deals = pmp.deals.as_ref().unwrap();
+        assert_eq!(deals.len(), 2);
+        assert_eq!(deals[0].id, "AB-Agency1-0001");
+        assert_eq!(deals[1].at, Some(2));
+        assert_eq!(deals[1].bidfloor, 2.0);
+        assert_eq!(deals[1].bidfloorcur, "USD");
+
+        let roundtrip: Pmp = serde_json::from_str(&serde_json::to_string(&pmp).unwrap()).unwrap();
+        assert_eq!(pmp, roundtrip);
+    }
+
+    #[test]
+    fn test_pmp_with_extension() {
+        let pmp = PmpBuilder::<serde_json::Value>::default()
+            .private_auction(1)
+            .ext(Some(Box::new(serde_json::json!({"exchange": "value"}))))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_string(&pmp).unwrap();
+        assert!(json.contains("\"private_auction\":1"));
+        let deserialized: Pmp<serde_json::Value> = serde_json::from_str(&json).unwrap();
+        assert_eq!(pmp, deserialized);
+    }
+}
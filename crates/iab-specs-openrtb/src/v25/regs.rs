@@ -1,7 +1,8 @@
 use crate::Extension;
-/// OpenRTB 2.5 Regs Object
+/// OpenRTB 2.5/2.6 Regs Object
 ///
 /// This module implements the Regs object for regulatory compliance.
+/// OpenRTB 2.6 fields (gdpr, us_privacy, gpp, gpp_sid) are included.
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
@@ -38,9 +39,40 @@ pub struct Regs<Ext: Extension = crate::DefaultExt> {
     #[builder(default)]
     pub coppa: Option<i32>,
 
+    /// Flag that indicates whether or not the request is subject to GDPR regulations
+    /// (OpenRTB 2.6+):
+    /// - 0 = no
+    /// - 1 = yes
+    ///
+    /// If omitted, the request is assumed to be unknown.
+    #[cfg(feature = "openrtb_26")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub gdpr: Option<i32>,
+
+    /// Communicates signals regarding consumer privacy under US privacy regulation under
+    /// CCPA and LSPA (OpenRTB 2.6+).
+    #[cfg(feature = "openrtb_26")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub us_privacy: Option<String>,
+
+    /// Contains the Global Privacy Platform's consent string (OpenRTB 2.6+).
+    #[cfg(feature = "openrtb_26")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub gpp: Option<String>,
+
+    /// Array of the section IDs of the GPP string which should be applied for this
+    /// transaction (OpenRTB 2.6+). Generally it will contain one and only one value.
+    #[cfg(feature = "openrtb_26")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub gpp_sid: Option<Vec<i32>>,
+
     /// Extension object for exchange-specific extensions.
     ///
-    /// Common extensions include:
+    /// Common OpenRTB 2.5 extensions include:
     /// - `gdpr`: Flag indicating if GDPR regulations apply (0=no, 1=yes)
     /// - `us_privacy`: US Privacy String per IAB CCPA Compliance Framework
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -185,4 +217,39 @@ mod tests {
         assert_eq!(regs.coppa, deserialized.coppa);
         assert_eq!(regs.ext, deserialized.ext);
     }
+
+    // === Feature Flag Tests (openrtb_26) ===
+
+    #[cfg(feature = "openrtb_26")]
+    #[test]
+    fn test_regs_privacy_fields() {
+        // Spec: OpenRTB 2.6 Section 3.2.3 — gdpr, us_privacy, gpp and gpp_sid
+        let json = r#"{"coppa":0,"gdpr":1,"us_privacy":"1YNN","gpp":"DBABMA~CPXxRfAPXxRfAAfKABENB-CgAAAAAAAAAAYgAAAAAAAA","gpp_sid":[2]}"#;
+        let regs: Regs = serde_json::from_str(json).unwrap();
+
+        assert_eq!(regs.gdpr, Some(1));
+        assert_eq!(regs.us_privacy.as_deref(), Some("1YNN"));
+        assert!(regs.gpp.as_deref().unwrap().starts_with("DBABMA~"));
+        assert_eq!(regs.gpp_sid, Some(vec![2]));
+        assert_eq!(serde_json::to_string(&regs).unwrap(), json);
+    }
+
+    #[cfg(feature = "openrtb_26")]
+    #[test]
+    fn test_regs_privacy_fields_omitted() {
+        let regs = Regs::builder().coppa(Some(1)).build().unwrap();
+
+        assert_eq!(regs.gdpr, None);
+        assert_eq!(regs.gpp_sid, None);
+        assert_eq!(serde_json::to_string(&regs).unwrap(), r#"{"coppa":1}"#);
+    }
+
+    #[cfg(not(feature = "openrtb_26"))]
+    #[test]
+    fn test_regs_privacy_fields_ignored_without_feature() {
+        // Without openrtb_26 feature, the 2.6 privacy fields are not part of Regs
+        let regs: Regs = serde_json::from_str(r#"{"coppa":1,"gdpr":1,"gpp_sid":[2]}"#).unwrap();
+
+        assert_eq!(serde_json::to_string(&regs).unwrap(), r#"{"coppa":1}"#);
+    }
 }
@@ -3,6 +3,7 @@
 /// This module contains the BidRequest object for OpenRTB 2.5 and 2.6.
 /// OpenRTB 2.6 adds support for the dooh field.
 use derive_builder::Builder;
+use iab_specs_adcom::enums::AuctionType;
 use serde::{Deserialize, Serialize};
 
 use super::app::App;
@@ -14,6 +15,7 @@ use super::source::Source;
 use super::user::User;
 
 // Import Dooh from AdCOM when openrtb_26 feature is enabled
+use crate::Code;
 use crate::Extension;
 #[cfg(feature = "openrtb_26")]
 use iab_specs_adcom::context::Dooh;
@@ -207,6 +209,13 @@ impl BidRequest {
     }
 }
 
+impl<Ext: Extension> BidRequest<Ext> {
+    /// Auction type, `at` decoded as [`AuctionType`].
+    pub fn auction_type(&self) -> Code<AuctionType> {
+        Code::from(self.at)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1053,4 +1062,20 @@ mod tests {
         assert_eq!(deserialized.badv, request.badv);
         assert_eq!(deserialized.bapp, request.bapp);
     }
+
+    #[test]
+    fn test_bid_request_auction_type() {
+        // Spec: Section 3.2.1 — at, exchange-specific auction types above 500
+        let request: BidRequest = serde_json::from_str(r#"{"id":"r1","imp":[],"at":1}"#).unwrap();
+        assert_eq!(request.auction_type(), Code::Known(AuctionType::FirstPrice));
+
+        let request: BidRequest = serde_json::from_str(r#"{"id":"r2","imp":[]}"#).unwrap();
+        assert_eq!(
+            request.auction_type(),
+            Code::Known(AuctionType::SecondPricePlus)
+        );
+
+        let request: BidRequest = serde_json::from_str(r#"{"id":"r3","imp":[],"at":501}"#).unwrap();
+        assert_eq!(request.auction_type(), Code::Unknown(501));
+    }
 }
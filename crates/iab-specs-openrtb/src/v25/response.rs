@@ -1,9 +1,11 @@
 use super::SeatBid;
+use crate::Code;
 use crate::Extension;
 /// OpenRTB 2.5 Response Objects
 ///
 /// This module contains the BidResponse and BidRequest objects for OpenRTB 2.5.
 use derive_builder::Builder;
+use iab_specs_adcom::enums::NoBidReason;
 use serde::{Deserialize, Serialize};
 
 /// Default currency for bid responses (USD per OpenRTB 2.5 spec)
@@ -103,6 +105,13 @@ impl BidResponse {
     }
 }
 
+impl<Ext: Extension> BidResponse<Ext> {
+    /// Reason for not bidding, `nbr` decoded as [`NoBidReason`].
+    pub fn no_bid_reason(&self) -> Option<Code<NoBidReason>> {
+        self.nbr.map(Code::from)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -426,4 +435,16 @@ mod tests {
         assert!(deser_empty.seatbid.is_some());
         assert_eq!(deser_empty.seatbid.unwrap().len(), 0);
     }
+
+    #[test]
+    fn test_bid_response_no_bid_reason() {
+        let response: BidResponse = serde_json::from_str(r#"{"id":"r1","nbr":3}"#).unwrap();
+        assert_eq!(
+            response.no_bid_reason(),
+            Some(Code::Known(NoBidReason::KnownSpider))
+        );
+
+        let response: BidResponse = serde_json::from_str(r#"{"id":"r2","nbr":600}"#).unwrap();
+        assert_eq!(response.no_bid_reason(), Some(Code::Unknown(600)));
+    }
 }
@@ -1,4 +1,6 @@
 use super::Data;
+#[cfg(feature = "openrtb_26")]
+use super::Eid;
 use super::geo::Geo;
 use crate::Extension;
 /// OpenRTB 2.5 User Object
@@ -93,6 +95,13 @@ pub struct User<Ext: Extension = crate::DefaultExt> {
     #[builder(default)]
     pub data: Option<Vec<Data<Ext>>>,
 
+    /// Array of extended identifiers from identity providers and ID systems
+    /// (OpenRTB 2.6+).
+    #[cfg(feature = "openrtb_26")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub eids: Option<Vec<Eid<Ext>>>,
+
     /// Consent string as defined by the Transparency & Consent Framework.
     /// OpenRTB 2.6+ field for GDPR compliance.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -103,9 +112,12 @@ pub struct User<Ext: Extension = crate::DefaultExt> {
     /// - 0 = GDPR does not apply
     /// - 1 = GDPR applies
     ///
-    /// OpenRTB 2.6+ field (added retroactively to 2.5 via ext in practice).
+    /// Not part of the User object in any OpenRTB version, the specification carries this
+    /// flag in `Regs.gdpr` (OpenRTB 2.6+) or `Regs.ext.gdpr`.
+    #[deprecated(note = "Use Regs::gdpr")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default)]
+    #[builder_setter_attr(deprecated(note = "Use Regs::gdpr"))]
     pub gdpr: Option<i32>,
 
     /// Extension object for exchange-specific extensions.
@@ -215,6 +227,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_user_with_gdpr() {
         let user = User::builder()
             .id(Some("user123".to_string()))
@@ -304,6 +317,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_user_roundtrip_all_fields() {
         // Spec: Section 3.2.20
         let geo = Geo::builder()
@@ -346,4 +360,23 @@ mod tests {
         assert_eq!(user.consent, deserialized.consent);
         assert_eq!(user.gdpr, deserialized.gdpr);
     }
+
+    // === Feature Flag Tests (openrtb_26) ===
+
+    #[cfg(feature = "openrtb_26")]
+    #[test]
+    fn test_user_with_eids() {
+        // Spec: OpenRTB 2.6 Section 3.2.27 — EID and UID objects
+        use crate::Code;
+        use iab_specs_adcom::enums::IDMatchMethod;
+
+        let json = r#"{"id":"user-eids","eids":[{"source":"liveramp.com","uids":[{"id":"XY1000bIVBVah9ium","atype":3}],"inserter":"ssp.com","matcher":"liveramp.com","mm":3}]}"#;
+        let user: User = serde_json::from_str(json).unwrap();
+
+        let eid = &user.eids.as_ref().unwrap()[0];
+        assert_eq!(eid.source.as_deref(), Some("liveramp.com"));
+        assert_eq!(eid.mm, Some(Code::Known(IDMatchMethod::Authenticated)));
+        assert_eq!(eid.uids.as_ref().unwrap()[0].atype, Some(3));
+        assert_eq!(serde_json::to_string(&user).unwrap(), json);
+    }
 }
@@ -1,10 +1,15 @@
 use super::banner::Banner;
+use crate::Code;
 use crate::Extension;
 /// OpenRTB 2.5/2.6 Video Ad Object
 ///
 /// This module implements the Video object for OpenRTB 2.5 and 2.6.
 /// OpenRTB 2.6 fields (podid, podseq, slotinpod, durfloors) are included.
 use derive_builder::Builder;
+use iab_specs_adcom::enums::{
+    AdPosition, ApiFramework, ContentDeliveryMethod, CreativeAttribute, PlaybackCessationMode,
+    PlaybackMethod, Protocol, SlotPosition, StartDelay, VideoLinearity,
+};
 use serde::{Deserialize, Serialize};
 
 // Import DurFloors from v26 when openrtb_26 feature is enabled
@@ -261,6 +266,58 @@ impl Video {
     }
 }
 
+impl<Ext: Extension> Video<Ext> {
+    /// Supported video protocols, `protocols` decoded as [`Protocol`].
+    pub fn supported_protocols(&self) -> Option<Vec<Code<Protocol>>> {
+        self.protocols.as_deref().map(Code::from_codes)
+    }
+
+    /// Start delay of the placement, `startdelay` decoded as [`StartDelay`].
+    pub fn start_delay(&self) -> Option<StartDelay> {
+        self.startdelay.map(StartDelay)
+    }
+
+    /// Required linearity of the impression, `linearity` decoded as [`VideoLinearity`].
+    pub fn video_linearity(&self) -> Option<Code<VideoLinearity>> {
+        self.linearity.map(Code::from)
+    }
+
+    /// Guaranteed position of the slot in the pod, `slotinpod` decoded as [`SlotPosition`].
+    pub fn slot_position(&self) -> Code<SlotPosition> {
+        Code::from(self.slotinpod)
+    }
+
+    /// Blocked creative attributes, `battr` decoded as [`CreativeAttribute`].
+    pub fn creative_attributes(&self) -> Option<Vec<Code<CreativeAttribute>>> {
+        self.battr.as_deref().map(Code::from_codes)
+    }
+
+    /// Playback methods that may be in use, `playbackmethod` decoded as [`PlaybackMethod`].
+    pub fn playback_methods(&self) -> Option<Vec<Code<PlaybackMethod>>> {
+        self.playbackmethod.as_deref().map(Code::from_codes)
+    }
+
+    /// Event that causes playback to end, `playbackend` decoded as [`PlaybackCessationMode`].
+    pub fn playback_cessation_mode(&self) -> Option<Code<PlaybackCessationMode>> {
+        self.playbackend.map(Code::from)
+    }
+
+    /// Supported delivery methods, `delivery` decoded as [`ContentDeliveryMethod`].
+    pub fn delivery_methods(&self) -> Option<Vec<Code<ContentDeliveryMethod>>> {
+        self.delivery.as_deref().map(Code::from_codes)
+    }
+
+    /// Ad position on screen, `pos` decoded as [`AdPosition`].
+    pub fn ad_position(&self) -> Option<Code<AdPosition>> {
+        self.pos.map(Code::from)
+    }
+
+    /// Supported API frameworks, `api` decoded as [`ApiFramework`].
+    pub fn api_frameworks(&self) -> Option<Vec<Code<ApiFramework>>> {
+        self.api.as_deref().map(Code::from_codes)
+    }
+}
+
 impl<Ext: Extension> Default for Video<Ext> {
     fn default() -> Self {
         Self {
@@ -865,4 +922,54 @@ mod tests {
         assert_eq!(video.poddedupe, deserialized.poddedupe);
         assert_eq!(video, deserialized);
     }
+
+    #[test]
+    fn test_video_typed_codes() {
+        // Spec: Section 3.2.7 — list values decoded, unknown codes preserved
+        let json = r#"{
+            "mimes": ["video/mp4"],
+            "protocols": [2, 7, 99],
+            "startdelay": -1,
+            "linearity": 1,
+            "slotinpod": -1,
+            "playbackmethod": [2],
+            "playbackend": 1,
+            "delivery": [1],
+            "api": [7]
+        }"#;
+        let video: Video = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            video.supported_protocols(),
+            Some(vec![
+                Code::Known(Protocol::Vast2),
+                Code::Known(Protocol::Vast4),
+                Code::Unknown(99)
+            ])
+        );
+        assert_eq!(video.start_delay(), Some(StartDelay::GENERIC_MID_ROLL));
+        assert_eq!(
+            video.video_linearity(),
+            Some(Code::Known(VideoLinearity::Linear))
+        );
+        assert_eq!(video.slot_position(), Code::Known(SlotPosition::Last));
+        assert_eq!(
+            video.playback_methods(),
+            Some(vec![Code::Known(PlaybackMethod::AutoPlaySoundOff)])
+        );
+        assert_eq!(
+            video.playback_cessation_mode(),
+            Some(Code::Known(PlaybackCessationMode::OnCompletion))
+        );
+        assert_eq!(
+            video.delivery_methods(),
+            Some(vec![Code::Known(ContentDeliveryMethod::Streaming)])
+        );
+        assert_eq!(
+            video.api_frameworks(),
+            Some(vec![Code::Known(ApiFramework::Omid1)])
+        );
+        assert_eq!(video.creative_attributes(), None);
+        assert_eq!(video.ad_position(), None);
+    }
 }
@@ -0,0 +1,141 @@
+use super::legacy_privacy::take_ext;
+use crate::v25::{BidRequest, Eid, User};
+/// OpenRTB 2.6 Legacy Extended Identifiers Normalisation
+///
+/// Before OpenRTB 2.6, extended identifiers were exchanged in `user.ext.eids` following the
+/// Prebid convention. This module moves them to the typed `eids` field of the user.
+use serde::Deserialize;
+use serde_json::Value;
+
+impl User<Value> {
+    /// Moves the legacy `ext.eids` extension to `eids`.
+    ///
+    /// Identifiers already in `eids` take precedence, the legacy array is removed from `ext`
+    /// either way. An array that is not a valid list of [`Eid`] objects is left in `ext`, and
+    /// `ext` is removed once empty.
+    ///
+    /// ```
+    /// use iab_specs_openrtb::v25::User;
+    /// use serde_json::Value;
+    ///
+    /// let mut user: User<Value> = serde_json::from_str(
+    ///     r#"{"ext":{"eids":[{"source":"adserver.org","uids":[{"id":"tdid-1","atype":1}]}]}}"#,
+    /// )
+    /// .unwrap();
+    /// user.normalize_legacy_eids();
+    /// assert_eq!(user.eids.unwrap()[0].source.as_deref(), Some("adserver.org"));
+    /// assert_eq!(user.ext, None);
+    /// ```
+    pub fn normalize_legacy_eids(&mut self) {
+        if let Some(eids) = take_ext(&mut self.ext, "eids", as_eids) {
+            self.eids.get_or_insert(eids);
+        }
+    }
+}
+
+impl BidRequest<Value> {
+    /// Moves the legacy `user.ext.eids` extension to `user.eids`, see
+    /// [`User::normalize_legacy_eids`].
+    pub fn normalize_legacy_eids(&mut self) {
+        if let Some(user) = &mut self.user {
+            user.normalize_legacy_eids();
+        }
+    }
+}
+
+fn as_eids(value: &Value) -> Option<Vec<Eid<Value>>> {
+    Vec::<Eid<Value>>::deserialize(value).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Code;
+    use iab_specs_adcom::enums::IDMatchMethod;
+
+    #[test]
+    fn test_user_lifts_legacy_eids() {
+        // Spec: OpenRTB 2.6 Section 3.2.20 — eids moved out of ext
+        let mut user: User<Value> = serde_json::from_str(
+            r#"{"id":"u1","ext":{"eids":[{"source":"id5-sync.com","uids":[{"id":"ID5-abc","atype":1}],"mm":2}],"consent":"CO"}}"#,
+        )
+        .unwrap();
+        user.normalize_legacy_eids();
+
+        let eids = user.eids.as_ref().unwrap();
+        assert_eq!(eids.len(), 1);
+        assert_eq!(eids[0].mm, Some(Code::Known(IDMatchMethod::CookieSync)));
+        assert_eq!(
+            eids[0].uids.as_ref().unwrap()[0].id.as_deref(),
+            Some("ID5-abc")
+        );
+        assert_eq!(
+            user.ext.as_deref(),
+            Some(&serde_json::json!({"consent": "CO"}))
+        );
+    }
+
+    #[test]
+    fn test_user_typed_eids_take_precedence() {
+        let mut user: User<Value> = serde_json::from_str(
+            r#"{"eids":[{"source":"typed.com"}],"ext":{"eids":[{"source":"legacy.com"}]}}"#,
+        )
+        .unwrap();
+        user.normalize_legacy_eids();
+
+        let eids = user.eids.as_ref().unwrap();
+        assert_eq!(eids.len(), 1);
+        assert_eq!(eids[0].source.as_deref(), Some("typed.com"));
+        assert_eq!(user.ext, None);
+    }
+
+    #[test]
+    fn test_user_lifts_prebid_uid_extensions() {
+        let mut user: User<Value> = serde_json::from_str(
+            r#"{"ext":{"eids":[{"source":"pubcid.org","uids":[{"id":"abc","atype":1,"ext":{"stype":"ppuid"}}],"mm":501}]}}"#,
+        )
+        .unwrap();
+        user.normalize_legacy_eids();
+
+        let eid = &user.eids.as_ref().unwrap()[0];
+        assert_eq!(eid.mm, Some(Code::Unknown(501)));
+        assert_eq!(
+            eid.uids.as_ref().unwrap()[0].ext.as_deref(),
+            Some(&serde_json::json!({"stype": "ppuid"}))
+        );
+        assert_eq!(user.ext, None);
+    }
+
+    #[test]
+    fn test_user_keeps_invalid_legacy_eids() {
+        let mut user: User<Value> =
+            serde_json::from_str(r#"{"ext":{"eids":[{"source":"a.com","mm":"9"}]}}"#).unwrap();
+        user.normalize_legacy_eids();
+
+        assert_eq!(user.eids, None);
+        assert_eq!(
+            user.ext.as_deref(),
+            Some(&serde_json::json!({"eids": [{"source": "a.com", "mm": "9"}]}))
+        );
+    }
+
+    #[test]
+    fn test_bid_request_lifts_legacy_eids() {
+        let json = r#"{
+            "id": "req-1",
+            "imp": [{"id": "1"}],
+            "user": {"ext": {"eids": [{"source": "adserver.org", "uids": [{"id": "tdid-1"}]}]}}
+        }"#;
+        let mut request: BidRequest<Value> = serde_json::from_str(json).unwrap();
+        request.normalize_legacy_eids();
+
+        let user = request.user.as_ref().unwrap();
+        assert_eq!(user.eids.as_ref().unwrap().len(), 1);
+        assert_eq!(user.ext, None);
+
+        let mut request: BidRequest<Value> =
+            serde_json::from_str(r#"{"id":"req-2","imp":[]}"#).unwrap();
+        request.normalize_legacy_eids();
+        assert!(request.user.is_none());
+    }
+}
@@ -0,0 +1,158 @@
+use crate::v25::{BidRequest, Regs, User};
+/// OpenRTB 2.6 Legacy Privacy Normalisation
+///
+/// OpenRTB 2.5 carried privacy signals in extensions, as recommended by the IAB GDPR and
+/// CCPA guidance: `regs.ext.gdpr`, `regs.ext.us_privacy` and `user.ext.consent`. OpenRTB
+/// 2.6 promotes them to first-class fields. This module moves the legacy locations into the
+/// typed fields, for requests whose extensions are decoded as JSON.
+use serde_json::Value;
+
+impl Regs<Value> {
+    /// Moves the legacy `ext.gdpr` and `ext.us_privacy` extensions to `gdpr` and `us_privacy`.
+    ///
+    /// Fields already set take precedence, the legacy values are removed from `ext` either way.
+    /// Values of an unexpected JSON type are left in `ext`, and `ext` is removed once empty.
+    ///
+    /// ```
+    /// use iab_specs_openrtb::v25::Regs;
+    /// use serde_json::Value;
+    ///
+    /// let mut regs: Regs<Value> =
+    ///     serde_json::from_str(r#"{"ext":{"gdpr":1,"us_privacy":"1YNN"}}"#).unwrap();
+    /// regs.normalize_legacy_privacy();
+    /// assert_eq!(regs.gdpr, Some(1));
+    /// assert_eq!(regs.us_privacy.as_deref(), Some("1YNN"));
+    /// assert_eq!(regs.ext, None);
+    /// ```
+    pub fn normalize_legacy_privacy(&mut self) {
+        if let Some(gdpr) = take_ext(&mut self.ext, "gdpr", as_i32) {
+            self.gdpr.get_or_insert(gdpr);
+        }
+        if let Some(us_privacy) = take_ext(&mut self.ext, "us_privacy", as_string) {
+            self.us_privacy.get_or_insert(us_privacy);
+        }
+    }
+}
+
+impl User<Value> {
+    /// Moves the legacy `ext.consent` extension to `consent`, as
+    /// [`Regs::normalize_legacy_privacy`] does.
+    pub fn normalize_legacy_privacy(&mut self) {
+        if let Some(consent) = take_ext(&mut self.ext, "consent", as_string) {
+            self.consent.get_or_insert(consent);
+        }
+    }
+}
+
+impl BidRequest<Value> {
+    /// Moves the legacy privacy extensions of `regs` and `user` to their typed fields, see
+    /// [`Regs::normalize_legacy_privacy`] and [`User::normalize_legacy_privacy`].
+    pub fn normalize_legacy_privacy(&mut self) {
+        if let Some(regs) = &mut self.regs {
+            regs.normalize_legacy_privacy();
+        }
+        if let Some(user) = &mut self.user {
+            user.normalize_legacy_privacy();
+        }
+    }
+}
+
+/// Removes `key` from the `ext` object if `convert` accepts its value, removing `ext` once
+/// empty.
+pub(super) fn take_ext<T>(
+    ext: &mut Option<Box<Value>>,
+    key: &str,
+    convert: impl Fn(&Value) -> Option<T>,
+) -> Option<T> {
+    let object = ext.as_deref_mut()?.as_object_mut()?;
+    let value = convert(object.get(key)?)?;
+    object.remove(key);
+    if object.is_empty() {
+        *ext = None;
+    }
+    Some(value)
+}
+
+fn as_i32(value: &Value) -> Option<i32> {
+    value.as_i64().and_then(|value| i32::try_from(value).ok())
+}
+
+fn as_string(value: &Value) -> Option<String> {
+    value.as_str().map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regs_lifts_legacy_extensions() {
+        // Spec: OpenRTB 2.6 Section 3.2.3 — gdpr and us_privacy moved out of ext
+        let mut regs: Regs<Value> = serde_json::from_str(
+            r#"{"coppa":0,"ext":{"gdpr":1,"us_privacy":"1YNN","exchange":"value"}}"#,
+        )
+        .unwrap();
+        regs.normalize_legacy_privacy();
+
+        assert_eq!(regs.gdpr, Some(1));
+        assert_eq!(regs.us_privacy.as_deref(), Some("1YNN"));
+        assert_eq!(
+            serde_json::to_string(&regs).unwrap(),
+            r#"{"coppa":0,"gdpr":1,"us_privacy":"1YNN","ext":{"exchange":"value"}}"#
+        );
+    }
+
+    #[test]
+    fn test_regs_typed_fields_take_precedence() {
+        let mut regs: Regs<Value> =
+            serde_json::from_str(r#"{"gdpr":0,"ext":{"gdpr":1,"us_privacy":"1YNN"}}"#).unwrap();
+        regs.normalize_legacy_privacy();
+
+        assert_eq!(regs.gdpr, Some(0));
+        assert_eq!(regs.us_privacy.as_deref(), Some("1YNN"));
+        assert_eq!(regs.ext, None);
+    }
+
+    #[test]
+    fn test_regs_keeps_unexpected_values() {
+        let mut regs: Regs<Value> =
+            serde_json::from_str(r#"{"ext":{"gdpr":"yes","us_privacy":1}}"#).unwrap();
+        regs.normalize_legacy_privacy();
+
+        assert_eq!(regs.gdpr, None);
+        assert_eq!(regs.us_privacy, None);
+        assert_eq!(
+            regs.ext.as_deref(),
+            Some(&serde_json::json!({"gdpr": "yes", "us_privacy": 1}))
+        );
+
+        let mut regs: Regs<Value> = serde_json::from_str(r#"{"ext":[1]}"#).unwrap();
+        regs.normalize_legacy_privacy();
+        assert_eq!(regs.ext.as_deref(), Some(&serde_json::json!([1])));
+    }
+
+    #[test]
+    fn test_bid_request_lifts_both_forms() {
+        // Partners sending the 2.5 extensions and the 2.6 fields in the same request
+        let json = r#"{
+            "id": "req-1",
+            "imp": [{"id": "1"}],
+            "regs": {"ext": {"gdpr": 1}},
+            "user": {"id": "u1", "consent": "CO-typed", "ext": {"consent": "CO-legacy"}}
+        }"#;
+        let mut request: BidRequest<Value> = serde_json::from_str(json).unwrap();
+        request.normalize_legacy_privacy();
+
+        let regs = request.regs.as_ref().unwrap();
+        assert_eq!(regs.gdpr, Some(1));
+        assert_eq!(regs.ext, None);
+        let user = request.user.as_ref().unwrap();
+        assert_eq!(user.consent.as_deref(), Some("CO-typed"));
+        assert_eq!(user.ext, None);
+
+        let mut request: BidRequest<Value> =
+            serde_json::from_str(r#"{"id":"req-2","imp":[]}"#).unwrap();
+        request.normalize_legacy_privacy();
+        assert!(request.regs.is_none() && request.user.is_none());
+    }
+}
@@ -72,6 +72,20 @@
 /// - [`RefSettings`] - Refresh interval and type settings
 /// - [`DurFloors`] - Duration-based floor pricing for video/audio
 ///
+/// ## Privacy Fields
+///
+/// The GDPR, US Privacy and GPP signals are first-class fields of [`Regs`](crate::v25::Regs)
+/// (`gdpr`, `us_privacy`, `gpp`, `gpp_sid`). Requests still using the OpenRTB 2.5
+/// extensions can be normalised with `normalize_legacy_privacy` on
+/// [`BidRequest`](crate::v25::BidRequest), `Regs` and `User` when their extensions are
+/// decoded as `serde_json::Value`.
+///
+/// ## Extended Identifiers
+///
+/// [`User`](crate::v25::User) carries the `eids` array of [`Eid`](crate::v25::Eid) objects.
+/// Identifiers still sent in `user.ext.eids` can be moved there with
+/// `normalize_legacy_eids` on [`BidRequest`](crate::v25::BidRequest) and `User`.
+///
 /// ## AdCOM Integration
 ///
 /// OpenRTB 2.6 uses AdCOM (Advertising Common Object Model) for common domain objects.
@@ -91,6 +105,8 @@
 /// <https://github.com/InteractiveAdvertisingBureau/openrtb2.x/blob/main/2.6.md>
 // OpenRTB 2.6 specific objects
 mod durfloors;
+mod legacy_eids;
+mod legacy_privacy;
 mod qty;
 mod ref_settings;
 mod refresh;
@@ -66,7 +66,7 @@ mod tests {
 
     #[test]
     fn test_every_valid_trust_transition() {
-        for (from, to) in VALID_TRUST_TRANSITIONS.iter().copied() {
+        for &(from, to) in VALID_TRUST_TRANSITIONS {
             assert!(
                 can_transition_trust(&from, &to),
                 "expected transition {:?} -> {:?} to be valid",
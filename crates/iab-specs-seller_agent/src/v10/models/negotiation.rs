@@ -220,7 +220,7 @@ mod tests {
         assert_eq!(round.buyer_price, 4.50);
         assert_eq!(round.seller_price, 5.00);
         assert_eq!(round.concession, 0.50);
-        assert_eq!(round.accepted, false);
+        assert!(!round.accepted);
         assert!(round.ext.is_none());
     }
 
@@ -241,7 +241,7 @@ mod tests {
         assert_eq!(parsed.buyer_price, 3.75);
         assert_eq!(parsed.seller_price, 4.25);
         assert_eq!(parsed.concession, 0.25);
-        assert_eq!(parsed.accepted, false);
+        assert!(!parsed.accepted);
     }
 
     #[test]
@@ -255,7 +255,7 @@ mod tests {
             .build()
             .unwrap();
 
-        assert_eq!(round.accepted, true);
+        assert!(round.accepted);
     }
 
     #[test]
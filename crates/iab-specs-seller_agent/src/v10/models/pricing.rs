@@ -240,7 +240,7 @@ mod tests {
 
         assert_eq!(tier.tier_type, PricingTierType::Agency);
         assert_eq!(tier.discount_percent, 10.0);
-        assert_eq!(tier.negotiation_enabled, true);
+        assert!(tier.negotiation_enabled);
         assert_eq!(tier.min_spend, Some(5000.0));
         assert!(tier.ext.is_none());
     }
@@ -251,7 +251,7 @@ mod tests {
 
         assert_eq!(tier.tier_type, PricingTierType::Public);
         assert_eq!(tier.discount_percent, 0.0);
-        assert_eq!(tier.negotiation_enabled, false);
+        assert!(!tier.negotiation_enabled);
         assert!(tier.min_spend.is_none());
         assert!(tier.ext.is_none());
     }
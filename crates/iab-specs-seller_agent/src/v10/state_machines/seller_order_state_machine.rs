@@ -92,7 +92,7 @@ mod tests {
 
     #[test]
     fn test_every_valid_seller_order_transition_returns_true() {
-        for (from, to) in VALID_SELLER_ORDER_TRANSITIONS.iter().copied() {
+        for &(from, to) in VALID_SELLER_ORDER_TRANSITIONS {
             assert!(
                 can_transition_seller_order(&from, &to),
                 "expected transition {:?} -> {:?} to be valid",
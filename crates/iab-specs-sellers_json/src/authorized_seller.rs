@@ -0,0 +1,453 @@
+use crate::{Extension, Seller, SellerType, SellersIndex};
+use iab_specs_ads_txt::{AdsTxt, AdsTxtSystem, DomainCanonicalizer, SellerRelationType};
+use isosphere::CountryCode;
+use serde::{Deserialize, Serialize};
+
+/// The outcome of checking one ads.txt record against the sellers.json file of its
+/// advertising system.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AuthorizedSellerVerdict {
+    /// The seller exists and its type matches the relationship of the record.
+    ///
+    /// `domain_verified` tells whether the seller domain was compared with the OWNERDOMAIN and
+    /// MANAGERDOMAIN of the file. It is not for RESELLER records, confidential sellers,
+    /// sellers without domain and files declaring neither variable.
+    Authorized {
+        seller_type: SellerType,
+        domain_verified: bool,
+    },
+
+    /// No sellers.json file of the advertising system is indexed.
+    UnknownAdvertisingSystem,
+
+    /// The sellers.json file of the advertising system has no seller with the publisher ID
+    /// of the record.
+    SellerNotFound,
+
+    /// A DIRECT record points to an INTERMEDIARY seller, or a RESELLER record to a PUBLISHER.
+    SellerTypeMismatch {
+        relation: SellerRelationType,
+        seller_type: SellerType,
+    },
+
+    /// The domain of the seller of a DIRECT record is neither the OWNERDOMAIN nor an
+    /// applicable MANAGERDOMAIN of the file.
+    DomainMismatch {
+        seller_domain: String,
+        expected: Vec<String>,
+    },
+}
+
+impl AuthorizedSellerVerdict {
+    /// Whether the record is authorized.
+    pub fn is_authorized(&self) -> bool {
+        matches!(self, AuthorizedSellerVerdict::Authorized { .. })
+    }
+}
+
+/// An ads.txt record with its verdict.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AuthorizedSellerCheck {
+    /// The checked ads.txt record.
+    pub record: AdsTxtSystem,
+
+    /// The outcome of the check.
+    pub verdict: AuthorizedSellerVerdict,
+}
+
+/// The verdicts of every record of an ads.txt file, in file order.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AuthorizedSellerReport {
+    /// One check per record of the file.
+    pub checks: Vec<AuthorizedSellerCheck>,
+}
+
+impl AuthorizedSellerReport {
+    /// Whether every record is authorized.
+    pub fn is_authorized(&self) -> bool {
+        self.checks
+            .iter()
+            .all(|check| check.verdict.is_authorized())
+    }
+
+    /// The records that are not authorized.
+    pub fn unauthorized(&self) -> impl Iterator<Item = &AuthorizedSellerCheck> {
+        self.checks
+            .iter()
+            .filter(|check| !check.verdict.is_authorized())
+    }
+
+    /// Number of authorized records.
+    pub fn authorized_count(&self) -> usize {
+        self.checks
+            .iter()
+            .filter(|check| check.verdict.is_authorized())
+            .count()
+    }
+}
+
+/// Checks ads.txt records against the sellers.json files of their advertising systems, the
+/// "authorized seller" check of supply path transparency.
+///
+/// For each record, the seller `publisher_id` must exist in the sellers.json file of the
+/// advertising system `domain`. DIRECT records must point to PUBLISHER or BOTH sellers and
+/// RESELLER records to INTERMEDIARY or BOTH sellers. The seller of a DIRECT record must
+/// disclose the OWNERDOMAIN of the file, or its MANAGERDOMAIN for the country of the traffic.
+///
+/// Domains are compared through a [`DomainCanonicalizer`]. Advertising systems are looked up
+/// by their canonical domain, so the index should be built with canonical domains. Publisher
+/// IDs, which ads.txt parsing lowercases, are matched with seller IDs ignoring case.
+///
+/// ```
+/// use iab_specs_ads_txt::AdsTxt;
+/// use iab_specs_sellers_json::{AuthorizedSellerChecker, Sellers, SellersIndex};
+/// use std::str::FromStr;
+///
+/// let sellers = Sellers::from_str(
+///     r#"{"version":"1.0","identifiers":[],"sellers":[{"seller_id":"pub-1","seller_type":"PUBLISHER","name":"Pub","domain":"pub.com"}]}"#,
+/// )
+/// .unwrap();
+/// let index = SellersIndex::new([("ssp.com", sellers)]);
+/// let ads_txt = AdsTxt::from_str("ownerdomain=pub.com\nssp.com, pub-1, DIRECT").unwrap();
+/// assert!(AuthorizedSellerChecker::new(&index).check(&ads_txt).is_authorized());
+/// ```
+pub struct AuthorizedSellerChecker<'a, Ext: Extension = serde_json::Value> {
+    index: &'a SellersIndex<Ext>,
+    canonicalizer: DomainCanonicalizer,
+    country_code: Option<CountryCode>,
+}
+
+impl<'a, Ext: Extension> AuthorizedSellerChecker<'a, Ext> {
+    /// Creates a checker looking sellers up in `index`, accepting the MANAGERDOMAIN of any
+    /// country.
+    pub fn new(index: &'a SellersIndex<Ext>) -> Self {
+        AuthorizedSellerChecker {
+            index,
+            canonicalizer: DomainCanonicalizer::new(),
+            country_code: None,
+        }
+    }
+
+    /// Uses `canonicalizer` to compare domains.
+    pub fn with_canonicalizer(mut self, canonicalizer: DomainCanonicalizer) -> Self {
+        self.canonicalizer = canonicalizer;
+        self
+    }
+
+    /// Only accepts the MANAGERDOMAIN of `country_code`, or the global one when the file has
+    /// none for this country.
+    pub fn with_country_code(mut self, country_code: CountryCode) -> Self {
+        self.country_code = Some(country_code);
+        self
+    }
+
+    /// The index sellers are looked up in.
+    #[cfg(feature = "openrtb")]
+    pub(crate) fn index(&self) -> &'a SellersIndex<Ext> {
+        self.index
+    }
+
+    /// Checks every record of `ads_txt`.
+    pub fn check(&self, ads_txt: &AdsTxt) -> AuthorizedSellerReport {
+        let expected = self.expected_domains(ads_txt);
+        AuthorizedSellerReport {
+            checks: ads_txt
+                .systems
+                .iter()
+                .map(|record| AuthorizedSellerCheck {
+                    record: record.clone(),
+                    verdict: self.check_record(record, &expected),
+                })
+                .collect(),
+        }
+    }
+
+    /// Checks `record`, whose seller must disclose one of the `expected` canonical domains
+    /// when it is a DIRECT record.
+    fn check_record(&self, record: &AdsTxtSystem, expected: &[String]) -> AuthorizedSellerVerdict {
+        let advertising_system = self.canonicalize(&record.domain);
+        if !self.index.contains_advertising_system(&advertising_system) {
+            return AuthorizedSellerVerdict::UnknownAdvertisingSystem;
+        }
+        let Some(seller) = self
+            .index
+            .get_ignore_case(&advertising_system, &record.publisher_id)
+        else {
+            return AuthorizedSellerVerdict::SellerNotFound;
+        };
+        let type_matches = match record.relation {
+            SellerRelationType::Direct => seller.seller_type != SellerType::Intermediary,
+            SellerRelationType::Reseller => seller.seller_type != SellerType::Publisher,
+        };
+        if !type_matches {
+            return AuthorizedSellerVerdict::SellerTypeMismatch {
+                relation: record.relation,
+                seller_type: seller.seller_type.clone(),
+            };
+        }
+        match self.seller_domain(record.relation, seller, expected) {
+            Some(domain) if !expected.contains(&domain) => {
+                AuthorizedSellerVerdict::DomainMismatch {
+                    seller_domain: domain,
+                    expected: expected.to_vec(),
+                }
+            }
+            seller_domain => AuthorizedSellerVerdict::Authorized {
+                seller_type: seller.seller_type.clone(),
+                domain_verified: seller_domain.is_some(),
+            },
+        }
+    }
+
+    /// The canonical domain of the seller, if it has to be verified.
+    pub(crate) fn seller_domain(
+        &self,
+        relation: SellerRelationType,
+        seller: &Seller<Ext>,
+        expected: &[String],
+    ) -> Option<String> {
+        if relation != SellerRelationType::Direct || seller.is_confidential || expected.is_empty() {
+            return None;
+        }
+        seller
+            .domain
+            .as_deref()
+            .map(|domain| self.canonicalize(domain))
+    }
+
+    /// The canonical OWNERDOMAIN followed by the canonical MANAGERDOMAIN applicable to the
+    /// country of the checker.
+    pub(crate) fn expected_domains(&self, ads_txt: &AdsTxt) -> Vec<String> {
+        let local = ads_txt.manager_domains.iter().any(|manager| {
+            manager.country_code.is_some() && manager.country_code == self.country_code
+        });
+        let managers = ads_txt.manager_domains.iter().filter(|manager| {
+            match (self.country_code, manager.country_code) {
+                (None, _) => true,
+                (Some(_), None) => !local,
+                (Some(country_code), Some(manager_country_code)) => {
+                    country_code == manager_country_code
+                }
+            }
+        });
+
+        let mut expected: Vec<String> = vec![];
+        let domains = ads_txt
+            .owner_domain
+            .iter()
+            .chain(managers.map(|manager| &manager.domain));
+        for domain in domains {
+            let domain = self.canonicalize(domain);
+            if !expected.contains(&domain) {
+                expected.push(domain);
+            }
+        }
+        expected
+    }
+
+    /// The canonical form of `domain`, or its lowercase form if it is not a valid domain name.
+    pub(crate) fn canonicalize(&self, domain: &str) -> String {
+        self.canonicalizer
+            .canonicalize(domain)
+            .unwrap_or_else(|_| domain.trim().to_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Sellers;
+    use std::str::FromStr;
+
+    fn index() -> SellersIndex {
+        let sellers = Sellers::from_str(
+            r#"{"version":"1.0","identifiers":[],"sellers":[
+                {"seller_id":"Pub-1","seller_type":"PUBLISHER","name":"Pub","domain":"pub.com"},
+                {"seller_id":"net-1","seller_type":"INTERMEDIARY","name":"Net","domain":"net.com"},
+                {"seller_id":"both-1","seller_type":"BOTH","name":"Manager","domain":"www.manager.com"},
+                {"seller_id":"both-2","seller_type":"BOTH","name":"Local","domain":"local.fr"},
+                {"seller_id":"conf-1","seller_type":"PUBLISHER","is_confidential":1}
+            ]}"#,
+        )
+        .unwrap();
+        SellersIndex::new([("ssp.com", sellers)])
+    }
+
+    fn verdicts(checker: &AuthorizedSellerChecker, content: &str) -> Vec<AuthorizedSellerVerdict> {
+        checker
+            .check(&AdsTxt::from_str(content).unwrap())
+            .checks
+            .into_iter()
+            .map(|check| check.verdict)
+            .collect()
+    }
+
+    #[test]
+    fn authorized_records() {
+        let index = index();
+        let checker = AuthorizedSellerChecker::new(&index);
+        let content = "ownerdomain=pub.com\nmanagerdomain=manager.com\nssp.com, pub-1, DIRECT\nwww.SSP.com, net-1, RESELLER\nssp.com, both-1, DIRECT\nssp.com, both-1, RESELLER\nssp.com, conf-1, DIRECT";
+        assert_eq!(
+            verdicts(&checker, content),
+            vec![
+                AuthorizedSellerVerdict::Authorized {
+                    seller_type: SellerType::Publisher,
+                    domain_verified: true
+                },
+                AuthorizedSellerVerdict::Authorized {
+                    seller_type: SellerType::Intermediary,
+                    domain_verified: false
+                },
+                AuthorizedSellerVerdict::Authorized {
+                    seller_type: SellerType::Both,
+                    domain_verified: true
+                },
+                AuthorizedSellerVerdict::Authorized {
+                    seller_type: SellerType::Both,
+                    domain_verified: false
+                },
+                AuthorizedSellerVerdict::Authorized {
+                    seller_type: SellerType::Publisher,
+                    domain_verified: false
+                },
+            ]
+        );
+        let report = checker.check(&AdsTxt::from_str(content).unwrap());
+        assert!(report.is_authorized());
+        assert_eq!(report.authorized_count(), 5);
+    }
+
+    #[test]
+    fn unknown_advertising_system_and_seller() {
+        let index = index();
+        let checker = AuthorizedSellerChecker::new(&index);
+        let report = checker
+            .check(&AdsTxt::from_str("other.com, pub-1, DIRECT\nssp.com, pub-2, DIRECT").unwrap());
+        assert!(!report.is_authorized());
+        assert_eq!(report.authorized_count(), 0);
+        let verdicts: Vec<_> = report.unauthorized().map(|check| &check.verdict).collect();
+        assert_eq!(
+            verdicts,
+            vec![
+                &AuthorizedSellerVerdict::UnknownAdvertisingSystem,
+                &AuthorizedSellerVerdict::SellerNotFound
+            ]
+        );
+    }
+
+    #[test]
+    fn seller_type_mismatch() {
+        let index = index();
+        let checker = AuthorizedSellerChecker::new(&index);
+        assert_eq!(
+            verdicts(&checker, "ssp.com, net-1, DIRECT\nssp.com, pub-1, RESELLER"),
+            vec![
+                AuthorizedSellerVerdict::SellerTypeMismatch {
+                    relation: SellerRelationType::Direct,
+                    seller_type: SellerType::Intermediary
+                },
+                AuthorizedSellerVerdict::SellerTypeMismatch {
+                    relation: SellerRelationType::Reseller,
+                    seller_type: SellerType::Publisher
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn domain_mismatch() {
+        let index = index();
+        let checker = AuthorizedSellerChecker::new(&index);
+        assert_eq!(
+            verdicts(&checker, "ownerdomain=other.com\nssp.com, pub-1, DIRECT"),
+            vec![AuthorizedSellerVerdict::DomainMismatch {
+                seller_domain: "pub.com".to_string(),
+                expected: vec!["other.com".to_string()]
+            }]
+        );
+
+        // Without OWNERDOMAIN nor MANAGERDOMAIN, the domain cannot be verified.
+        assert_eq!(
+            verdicts(&checker, "ssp.com, pub-1, DIRECT"),
+            vec![AuthorizedSellerVerdict::Authorized {
+                seller_type: SellerType::Publisher,
+                domain_verified: false
+            }]
+        );
+    }
+
+    #[test]
+    fn manager_domain_by_country() {
+        let index = index();
+        let content = "ownerdomain=pub.com\nmanagerdomain=manager.com\nmanagerdomain=local.fr, FR\nssp.com, both-1, DIRECT\nssp.com, both-2, DIRECT";
+        let authorized = AuthorizedSellerVerdict::Authorized {
+            seller_type: SellerType::Both,
+            domain_verified: true,
+        };
+
+        let checker = AuthorizedSellerChecker::new(&index);
+        assert_eq!(
+            verdicts(&checker, content),
+            vec![authorized.clone(), authorized.clone()]
+        );
+
+        // The French MANAGERDOMAIN overrides the global one in France.
+        let checker = AuthorizedSellerChecker::new(&index)
+            .with_country_code(CountryCode::from_str("FR").unwrap());
+        assert_eq!(
+            verdicts(&checker, content),
+            vec![
+                AuthorizedSellerVerdict::DomainMismatch {
+                    seller_domain: "manager.com".to_string(),
+                    expected: vec!["pub.com".to_string(), "local.fr".to_string()]
+                },
+                authorized.clone()
+            ]
+        );
+
+        let checker = AuthorizedSellerChecker::new(&index)
+            .with_country_code(CountryCode::from_str("DE").unwrap());
+        assert_eq!(
+            verdicts(&checker, content),
+            vec![
+                authorized,
+                AuthorizedSellerVerdict::DomainMismatch {
+                    seller_domain: "local.fr".to_string(),
+                    expected: vec!["pub.com".to_string(), "manager.com".to_string()]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn domain_aliases() {
+        let index = index();
+        let checker = AuthorizedSellerChecker::new(&index).with_canonicalizer(
+            DomainCanonicalizer::new()
+                .with_alias("pub-alias.com", "pub.com")
+                .unwrap(),
+        );
+        assert_eq!(
+            verdicts(
+                &checker,
+                "ownerdomain=pub-alias.com\nssp.com, pub-1, DIRECT"
+            ),
+            vec![AuthorizedSellerVerdict::Authorized {
+                seller_type: SellerType::Publisher,
+                domain_verified: true
+            }]
+        );
+    }
+
+    #[test]
+    fn report_serializes_with_verdict_tag() {
+        let index = index();
+        let report = AuthorizedSellerChecker::new(&index)
+            .check(&AdsTxt::from_str("ssp.com, pub-2, DIRECT").unwrap());
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"{"checks":[{"record":"ssp.com,pub-2,direct","verdict":{"type":"seller_not_found"}}]}"#
+        );
+    }
+}
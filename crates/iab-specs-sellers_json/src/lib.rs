@@ -1,15 +1,31 @@
 pub use iab_specs_core::{DefaultExt, Error, Extension, Result};
 
+#[cfg(feature = "ads_txt")]
+mod authorized_seller;
 mod seller;
 mod seller_type;
 mod sellers;
+mod sellers_diff;
 mod sellers_identifier;
 mod sellers_identifier_name;
+mod sellers_index;
+mod sellers_reader;
+mod sellers_validation;
 mod sellers_version;
+#[cfg(feature = "openrtb")]
+mod supply_chain_validation;
 
+#[cfg(feature = "ads_txt")]
+pub use authorized_seller::*;
 pub use seller::*;
 pub use seller_type::*;
 pub use sellers::*;
+pub use sellers_diff::*;
 pub use sellers_identifier::*;
 pub use sellers_identifier_name::*;
+pub use sellers_index::*;
+pub use sellers_reader::*;
+pub use sellers_validation::*;
 pub use sellers_version::*;
+#[cfg(feature = "openrtb")]
+pub use supply_chain_validation::*;
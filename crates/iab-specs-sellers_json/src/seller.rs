@@ -1,9 +1,10 @@
-use crate::SellerType;
+use crate::{Extension, SellerType};
 use derive_builder::Builder;
 use serde::de::{Error, MapAccess, Unexpected, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_with::serde_as;
 use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
 use std::str::FromStr;
 
 /// The identification of the selling legal entity that is paid for inventory sold on behalf
@@ -11,11 +12,16 @@ use std::str::FromStr;
 /// Every seller_id must map to only a single entity that is paid for inventory transacted with
 /// that seller_id. It is valid for a selling entity to have multiple seller_ids
 /// within an advertising system.
+///
+/// # Generic Parameters
+///
+/// * `Ext` - Extension object type (must implement [`Extension`]). Defaults to
+///   [`serde_json::Value`].
 #[serde_as]
-#[derive(Builder, Serialize, Clone, Debug)]
-#[serde(rename_all = "snake_case")]
+#[derive(Builder, Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case", bound(serialize = "Ext: Extension"))]
 #[builder(build_fn(error = "crate::Error"))]
-pub struct Seller {
+pub struct Seller<Ext: Extension = serde_json::Value> {
     /// This is the same ID that appears in an ads.txt file and in the SupplyChain Nodes array sid
     /// property. In most cases will also appear in the Publisher Id property of an OpenRTB request.
     #[builder(setter(into))]
@@ -77,7 +83,7 @@ pub struct Seller {
     /// Placeholder for advertising-system specific extensions to this object.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ext: Option<String>,
+    pub ext: Option<Box<Ext>>,
 }
 
 impl Seller {
@@ -87,7 +93,7 @@ impl Seller {
     }
 }
 
-impl Display for Seller {
+impl<Ext: Extension> Display for Seller<Ext> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match serde_json::to_string(&self) {
             Ok(v) => write!(f, "{}", v),
@@ -104,7 +110,7 @@ impl FromStr for Seller {
     }
 }
 
-impl<'de> Deserialize<'de> for Seller {
+impl<'de, Ext: Extension> Deserialize<'de> for Seller<Ext> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
@@ -131,10 +137,10 @@ impl<'de> Deserialize<'de> for Seller {
             "comment",
             "ext",
         ];
-        struct SellerVisitor;
+        struct SellerVisitor<Ext>(PhantomData<Ext>);
 
-        impl<'de> Visitor<'de> for SellerVisitor {
-            type Value = Seller;
+        impl<'de, Ext: Extension> Visitor<'de> for SellerVisitor<Ext> {
+            type Value = Seller<Ext>;
 
             fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
                 formatter.write_str(
@@ -227,7 +233,7 @@ impl<'de> Deserialize<'de> for Seller {
                     return Err(Error::missing_field("name"));
                 }
 
-                Seller::builder()
+                SellerBuilder::<Ext>::create_empty()
                     .seller_id(seller_id)
                     .is_confidential(is_confidential)
                     .seller_type(seller_type)
@@ -240,7 +246,7 @@ impl<'de> Deserialize<'de> for Seller {
                     .map_err(|e| Error::custom(e.to_string()))
             }
         }
-        deserializer.deserialize_struct("Seller", FIELDS, SellerVisitor)
+        deserializer.deserialize_struct("Seller", FIELDS, SellerVisitor(PhantomData))
     }
 }
 
@@ -388,7 +394,9 @@ mod tests {
             .name(Some("ssp".to_string()))
             .domain(Some("example.com".to_string()))
             .comment(Some("test comment".to_string()))
-            .ext(Some("extension data".to_string()))
+            .ext(Some(Box::new(
+                serde_json::json!({"data": "extension data"}),
+            )))
             .build()
             .unwrap();
         let res = serde_json::to_string(&v);
@@ -399,7 +407,7 @@ mod tests {
         assert_eq!(json_value["name"], "ssp");
         assert_eq!(json_value["domain"], "example.com");
         assert_eq!(json_value["comment"], "test comment");
-        assert_eq!(json_value["ext"], "extension data");
+        assert_eq!(json_value["ext"]["data"], "extension data");
     }
 
     #[test]
@@ -580,7 +588,7 @@ mod tests {
             .name(Some("RT Company".to_string()))
             .domain(Some("rt.example.com".to_string()))
             .comment(Some("round trip test".to_string()))
-            .ext(Some("ext-rt".to_string()))
+            .ext(Some(Box::new(serde_json::json!({"rt": 1}))))
             .build()
             .unwrap();
         let json_str = serde_json::to_string(&original).unwrap();
@@ -695,7 +703,7 @@ mod tests {
  "name": "All Fields Corp",
  "domain": "allfields.com",
  "comment": "test all fields",
- "ext": "extension data"
+ "ext": {"data": "extension data"}
  }"#,
         );
         assert!(res.is_ok());
@@ -707,6 +715,24 @@ mod tests {
         assert_eq!(seller.name, Some("All Fields Corp".to_string()));
         assert_eq!(seller.domain, Some("allfields.com".to_string()));
         assert_eq!(seller.comment, Some("test all fields".to_string()));
-        assert_eq!(seller.ext, Some("extension data".to_string()));
+        assert_eq!(seller.ext.unwrap()["data"], "extension data");
+    }
+
+    #[test]
+    fn deserialize_with_typed_ext() {
+        // Spec: Section 2.3 — ext is an object
+        #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+        struct NetworkExt {
+            network_id: u32,
+        }
+
+        let json =
+            r#"{"seller_id":"1","seller_type":"PUBLISHER","name":"Pub","ext":{"network_id":7}}"#;
+        let seller: Seller<NetworkExt> = serde_json::from_str(json).unwrap();
+        assert_eq!(seller.ext.as_deref(), Some(&NetworkExt { network_id: 7 }));
+        assert_eq!(
+            seller.to_string(),
+            r#"{"seller_id":"1","seller_type":"publisher","name":"Pub","ext":{"network_id":7}}"#
+        );
     }
 }
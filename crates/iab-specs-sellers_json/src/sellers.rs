@@ -1,4 +1,4 @@
-use crate::{Seller, SellersIdentifier, SellersVersion};
+use crate::{Extension, Seller, SellersIdentifier, SellersVersion};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
@@ -8,10 +8,18 @@ use std::str::FromStr;
 // https://iabtechlab.com/wp-content/uploads/2019/07/Sellers.json_Final.pdf
 
 /// It is a container for all properties in a sellers.json file
+///
+/// # Generic Parameters
+///
+/// * `Ext` - Extension object type of the file and of its sellers (must implement
+///   [`Extension`]). Defaults to [`serde_json::Value`], as sellers.json files are JSON.
 #[derive(Builder, Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "snake_case")]
+#[serde(
+    rename_all = "snake_case",
+    bound(serialize = "Ext: Extension", deserialize = "Ext: Extension")
+)]
 #[builder(build_fn(error = "crate::Error"))]
-pub struct Sellers {
+pub struct Sellers<Ext: Extension = serde_json::Value> {
     /// The business address of the advertising system.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -29,7 +37,7 @@ pub struct Sellers {
     /// Placeholder for advertising-system specific extensions to this object.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ext: Option<String>,
+    pub ext: Option<Box<Ext>>,
 
     /// Array of Identifier objects associated with this advertising system.
     /// Examples could be Tag-Ids, Dun & Bradstreet business identifiers, or
@@ -42,7 +50,7 @@ pub struct Sellers {
     /// system. All sellers must be included even if they are confidential.
     #[builder(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub sellers: Vec<Seller>,
+    pub sellers: Vec<Seller<Ext>>,
 }
 
 impl Sellers {
@@ -52,7 +60,7 @@ impl Sellers {
     }
 }
 
-impl Display for Sellers {
+impl<Ext: Extension> Display for Sellers<Ext> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match serde_json::to_string(&self) {
             Ok(v) => write!(f, "{}", v),
@@ -144,7 +152,7 @@ mod tests {
             .contact_email(Some("test@example.com".to_string()))
             .contact_address(Some("123 Main St".to_string()))
             .version(SellersVersion::OneZero)
-            .ext(Some("ext-data".to_string()))
+            .ext(Some(Box::new(serde_json::json!({"network": "ext-data"}))))
             .identifiers(vec![
                 SellersIdentifier::builder()
                     .name(SellersIdentifierName::TagId)
@@ -223,4 +231,15 @@ mod tests {
         assert_eq!(sellers.identifiers[0].name, SellersIdentifierName::TagId);
         assert_eq!(sellers.identifiers[1].name, SellersIdentifierName::Duns);
     }
+
+    #[test]
+    fn deserialize_with_object_ext() {
+        // Spec: Section 2.1 — ext is an object on the file and on each seller
+        let sellers = Sellers::from_str(
+            r#"{"version":"1.0","ext":{"network":"example"},"identifiers":[],"sellers":[{"seller_id":"1","seller_type":"PUBLISHER","name":"Pub","ext":{"tier":2}}]}"#,
+        )
+        .unwrap();
+        assert_eq!(sellers.ext.unwrap()["network"], "example");
+        assert_eq!(sellers.sellers[0].ext.as_ref().unwrap()["tier"], 2);
+    }
 }
@@ -0,0 +1,379 @@
+use crate::{Extension, Seller, SellerType, Sellers};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// A single difference between two [`Sellers`] snapshots.
+///
+/// Sellers are identified by their `seller_id`, so that a modified seller is reported as one
+/// or more changes rather than as a removal followed by an addition. Comments and extensions
+/// are ignored.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(
+    tag = "type",
+    rename_all = "snake_case",
+    bound(serialize = "Ext: Extension", deserialize = "Ext: Extension")
+)]
+pub enum SellersChange<Ext: Extension = serde_json::Value> {
+    /// A seller only present in the newer snapshot.
+    SellerAdded { seller: Seller<Ext> },
+
+    /// A seller only present in the older snapshot.
+    SellerRemoved { seller: Seller<Ext> },
+
+    /// A seller was reclassified, for instance from PUBLISHER to INTERMEDIARY.
+    SellerTypeChanged {
+        seller_id: String,
+        from: SellerType,
+        to: SellerType,
+    },
+
+    /// A seller became confidential or stopped being confidential.
+    ConfidentialityChanged {
+        seller_id: String,
+        from: bool,
+        to: bool,
+    },
+
+    /// The passthrough flag of a seller flipped.
+    PassthroughChanged {
+        seller_id: String,
+        from: bool,
+        to: bool,
+    },
+
+    /// The name of a seller changed, was added or was removed.
+    NameChanged {
+        seller_id: String,
+        from: Option<String>,
+        to: Option<String>,
+    },
+
+    /// The domain of a seller changed, was added or was removed.
+    DomainChanged {
+        seller_id: String,
+        from: Option<String>,
+        to: Option<String>,
+    },
+
+    /// The contact email of the advertising system changed, was added or was removed.
+    ContactEmailChanged {
+        from: Option<String>,
+        to: Option<String>,
+    },
+
+    /// The contact address of the advertising system changed, was added or was removed.
+    ContactAddressChanged {
+        from: Option<String>,
+        to: Option<String>,
+    },
+}
+
+/// Number of changes of each kind in a [`SellersDiff`].
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SellersDiffSummary {
+    /// Number of added sellers.
+    pub added: usize,
+
+    /// Number of removed sellers.
+    pub removed: usize,
+
+    /// Number of reclassified sellers.
+    pub seller_type_changed: usize,
+
+    /// Number of confidentiality flips.
+    pub confidentiality_changed: usize,
+
+    /// Number of passthrough flips.
+    pub passthrough_changed: usize,
+
+    /// Number of name changes.
+    pub name_changed: usize,
+
+    /// Number of domain changes.
+    pub domain_changed: usize,
+
+    /// Number of contact email and contact address changes.
+    pub contact_changed: usize,
+}
+
+/// The list of differences between two [`Sellers`] snapshots, as returned by
+/// [`Sellers::diff`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(bound(serialize = "Ext: Extension", deserialize = "Ext: Extension"))]
+pub struct SellersDiff<Ext: Extension = serde_json::Value> {
+    /// Changes, contacts first, then added and modified sellers in the order of the newer
+    /// snapshot, then removed sellers in the order of the older snapshot.
+    pub changes: Vec<SellersChange<Ext>>,
+}
+
+impl<Ext: Extension> SellersDiff<Ext> {
+    /// Returns `true` if both snapshots are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Counts the changes of each kind.
+    pub fn summary(&self) -> SellersDiffSummary {
+        let mut summary = SellersDiffSummary::default();
+        for change in &self.changes {
+            let count = match change {
+                SellersChange::SellerAdded { .. } => &mut summary.added,
+                SellersChange::SellerRemoved { .. } => &mut summary.removed,
+                SellersChange::SellerTypeChanged { .. } => &mut summary.seller_type_changed,
+                SellersChange::ConfidentialityChanged { .. } => {
+                    &mut summary.confidentiality_changed
+                }
+                SellersChange::PassthroughChanged { .. } => &mut summary.passthrough_changed,
+                SellersChange::NameChanged { .. } => &mut summary.name_changed,
+                SellersChange::DomainChanged { .. } => &mut summary.domain_changed,
+                SellersChange::ContactEmailChanged { .. }
+                | SellersChange::ContactAddressChanged { .. } => &mut summary.contact_changed,
+            };
+            *count += 1;
+        }
+        summary
+    }
+}
+
+impl<Ext: Extension> Sellers<Ext> {
+    /// Computes the changes needed to go from `self` to `newer`.
+    ///
+    /// When a `seller_id` is declared several times in a snapshot, only its first declaration
+    /// is compared.
+    pub fn diff(&self, newer: &Sellers<Ext>) -> SellersDiff<Ext> {
+        let mut changes = vec![];
+        if self.contact_email != newer.contact_email {
+            changes.push(SellersChange::ContactEmailChanged {
+                from: self.contact_email.clone(),
+                to: newer.contact_email.clone(),
+            });
+        }
+        if self.contact_address != newer.contact_address {
+            changes.push(SellersChange::ContactAddressChanged {
+                from: self.contact_address.clone(),
+                to: newer.contact_address.clone(),
+            });
+        }
+
+        let older = first_by_seller_id(&self.sellers);
+        let mut seen = HashSet::new();
+        for new in &newer.sellers {
+            if !seen.insert(new.seller_id.as_str()) {
+                continue;
+            }
+            match older.get(new.seller_id.as_str()) {
+                Some(old) => diff_seller(old, new, &mut changes),
+                None => changes.push(SellersChange::SellerAdded {
+                    seller: new.clone(),
+                }),
+            }
+        }
+        let newer = first_by_seller_id(&newer.sellers);
+        let mut seen = HashSet::new();
+        for old in &self.sellers {
+            if seen.insert(old.seller_id.as_str()) && !newer.contains_key(old.seller_id.as_str()) {
+                changes.push(SellersChange::SellerRemoved {
+                    seller: old.clone(),
+                });
+            }
+        }
+
+        SellersDiff { changes }
+    }
+}
+
+fn first_by_seller_id<Ext: Extension>(sellers: &[Seller<Ext>]) -> HashMap<&str, &Seller<Ext>> {
+    let mut by_seller_id = HashMap::with_capacity(sellers.len());
+    for seller in sellers {
+        by_seller_id
+            .entry(seller.seller_id.as_str())
+            .or_insert(seller);
+    }
+    by_seller_id
+}
+
+fn diff_seller<Ext: Extension>(
+    old: &Seller<Ext>,
+    new: &Seller<Ext>,
+    changes: &mut Vec<SellersChange<Ext>>,
+) {
+    let seller_id = || new.seller_id.clone();
+    if old.seller_type != new.seller_type {
+        changes.push(SellersChange::SellerTypeChanged {
+            seller_id: seller_id(),
+            from: old.seller_type.clone(),
+            to: new.seller_type.clone(),
+        });
+    }
+    if old.is_confidential != new.is_confidential {
+        changes.push(SellersChange::ConfidentialityChanged {
+            seller_id: seller_id(),
+            from: old.is_confidential,
+            to: new.is_confidential,
+        });
+    }
+    if old.is_passthrough != new.is_passthrough {
+        changes.push(SellersChange::PassthroughChanged {
+            seller_id: seller_id(),
+            from: old.is_passthrough,
+            to: new.is_passthrough,
+        });
+    }
+    if old.name != new.name {
+        changes.push(SellersChange::NameChanged {
+            seller_id: seller_id(),
+            from: old.name.clone(),
+            to: new.name.clone(),
+        });
+    }
+    let same_domain = match (&old.domain, &new.domain) {
+        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+        (a, b) => a == b,
+    };
+    if !same_domain {
+        changes.push(SellersChange::DomainChanged {
+            seller_id: seller_id(),
+            from: old.domain.clone(),
+            to: new.domain.clone(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn sellers(contact_email: &str, sellers: &str) -> Sellers {
+        Sellers::from_str(&format!(
+            r#"{{"version":"1.0","contact_email":"{}","identifiers":[],"sellers":[{}]}}"#,
+            contact_email, sellers
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn diff_identical_snapshots_is_empty() {
+        let older = sellers(
+            "adops@ssp.com",
+            r#"{"seller_id":"1","seller_type":"PUBLISHER","name":"Pub","domain":"pub.com","comment":"one"}"#,
+        );
+        let newer = sellers(
+            "adops@ssp.com",
+            r#"{"seller_id":"1","seller_type":"PUBLISHER","name":"Pub","domain":"PUB.com","comment":"two"}"#,
+        );
+        let diff = older.diff(&newer);
+        assert!(diff.is_empty());
+        assert_eq!(diff.summary(), SellersDiffSummary::default());
+    }
+
+    #[test]
+    fn diff_sellers() {
+        let older = sellers(
+            "adops@ssp.com",
+            r#"{"seller_id":"1","seller_type":"PUBLISHER","name":"Pub","domain":"pub.com"},
+            {"seller_id":"2","seller_type":"INTERMEDIARY","name":"Net","domain":"net.com"},
+            {"seller_id":"3","seller_type":"BOTH","name":"Gone"}"#,
+        );
+        let newer = sellers(
+            "ops@ssp.com",
+            r#"{"seller_id":"4","seller_type":"PUBLISHER","name":"New"},
+            {"seller_id":"1","seller_type":"INTERMEDIARY","name":"Pub","domain":"other.com"},
+            {"seller_id":"2","seller_type":"INTERMEDIARY","is_confidential":1,"is_passthrough":1}"#,
+        );
+        let diff = older.diff(&newer);
+        let types: Vec<&str> = diff
+            .changes
+            .iter()
+            .map(|change| match change {
+                SellersChange::SellerAdded { .. } => "added",
+                SellersChange::SellerRemoved { .. } => "removed",
+                SellersChange::SellerTypeChanged { .. } => "type",
+                SellersChange::ConfidentialityChanged { .. } => "confidentiality",
+                SellersChange::PassthroughChanged { .. } => "passthrough",
+                SellersChange::NameChanged { .. } => "name",
+                SellersChange::DomainChanged { .. } => "domain",
+                SellersChange::ContactEmailChanged { .. } => "contact_email",
+                SellersChange::ContactAddressChanged { .. } => "contact_address",
+            })
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                "contact_email",
+                "added",
+                "type",
+                "domain",
+                "confidentiality",
+                "passthrough",
+                "name",
+                "domain",
+                "removed"
+            ]
+        );
+        assert_eq!(
+            diff.changes[2],
+            SellersChange::SellerTypeChanged {
+                seller_id: "1".to_string(),
+                from: SellerType::Publisher,
+                to: SellerType::Intermediary,
+            }
+        );
+        assert_eq!(
+            diff.changes[3],
+            SellersChange::DomainChanged {
+                seller_id: "1".to_string(),
+                from: Some("pub.com".to_string()),
+                to: Some("other.com".to_string()),
+            }
+        );
+        assert_eq!(
+            diff.summary(),
+            SellersDiffSummary {
+                added: 1,
+                removed: 1,
+                seller_type_changed: 1,
+                confidentiality_changed: 1,
+                passthrough_changed: 1,
+                name_changed: 1,
+                domain_changed: 2,
+                contact_changed: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn diff_ignores_repeated_seller_ids() {
+        let older = sellers(
+            "adops@ssp.com",
+            r#"{"seller_id":"1","seller_type":"PUBLISHER","name":"Pub"},
+            {"seller_id":"1","seller_type":"BOTH","name":"Dup"}"#,
+        );
+        let newer = sellers(
+            "adops@ssp.com",
+            r#"{"seller_id":"1","seller_type":"PUBLISHER","name":"Pub"}"#,
+        );
+        assert!(older.diff(&newer).is_empty());
+        assert!(newer.diff(&older).is_empty());
+    }
+
+    #[test]
+    fn serialize_diff() {
+        let older = sellers("adops@ssp.com", "");
+        let newer = sellers(
+            "adops@ssp.com",
+            r#"{"seller_id":"1","seller_type":"PUBLISHER","name":"Pub"}"#,
+        );
+        let diff = older.diff(&newer);
+        let json = serde_json::to_string(&diff).unwrap();
+        assert_eq!(
+            json,
+            r#"{"changes":[{"type":"seller_added","seller":{"seller_id":"1","seller_type":"publisher","name":"Pub"}}]}"#
+        );
+        assert_eq!(serde_json::from_str::<SellersDiff>(&json).unwrap(), diff);
+        assert_eq!(
+            serde_json::to_string(&diff.summary()).unwrap(),
+            r#"{"added":1,"removed":0,"seller_type_changed":0,"confidentiality_changed":0,"passthrough_changed":0,"name_changed":0,"domain_changed":0,"contact_changed":0}"#
+        );
+    }
+}
@@ -51,8 +51,7 @@ mod tests {
         let res = serde_json::from_str::<SellersIdentifier>(r#"{"name":"tag-id"}"#);
         assert!(res.is_err());
 
-        let res =
-            serde_json::from_str::<SellersIdentifier>(r#"{"name":"tagid","value":"432432432"}"#);
+        let res = serde_json::from_str::<SellersIdentifier>(r#"{"name":"","value":"432432432"}"#);
         assert!(res.is_err());
 
         let res = serde_json::from_str::<SellersIdentifier>(r#"{"name":"tag-id","value":42}"#);
@@ -201,6 +200,16 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[test]
+    fn deserialize_with_custom_name() {
+        // Spec: Section 2.2 — custom identifiers are allowed
+        let res =
+            serde_json::from_str::<SellersIdentifier>(r#"{"name":"ein","value":"12-3456789"}"#)
+                .unwrap();
+        assert_eq!(res.name, SellersIdentifierName::Other("ein".to_string()));
+        assert_eq!(res.to_string(), r#"{"name":"ein","value":"12-3456789"}"#);
+    }
+
     #[test]
     fn builder_missing_required_name() {
         // Spec: Section 2.2
@@ -1,10 +1,11 @@
-use iab_specs_core::slice_up_to;
 use serde::de::Error;
 use serde_with::{DeserializeFromStr, SerializeDisplay};
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
 /// The following list defines standard identifiers that should be used in the identifier list.
+///
+/// The specification allows custom identifiers, kept as [`SellersIdentifierName::Other`].
 #[derive(Clone, DeserializeFromStr, SerializeDisplay, Debug, Ord, PartialOrd, Eq, PartialEq)]
 pub enum SellersIdentifierName {
     /// Trustworthy Accountability Group ID
@@ -12,6 +13,9 @@ pub enum SellersIdentifierName {
 
     /// Dun & Bradstreet DUNS Number
     Duns,
+
+    /// Any other identifier, such as `ein` or an advertising system specific one, as written.
+    Other(String),
 }
 
 impl Display for SellersIdentifierName {
@@ -19,6 +23,7 @@ impl Display for SellersIdentifierName {
         match self {
             SellersIdentifierName::TagId => write!(f, "tag-id"),
             SellersIdentifierName::Duns => write!(f, "duns"),
+            SellersIdentifierName::Other(name) => write!(f, "{}", name),
         }
     }
 }
@@ -31,11 +36,10 @@ impl FromStr for SellersIdentifierName {
             Ok(SellersIdentifierName::TagId)
         } else if content.eq_ignore_ascii_case("duns") {
             Ok(SellersIdentifierName::Duns)
+        } else if content.is_empty() {
+            Err(serde_plain::Error::missing_field("name").into())
         } else {
-            Err(
-                serde_plain::Error::unknown_field(slice_up_to!(content, 100), &["tag-id", "duns"])
-                    .into(),
-            )
+            Ok(SellersIdentifierName::Other(content.to_string()))
         }
     }
 }
@@ -46,12 +50,17 @@ mod tests {
     use std::str::FromStr;
 
     #[test]
-    fn deserialize_with_invalid_sellers_identifier_name_serde() {
+    fn deserialize_with_custom_sellers_identifier_name_serde() {
+        // Spec: Section 2.2 — custom identifiers are allowed
         let res = SellersIdentifierName::from_str("tagid");
-        assert!(res.is_err());
+        assert!(res.is_ok_and(|v| v == SellersIdentifierName::Other("tagid".to_string())));
 
-        let res = SellersIdentifierName::from_str("dun");
-        assert!(res.is_err());
+        let res = SellersIdentifierName::from_str("EIN");
+        assert!(res.is_ok_and(|v| v == SellersIdentifierName::Other("EIN".to_string())));
+
+        let res: SellersIdentifierName = serde_json::from_str(r#""ein""#).unwrap();
+        assert_eq!(res, SellersIdentifierName::Other("ein".to_string()));
+        assert_eq!(serde_json::to_string(&res).unwrap(), r#""ein""#);
     }
 
     #[test]
@@ -0,0 +1,389 @@
+use crate::{Extension, Seller, SellerType, Sellers};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// Statistics of the sellers of a [`SellersIndex`].
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SellersIndexStats {
+    /// Number of advertising systems whose sellers.json file is indexed.
+    pub advertising_systems: usize,
+
+    /// Number of indexed sellers.
+    pub sellers: usize,
+
+    /// Number of sellers whose `seller_id` was already declared by the same advertising
+    /// system. Only the first declaration is indexed.
+    pub duplicates: usize,
+
+    /// Number of confidential sellers.
+    pub confidential: usize,
+
+    /// Number of passthrough sellers.
+    pub passthrough: usize,
+
+    /// Number of PUBLISHER sellers.
+    pub publishers: usize,
+
+    /// Number of INTERMEDIARY sellers.
+    pub intermediaries: usize,
+
+    /// Number of BOTH sellers.
+    pub both: usize,
+}
+
+impl SellersIndexStats {
+    /// Share of confidential sellers, between 0 and 1. Zero when there are no sellers.
+    pub fn confidential_ratio(&self) -> f64 {
+        if self.sellers == 0 {
+            0.0
+        } else {
+            self.confidential as f64 / self.sellers as f64
+        }
+    }
+
+    /// Number of sellers of type `seller_type`.
+    pub fn count(&self, seller_type: &SellerType) -> usize {
+        match seller_type {
+            SellerType::Publisher => self.publishers,
+            SellerType::Intermediary => self.intermediaries,
+            SellerType::Both => self.both,
+        }
+    }
+}
+
+/// An immutable index of the sellers of one or more sellers.json files.
+///
+/// Sellers are looked up in constant time by the advertising system domain and `seller_id`
+/// pair found in SupplyChain nodes (`asi`, `sid`), or by seller domain. Domains are compared
+/// case-insensitively, seller IDs exactly unless looked up with
+/// [`SellersIndex::get_ignore_case`].
+///
+/// The index is meant to be built once per refresh and shared behind an [`Arc`], see
+/// [`SharedSellersIndex`].
+///
+/// ```
+/// use iab_specs_sellers_json::{Sellers, SellersIndex};
+/// use std::str::FromStr;
+///
+/// let sellers = Sellers::from_str(
+///     r#"{"version":"1.0","identifiers":[],"sellers":[{"seller_id":"pub-1","seller_type":"PUBLISHER","name":"Pub","domain":"pub.com"}]}"#,
+/// )
+/// .unwrap();
+/// let index = SellersIndex::new([("SSP.com", sellers)]);
+/// assert_eq!(index.get("ssp.com", "pub-1").unwrap().name.as_deref(), Some("Pub"));
+/// assert_eq!(index.by_domain("PUB.com").count(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SellersIndex<Ext: Extension = serde_json::Value> {
+    sellers: Vec<(String, Seller<Ext>)>,
+    by_seller_id: HashMap<String, SellerIds>,
+    by_domain: HashMap<String, Vec<usize>>,
+    stats: SellersIndexStats,
+}
+
+impl<Ext: Extension> SellersIndex<Ext> {
+    /// Indexes the sellers of each `(advertising system domain, file)` pair.
+    ///
+    /// Files of the same advertising system are merged.
+    pub fn new<'a>(files: impl IntoIterator<Item = (&'a str, Sellers<Ext>)>) -> Self {
+        let mut index = SellersIndex {
+            sellers: vec![],
+            by_seller_id: HashMap::new(),
+            by_domain: HashMap::new(),
+            stats: SellersIndexStats::default(),
+        };
+        for (advertising_system, file) in files {
+            let advertising_system = normalize_domain(advertising_system);
+            let seller_ids = index
+                .by_seller_id
+                .entry(advertising_system.clone())
+                .or_default();
+            for seller in file.sellers {
+                if seller_ids.exact.contains_key(&seller.seller_id) {
+                    index.stats.duplicates += 1;
+                    continue;
+                }
+                let position = index.sellers.len();
+                seller_ids.exact.insert(seller.seller_id.clone(), position);
+                seller_ids
+                    .folded
+                    .entry(seller.seller_id.to_lowercase())
+                    .or_insert(position);
+                if let Some(domain) = &seller.domain {
+                    index
+                        .by_domain
+                        .entry(normalize_domain(domain))
+                        .or_default()
+                        .push(position);
+                }
+                let stats = &mut index.stats;
+                stats.sellers += 1;
+                stats.confidential += usize::from(seller.is_confidential);
+                stats.passthrough += usize::from(seller.is_passthrough);
+                match seller.seller_type {
+                    SellerType::Publisher => stats.publishers += 1,
+                    SellerType::Intermediary => stats.intermediaries += 1,
+                    SellerType::Both => stats.both += 1,
+                }
+                index.sellers.push((advertising_system.clone(), seller));
+            }
+        }
+        index.stats.advertising_systems = index.by_seller_id.len();
+        index
+    }
+
+    /// The seller `seller_id` of `advertising_system`.
+    pub fn get(&self, advertising_system: &str, seller_id: &str) -> Option<&Seller<Ext>> {
+        let seller_ids = self.seller_ids(advertising_system)?;
+        let position = seller_ids.exact.get(seller_id)?;
+        Some(&self.sellers[*position].1)
+    }
+
+    /// The seller `seller_id` of `advertising_system`, ignoring the case of `seller_id`.
+    ///
+    /// An exact match is preferred, then the first seller matching regardless of case. Useful
+    /// to look up the publisher IDs of ads.txt records, which parsing lowercases.
+    pub fn get_ignore_case(
+        &self,
+        advertising_system: &str,
+        seller_id: &str,
+    ) -> Option<&Seller<Ext>> {
+        let seller_ids = self.seller_ids(advertising_system)?;
+        let position = match seller_ids.exact.get(seller_id) {
+            Some(position) => position,
+            None => seller_ids.folded.get(&seller_id.to_lowercase())?,
+        };
+        Some(&self.sellers[*position].1)
+    }
+
+    /// Whether the sellers.json file of `advertising_system` is indexed.
+    pub fn contains_advertising_system(&self, advertising_system: &str) -> bool {
+        self.seller_ids(advertising_system).is_some()
+    }
+
+    fn seller_ids(&self, advertising_system: &str) -> Option<&SellerIds> {
+        self.by_seller_id
+            .get(advertising_system)
+            .or_else(|| self.by_seller_id.get(&normalize_domain(advertising_system)))
+    }
+
+    /// The sellers declaring `domain`, with the domain of their advertising system.
+    pub fn by_domain(&self, domain: &str) -> impl Iterator<Item = (&str, &Seller<Ext>)> {
+        self.by_domain
+            .get(&normalize_domain(domain))
+            .into_iter()
+            .flatten()
+            .map(|&position| {
+                let (advertising_system, seller) = &self.sellers[position];
+                (advertising_system.as_str(), seller)
+            })
+    }
+
+    /// Iterates over every indexed seller, with the domain of its advertising system.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Seller<Ext>)> {
+        self.sellers
+            .iter()
+            .map(|(advertising_system, seller)| (advertising_system.as_str(), seller))
+    }
+
+    /// Number of indexed sellers.
+    pub fn len(&self) -> usize {
+        self.sellers.len()
+    }
+
+    /// Whether the index has no sellers.
+    pub fn is_empty(&self) -> bool {
+        self.sellers.is_empty()
+    }
+
+    /// Statistics of the indexed sellers.
+    pub fn stats(&self) -> &SellersIndexStats {
+        &self.stats
+    }
+}
+
+impl<Ext: Extension> Default for SellersIndex<Ext> {
+    fn default() -> Self {
+        SellersIndex::new([])
+    }
+}
+
+/// Positions of the sellers of one advertising system, by `seller_id`.
+#[derive(Clone, Debug, Default)]
+struct SellerIds {
+    exact: HashMap<String, usize>,
+    /// By lowercased `seller_id`, the first seller of each spelling.
+    folded: HashMap<String, usize>,
+}
+
+fn normalize_domain(domain: &str) -> String {
+    domain.trim().trim_end_matches('.').to_ascii_lowercase()
+}
+
+/// The current [`SellersIndex`] snapshot, shared between threads and replaced on refresh.
+///
+/// Readers take a cheap [`Arc`] of the snapshot with [`SharedSellersIndex::load`] and keep
+/// using it while a refresh stores a new one, so lookups never see a partially built index.
+#[derive(Debug, Default)]
+pub struct SharedSellersIndex<Ext: Extension = serde_json::Value> {
+    current: RwLock<Arc<SellersIndex<Ext>>>,
+}
+
+impl<Ext: Extension> SharedSellersIndex<Ext> {
+    /// Shares `index` as the current snapshot.
+    pub fn new(index: SellersIndex<Ext>) -> Self {
+        SharedSellersIndex {
+            current: RwLock::new(Arc::new(index)),
+        }
+    }
+
+    /// The current snapshot.
+    pub fn load(&self) -> Arc<SellersIndex<Ext>> {
+        self.current
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Replaces the current snapshot with `index`, returning the previous one.
+    pub fn store(&self, index: impl Into<Arc<SellersIndex<Ext>>>) -> Arc<SellersIndex<Ext>> {
+        let mut current = self.current.write().unwrap_or_else(PoisonError::into_inner);
+        std::mem::replace(&mut *current, index.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn sellers(sellers: &str) -> Sellers {
+        Sellers::from_str(&format!(
+            r#"{{"version":"1.0","identifiers":[],"sellers":[{}]}}"#,
+            sellers
+        ))
+        .unwrap()
+    }
+
+    fn index() -> SellersIndex {
+        SellersIndex::new([
+            (
+                "ssp.com",
+                sellers(
+                    r#"{"seller_id":"1","seller_type":"PUBLISHER","name":"Pub","domain":"Pub.com"},
+                    {"seller_id":"2","seller_type":"INTERMEDIARY","name":"Net","domain":"net.com","is_passthrough":1},
+                    {"seller_id":"3","seller_type":"BOTH","is_confidential":1},
+                    {"seller_id":"1","seller_type":"PUBLISHER","name":"Dup"}"#,
+                ),
+            ),
+            (
+                "exchange.com",
+                sellers(
+                    r#"{"seller_id":"1","seller_type":"PUBLISHER","name":"Pub","domain":"pub.com"}"#,
+                ),
+            ),
+        ])
+    }
+
+    #[test]
+    fn lookup_by_advertising_system_and_seller_id() {
+        let index = index();
+        assert_eq!(index.len(), 4);
+        assert_eq!(
+            index.get("ssp.com", "1").unwrap().name.as_deref(),
+            Some("Pub")
+        );
+        assert_eq!(
+            index.get("SSP.com", "2").unwrap().name.as_deref(),
+            Some("Net")
+        );
+        assert!(index.get("exchange.com", "1").is_some());
+        assert!(index.get("exchange.com", "2").is_none());
+        assert!(index.get("unknown.com", "1").is_none());
+    }
+
+    #[test]
+    fn lookup_ignoring_seller_id_case() {
+        let index = SellersIndex::new([(
+            "ssp.com",
+            sellers(
+                r#"{"seller_id":"Pub-A","seller_type":"PUBLISHER","name":"Pub","domain":"pub.com"},
+                {"seller_id":"pub-a","seller_type":"PUBLISHER","name":"Other","domain":"pub.com"}"#,
+            ),
+        )]);
+        assert_eq!(index.stats().duplicates, 0);
+        assert_eq!(
+            index.get("ssp.com", "pub-a").unwrap().name.as_deref(),
+            Some("Other")
+        );
+        assert!(index.get("ssp.com", "PUB-A").is_none());
+        assert_eq!(
+            index
+                .get_ignore_case("ssp.com", "pub-a")
+                .unwrap()
+                .name
+                .as_deref(),
+            Some("Other")
+        );
+        assert_eq!(
+            index
+                .get_ignore_case("ssp.com", "PUB-A")
+                .unwrap()
+                .name
+                .as_deref(),
+            Some("Pub")
+        );
+    }
+
+    #[test]
+    fn lookup_by_domain() {
+        let index = index();
+        let systems: Vec<&str> = index
+            .by_domain("pub.com")
+            .map(|(advertising_system, _)| advertising_system)
+            .collect();
+        assert_eq!(systems, vec!["ssp.com", "exchange.com"]);
+        assert_eq!(index.by_domain("unknown.com").count(), 0);
+    }
+
+    #[test]
+    fn compute_stats() {
+        let index = index();
+        let stats = index.stats();
+        assert_eq!(stats.advertising_systems, 2);
+        assert_eq!(stats.sellers, 4);
+        assert_eq!(stats.duplicates, 1);
+        assert_eq!(stats.confidential, 1);
+        assert_eq!(stats.passthrough, 1);
+        assert_eq!(stats.count(&SellerType::Publisher), 2);
+        assert_eq!(stats.count(&SellerType::Intermediary), 1);
+        assert_eq!(stats.count(&SellerType::Both), 1);
+        assert_eq!(stats.confidential_ratio(), 0.25);
+        assert_eq!(
+            SellersIndex::<serde_json::Value>::default()
+                .stats()
+                .confidential_ratio(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn swap_snapshots() {
+        let shared = Arc::new(SharedSellersIndex::new(SellersIndex::default()));
+        let before = shared.load();
+        assert!(before.is_empty());
+
+        let reader = {
+            let shared = shared.clone();
+            std::thread::spawn(move || shared.load().len())
+        };
+        reader.join().unwrap();
+
+        let previous = shared.store(index());
+        assert!(Arc::ptr_eq(&previous, &before));
+        assert_eq!(shared.load().len(), 4);
+        // Snapshots taken before the swap are unchanged.
+        assert!(before.is_empty());
+    }
+}
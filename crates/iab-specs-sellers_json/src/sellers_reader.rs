@@ -0,0 +1,446 @@
+use crate::{Extension, Seller, SellersIdentifier, SellersVersion};
+use serde::de::{DeserializeOwned, Error};
+use std::io::{BufRead, BufReader, Read};
+
+/// The top-level properties of a sellers.json file, read by [`SellersReader`].
+#[derive(Clone, Debug, Default)]
+pub struct SellersMetadata<Ext: Extension = serde_json::Value> {
+    /// The business address of the advertising system.
+    pub contact_address: Option<String>,
+
+    /// The contact email address of the advertising system.
+    pub contact_email: Option<String>,
+
+    /// The version of the specification, `None` if the file does not declare it.
+    pub version: Option<SellersVersion>,
+
+    /// Advertising-system specific extensions.
+    pub ext: Option<Box<Ext>>,
+
+    /// Identifiers associated with the advertising system.
+    pub identifiers: Vec<SellersIdentifier>,
+}
+
+/// Where the reader is in the file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum State {
+    /// Inside the `sellers` array, `first` until an element has been read.
+    Sellers { first: bool },
+    /// The whole object has been read.
+    Done,
+    /// The file is not valid JSON, no more sellers can be read.
+    Failed,
+}
+
+/// Reads a sellers.json file incrementally from a [`Read`].
+///
+/// Creating the reader reads the top-level properties up to the `sellers` array, then each
+/// iteration deserialises a single [`Seller`], so memory use is bounded by the largest seller
+/// rather than by the size of the file. A seller that cannot be deserialised is reported as an
+/// error and iteration continues with the next one, which includes a seller that is not valid
+/// JSON but whose strings and brackets are balanced. Any other malformed JSON, such as
+/// mismatched brackets or a missing separator between sellers, is reported as an error that
+/// ends the iteration.
+///
+/// Properties written after the `sellers` array are available once iteration completes, or
+/// from [`SellersReader::finish`].
+///
+/// ```
+/// use iab_specs_sellers_json::SellersReader;
+///
+/// let json = r#"{"version":"1.0","sellers":[
+///     {"seller_id":"1","seller_type":"PUBLISHER","name":"Pub"},
+///     {"seller_id":"2","seller_type":"INTERMEDIARY","name":"Net"}
+/// ],"contact_email":"adops@ssp.com"}"#;
+/// let mut reader = SellersReader::new(json.as_bytes()).unwrap();
+/// assert_eq!(reader.by_ref().filter(|seller| seller.is_ok()).count(), 2);
+/// let metadata = reader.finish().unwrap();
+/// assert_eq!(metadata.contact_email.as_deref(), Some("adops@ssp.com"));
+/// ```
+#[derive(Debug)]
+pub struct SellersReader<R: Read, Ext: Extension = serde_json::Value> {
+    reader: BufReader<R>,
+    offset: usize,
+    buffer: Vec<u8>,
+    state: State,
+    metadata: SellersMetadata<Ext>,
+}
+
+impl<R: Read> SellersReader<R> {
+    /// Creates a reader over `reader` and reads the properties preceding the `sellers` array.
+    pub fn new(reader: R) -> crate::Result<Self> {
+        SellersReader::open(reader)
+    }
+}
+
+impl<R: Read, Ext: Extension> SellersReader<R, Ext> {
+    /// Creates a reader of sellers with a custom extension type, see [`SellersReader::new`].
+    pub fn open(reader: R) -> crate::Result<Self> {
+        let mut reader = SellersReader {
+            reader: BufReader::new(reader),
+            offset: 0,
+            buffer: vec![],
+            state: State::Done,
+            metadata: SellersMetadata::default(),
+        };
+        reader.skip_whitespace()?;
+        reader.expect(b'{', "`{`")?;
+        if reader.read_properties(true)? {
+            reader.state = State::Sellers { first: true };
+        }
+        Ok(reader)
+    }
+
+    /// The top-level properties read so far.
+    pub fn metadata(&self) -> &SellersMetadata<Ext> {
+        &self.metadata
+    }
+
+    /// Skips the remaining sellers and returns the top-level properties.
+    ///
+    /// Fails if the rest of the file is not valid JSON. Sellers that cannot be deserialised
+    /// are ignored.
+    pub fn finish(mut self) -> crate::Result<SellersMetadata<Ext>> {
+        while let Some(result) = self.next() {
+            if let (Err(e), State::Failed) = (result, self.state) {
+                return Err(e);
+            }
+        }
+        Ok(self.metadata)
+    }
+
+    /// Reads top-level properties until the `sellers` array or the end of the object.
+    ///
+    /// Returns whether the reader is now positioned at the start of the `sellers` array.
+    fn read_properties(&mut self, mut first: bool) -> crate::Result<bool> {
+        loop {
+            self.skip_whitespace()?;
+            match self.peek()? {
+                Some(b'}') => {
+                    self.bump();
+                    self.skip_whitespace()?;
+                    return match self.peek()? {
+                        None => Ok(false),
+                        Some(_) => Err(self.syntax_error("the end of the file")),
+                    };
+                }
+                Some(b',') if !first => {
+                    self.bump();
+                    self.skip_whitespace()?;
+                }
+                _ if first => {}
+                _ => return Err(self.syntax_error("`,` or `}`")),
+            }
+            first = false;
+
+            let key: String = self.read_value()?;
+            self.skip_whitespace()?;
+            self.expect(b':', "`:`")?;
+            self.skip_whitespace()?;
+            if key == "sellers" && self.peek()? == Some(b'[') {
+                self.bump();
+                return Ok(true);
+            }
+            match key.as_str() {
+                "contact_address" => self.metadata.contact_address = self.read_value()?,
+                "contact_email" => self.metadata.contact_email = self.read_value()?,
+                "version" => self.metadata.version = self.read_value()?,
+                "ext" => self.metadata.ext = self.read_value()?,
+                "identifiers" => {
+                    self.metadata.identifiers = self.read_value::<Option<_>>()?.unwrap_or_default()
+                }
+                _ => self.read_raw_value()?,
+            }
+        }
+    }
+
+    /// Positions the reader after the next seller of the array, held in the buffer.
+    ///
+    /// Returns `false` at the end of the array.
+    fn read_seller(&mut self, first: bool) -> crate::Result<bool> {
+        self.skip_whitespace()?;
+        if self.peek()? == Some(b']') {
+            self.bump();
+            return Ok(false);
+        }
+        if !first {
+            self.expect(b',', "`,` or `]`")?;
+            self.skip_whitespace()?;
+        }
+        self.read_raw_value()?;
+        Ok(true)
+    }
+
+    /// Reads the next JSON value and deserialises it.
+    fn read_value<T: DeserializeOwned>(&mut self) -> crate::Result<T> {
+        self.read_raw_value()?;
+        Ok(serde_json::from_slice(&self.buffer)?)
+    }
+
+    /// Copies the next JSON value into the buffer, only checking that its strings and
+    /// brackets are balanced.
+    fn read_raw_value(&mut self) -> crate::Result<()> {
+        self.buffer.clear();
+        // The closing brackets of the arrays and objects being read, innermost last.
+        let mut closers: Vec<u8> = vec![];
+        let mut in_string = false;
+        let mut escaped = false;
+        loop {
+            let byte = match self.peek()? {
+                Some(byte) => byte,
+                None if closers.is_empty() && !in_string && !self.buffer.is_empty() => {
+                    return Ok(());
+                }
+                None => return Err(self.syntax_error("a value")),
+            };
+            if in_string {
+                self.push(byte);
+                if escaped {
+                    escaped = false;
+                } else if byte == b'\\' {
+                    escaped = true;
+                } else if byte == b'"' {
+                    in_string = false;
+                    if closers.is_empty() {
+                        return Ok(());
+                    }
+                }
+                continue;
+            }
+            match byte {
+                b'"' => {
+                    in_string = true;
+                    self.push(byte);
+                }
+                b'{' => {
+                    closers.push(b'}');
+                    self.push(byte);
+                }
+                b'[' => {
+                    closers.push(b']');
+                    self.push(byte);
+                }
+                b'}' | b']' if closers.last() == Some(&byte) => {
+                    closers.pop();
+                    self.push(byte);
+                    if closers.is_empty() {
+                        return Ok(());
+                    }
+                }
+                b'}' | b']' if !closers.is_empty() => {
+                    let expected = format!("`{}`", closers[closers.len() - 1] as char);
+                    return Err(self.syntax_error(&expected));
+                }
+                b',' | b'}' | b']' | b' ' | b'\t' | b'\n' | b'\r' if closers.is_empty() => {
+                    return if self.buffer.is_empty() {
+                        Err(self.syntax_error("a value"))
+                    } else {
+                        Ok(())
+                    };
+                }
+                _ => self.push(byte),
+            }
+        }
+    }
+
+    fn skip_whitespace(&mut self) -> crate::Result<()> {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek()? {
+            self.bump();
+        }
+        Ok(())
+    }
+
+    fn expect(&mut self, byte: u8, expected: &str) -> crate::Result<()> {
+        if self.peek()? == Some(byte) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.syntax_error(expected))
+        }
+    }
+
+    fn peek(&mut self) -> crate::Result<Option<u8>> {
+        Ok(self.reader.fill_buf()?.first().copied())
+    }
+
+    /// Consumes the byte returned by the last [`SellersReader::peek`].
+    fn bump(&mut self) {
+        self.reader.consume(1);
+        self.offset += 1;
+    }
+
+    fn push(&mut self, byte: u8) {
+        self.buffer.push(byte);
+        self.bump();
+    }
+
+    fn syntax_error(&self, expected: &str) -> crate::Error {
+        serde_plain::Error::custom(format!(
+            "invalid sellers.json at byte {}: expected {}",
+            self.offset, expected
+        ))
+        .into()
+    }
+}
+
+impl<R: Read, Ext: Extension> Iterator for SellersReader<R, Ext> {
+    type Item = crate::Result<Seller<Ext>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let State::Sellers { first } = self.state else {
+                return None;
+            };
+            let result = match self.read_seller(first) {
+                Ok(true) => {
+                    self.state = State::Sellers { first: false };
+                    return Some(serde_json::from_slice(&self.buffer).map_err(|e| e.into()));
+                }
+                Ok(false) => self.read_properties(false),
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(true) => self.state = State::Sellers { first: true },
+                Ok(false) => self.state = State::Done,
+                Err(e) => {
+                    self.state = State::Failed;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SellerType, Sellers, SellersIdentifierName};
+    use serde::{Deserialize, Serialize};
+    use std::str::FromStr;
+
+    const CONTENT: &str = r#"{
+  "contact_email": "adops@ssp.com",
+  "contact_address": "1 Main St, \"Suite\" {2}",
+  "version": "1.0",
+  "identifiers": [{"name": "TAG-ID", "value": "28cb65e5bbc0bd5f"}],
+  "sellers": [
+    {"seller_id": "1", "seller_type": "PUBLISHER", "name": "Pub, Inc. [main]", "domain": "pub.com"},
+    {"seller_id": "2", "seller_type": "INTERMEDIARY", "name": "Net", "ext": {"tier": [1, 2]}},
+    {"seller_id": "3", "seller_type": "BOTH", "is_confidential": 1}
+  ],
+  "ext": {"network": "ssp"}
+}"#;
+
+    /// A reader returning a single byte per read, to exercise buffer boundaries.
+    struct OneByte<'a>(&'a [u8]);
+
+    impl Read for OneByte<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            match self.0.split_first() {
+                Some((byte, rest)) if !buf.is_empty() => {
+                    buf[0] = *byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn stream_matches_from_str() {
+        let sellers = Sellers::from_str(CONTENT).unwrap();
+        let mut reader = SellersReader::new(OneByte(CONTENT.as_bytes())).unwrap();
+        assert_eq!(
+            reader.metadata().contact_address.as_deref(),
+            Some("1 Main St, \"Suite\" {2}")
+        );
+        assert_eq!(reader.metadata().identifiers.len(), 1);
+        assert!(reader.metadata().ext.is_none());
+
+        let streamed: Vec<Seller> = reader.by_ref().collect::<crate::Result<_>>().unwrap();
+        assert_eq!(streamed.len(), 3);
+        for (streamed, seller) in streamed.iter().zip(&sellers.sellers) {
+            assert_eq!(streamed.to_string(), seller.to_string());
+        }
+
+        let metadata = reader.finish().unwrap();
+        assert_eq!(metadata.contact_email, sellers.contact_email);
+        assert_eq!(metadata.version, Some(SellersVersion::OneZero));
+        assert_eq!(metadata.identifiers[0].name, SellersIdentifierName::TagId);
+        assert_eq!(metadata.ext.unwrap()["network"], "ssp");
+    }
+
+    #[test]
+    fn finish_skips_remaining_sellers() {
+        let reader = SellersReader::new(CONTENT.as_bytes()).unwrap();
+        let metadata = reader.finish().unwrap();
+        assert!(metadata.ext.is_some());
+    }
+
+    #[test]
+    fn files_without_sellers() {
+        for content in [
+            r#"{"version":"1.0"}"#,
+            r#" { "version" : "1.0" , "sellers" : [ ] } "#,
+            r#"{"sellers":null,"version":"1.0"}"#,
+            "{}",
+        ] {
+            let mut reader = SellersReader::new(content.as_bytes()).unwrap();
+            assert!(reader.next().is_none(), "{content}");
+            assert!(reader.finish().is_ok(), "{content}");
+        }
+    }
+
+    #[test]
+    fn invalid_sellers_are_reported_and_skipped() {
+        let content = r#"{"version":"1.0","sellers":[{"seller_id":"1","seller_type":"PUBLISHER","name":"A"},{"seller_id":"2","seller_type":"UNKNOWN"},{"seller_id":"3","seller_type":"BOTH","name":"C"}]}"#;
+        let results: Vec<_> = SellersReader::new(content.as_bytes()).unwrap().collect();
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert_eq!(results[2].as_ref().unwrap().seller_type, SellerType::Both);
+    }
+
+    #[test]
+    fn malformed_json_ends_iteration() {
+        assert!(SellersReader::new("[]".as_bytes()).is_err());
+        assert!(SellersReader::new(r#"{"version" "1.0"}"#.as_bytes()).is_err());
+        assert!(SellersReader::new(r#"{"version":"2.0","sellers":[]}"#.as_bytes()).is_err());
+
+        let content = r#"{"sellers":[{"seller_id":"1","seller_type":"PUBLISHER","name":"A"} {"seller_id":"2"}]}"#;
+        let mut reader = SellersReader::new(content.as_bytes()).unwrap();
+        assert!(reader.next().unwrap().is_ok());
+        let err = reader.next().unwrap().unwrap_err();
+        assert!(err.to_string().contains("expected `,` or `]`"), "{err}");
+        assert!(reader.next().is_none());
+
+        let reader = SellersReader::new(r#"{"sellers":[{"seller_id":"1""#.as_bytes()).unwrap();
+        assert!(reader.finish().is_err());
+
+        let reader = SellersReader::new(r#"{"sellers":[]} x"#.as_bytes()).unwrap();
+        assert!(reader.finish().is_err());
+
+        let content = r#"{"sellers":[{"seller_id":"1","ext":[}],{"seller_id":"2"}]}"#;
+        let mut reader = SellersReader::new(content.as_bytes()).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert!(err.to_string().contains("expected `]`"), "{err}");
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn read_typed_ext() {
+        #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+        struct NetworkExt {
+            network: String,
+        }
+
+        let mut reader: SellersReader<_, NetworkExt> = SellersReader::open(
+            r#"{"ext":{"network":"ssp"},"sellers":[{"seller_id":"1","seller_type":"PUBLISHER","name":"A","ext":{"network":"x"}}]}"#
+                .as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(reader.metadata().ext.as_ref().unwrap().network, "ssp");
+        assert_eq!(reader.next().unwrap().unwrap().ext.unwrap().network, "x");
+    }
+}
@@ -0,0 +1,317 @@
+use crate::{Extension, Seller, SellerType, Sellers};
+use iab_specs_core::{is_email_address, is_host_name};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A violation of the semantic rules of the sellers.json specification.
+///
+/// Deserialisation only enforces the shape of the file. These findings cover the rules that
+/// buyers rely on when analysing supply paths.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SellersFinding {
+    /// `contact_email` is not a valid email address.
+    InvalidContactEmail { contact_email: String },
+
+    /// Several sellers share the same `seller_id`.
+    DuplicateSellerId { seller_id: String, count: usize },
+
+    /// A non-confidential PUBLISHER or BOTH seller has no `name`.
+    MissingName { seller_id: String },
+
+    /// A non-confidential PUBLISHER or BOTH seller has no `domain`.
+    MissingDomain { seller_id: String },
+
+    /// `domain` is not a bare domain name, for instance a URL, a host with a port or a `www.`
+    /// host. With the `psl` feature, it is also reported when it is not the root domain of the
+    /// seller, the public suffix plus one label, for instance `ads.pub.com`.
+    InvalidDomain { seller_id: String, domain: String },
+
+    /// `is_passthrough` is set on a seller that is not an INTERMEDIARY.
+    PassthroughNotIntermediary {
+        seller_id: String,
+        seller_type: SellerType,
+    },
+
+    /// A confidential seller discloses its `name`.
+    ConfidentialWithName { seller_id: String },
+
+    /// A confidential seller discloses its `domain`.
+    ConfidentialWithDomain { seller_id: String },
+}
+
+impl<Ext: Extension> Sellers<Ext> {
+    /// Checks the file against the semantic rules of the specification.
+    ///
+    /// File-level findings come first, then duplicate seller IDs, then the findings of each
+    /// seller in file order. The version is not checked here as [`SellersVersion`] only
+    /// accepts 1.0.
+    ///
+    /// [`SellersVersion`]: crate::SellersVersion
+    pub fn validate(&self) -> Vec<SellersFinding> {
+        let mut findings = vec![];
+        if let Some(contact_email) = &self.contact_email {
+            if !is_email_address(contact_email) {
+                findings.push(SellersFinding::InvalidContactEmail {
+                    contact_email: contact_email.clone(),
+                });
+            }
+        }
+
+        let mut seller_ids: BTreeMap<&str, usize> = BTreeMap::new();
+        for seller in &self.sellers {
+            *seller_ids.entry(&seller.seller_id).or_default() += 1;
+        }
+        findings.extend(seller_ids.into_iter().filter(|(_, count)| *count > 1).map(
+            |(seller_id, count)| SellersFinding::DuplicateSellerId {
+                seller_id: seller_id.to_string(),
+                count,
+            },
+        ));
+
+        for seller in &self.sellers {
+            validate_seller(seller, &mut findings);
+        }
+        findings
+    }
+}
+
+fn validate_seller<Ext: Extension>(seller: &Seller<Ext>, findings: &mut Vec<SellersFinding>) {
+    let seller_id = || seller.seller_id.clone();
+    if seller.is_confidential {
+        if seller.name.is_some() {
+            findings.push(SellersFinding::ConfidentialWithName {
+                seller_id: seller_id(),
+            });
+        }
+        if seller.domain.is_some() {
+            findings.push(SellersFinding::ConfidentialWithDomain {
+                seller_id: seller_id(),
+            });
+        }
+    } else if seller.seller_type != SellerType::Intermediary {
+        if seller.name.is_none() {
+            findings.push(SellersFinding::MissingName {
+                seller_id: seller_id(),
+            });
+        }
+        if seller.domain.is_none() {
+            findings.push(SellersFinding::MissingDomain {
+                seller_id: seller_id(),
+            });
+        }
+    }
+    if let Some(domain) = &seller.domain {
+        if !is_root_domain(domain) {
+            findings.push(SellersFinding::InvalidDomain {
+                seller_id: seller_id(),
+                domain: domain.clone(),
+            });
+        }
+    }
+    if seller.is_passthrough && seller.seller_type != SellerType::Intermediary {
+        findings.push(SellersFinding::PassthroughNotIntermediary {
+            seller_id: seller_id(),
+            seller_type: seller.seller_type.clone(),
+        });
+    }
+}
+
+/// Whether `domain` is a bare domain name, without scheme, path, port or `www.` label.
+///
+/// With the `psl` feature, the domain must also be its registrable part, the public suffix
+/// plus one label.
+fn is_root_domain(domain: &str) -> bool {
+    is_host_name(domain)
+        && !domain
+            .get(..4)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("www."))
+        && is_registrable_domain(domain)
+}
+
+#[cfg(feature = "psl")]
+fn is_registrable_domain(domain: &str) -> bool {
+    let domain = domain.to_ascii_lowercase();
+    psl::domain_str(&domain) == Some(domain.as_str())
+}
+
+#[cfg(not(feature = "psl"))]
+fn is_registrable_domain(_domain: &str) -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn validate(sellers: &str) -> Vec<SellersFinding> {
+        Sellers::from_str(&format!(
+            r#"{{"version":"1.0","contact_email":"adops@ssp.com","identifiers":[],"sellers":[{}]}}"#,
+            sellers
+        ))
+        .unwrap()
+        .validate()
+    }
+
+    #[test]
+    fn valid_file_has_no_findings() {
+        let findings = validate(
+            r#"{"seller_id":"1","seller_type":"PUBLISHER","name":"Pub","domain":"pub.com"},
+            {"seller_id":"2","seller_type":"INTERMEDIARY","name":"Reseller","is_passthrough":1},
+            {"seller_id":"3","seller_type":"BOTH","is_confidential":1}"#,
+        );
+        assert_eq!(findings, vec![]);
+    }
+
+    #[test]
+    // Spec: Section 2.1 — contact_email is an email address
+    fn invalid_contact_email() {
+        let mut sellers =
+            Sellers::from_str(r#"{"version":"1.0","identifiers":[],"sellers":[]}"#).unwrap();
+        assert_eq!(sellers.validate(), vec![]);
+        sellers.contact_email = Some("adops at ssp.com".to_string());
+        assert_eq!(
+            sellers.validate(),
+            vec![SellersFinding::InvalidContactEmail {
+                contact_email: "adops at ssp.com".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    // Spec: Section 2.3 — seller_id is unique within the file
+    fn duplicate_seller_ids() {
+        let findings = validate(
+            r#"{"seller_id":"1","seller_type":"INTERMEDIARY","name":"A"},
+            {"seller_id":"1","seller_type":"INTERMEDIARY","name":"B"},
+            {"seller_id":"1","seller_type":"INTERMEDIARY","name":"C"}"#,
+        );
+        assert_eq!(
+            findings,
+            vec![SellersFinding::DuplicateSellerId {
+                seller_id: "1".to_string(),
+                count: 3
+            }]
+        );
+    }
+
+    #[test]
+    // Spec: Section 2.3 — name and domain of non-confidential publishers
+    fn missing_name_and_domain() {
+        let mut sellers = Sellers::from_str(
+            r#"{"version":"1.0","identifiers":[],"sellers":[{"seller_id":"1","seller_type":"BOTH","name":"Pub"}]}"#,
+        )
+        .unwrap();
+        sellers.sellers[0].name = None;
+        assert_eq!(
+            sellers.validate(),
+            vec![
+                SellersFinding::MissingName {
+                    seller_id: "1".to_string()
+                },
+                SellersFinding::MissingDomain {
+                    seller_id: "1".to_string()
+                }
+            ]
+        );
+    }
+
+    #[test]
+    // Spec: Section 2.3 — domain is the root domain name of the seller
+    fn invalid_domains() {
+        for domain in [
+            "https://pub.com",
+            "pub.com/ads",
+            "www.pub.com",
+            "pub.com:443",
+            "pub",
+            "-pub.com",
+        ] {
+            let findings = validate(&format!(
+                r#"{{"seller_id":"1","seller_type":"PUBLISHER","name":"Pub","domain":"{}"}}"#,
+                domain
+            ));
+            assert_eq!(
+                findings,
+                vec![SellersFinding::InvalidDomain {
+                    seller_id: "1".to_string(),
+                    domain: domain.to_string()
+                }],
+                "{domain}"
+            );
+        }
+    }
+
+    #[cfg(feature = "psl")]
+    #[test]
+    // Spec: Section 2.3 — domain is the root domain name of the seller
+    fn subdomains_are_not_root_domains() {
+        for domain in ["ads.pub.com", "Ads.Pub.co.uk", "co.uk"] {
+            let findings = validate(&format!(
+                r#"{{"seller_id":"1","seller_type":"PUBLISHER","name":"Pub","domain":"{}"}}"#,
+                domain
+            ));
+            assert_eq!(
+                findings,
+                vec![SellersFinding::InvalidDomain {
+                    seller_id: "1".to_string(),
+                    domain: domain.to_string()
+                }],
+                "{domain}"
+            );
+        }
+        for domain in ["pub.com", "Pub.co.uk"] {
+            let findings = validate(&format!(
+                r#"{{"seller_id":"1","seller_type":"PUBLISHER","name":"Pub","domain":"{}"}}"#,
+                domain
+            ));
+            assert_eq!(findings, vec![], "{domain}");
+        }
+    }
+
+    #[test]
+    // Spec: Section 2.3 — is_passthrough only applies to intermediaries
+    fn passthrough_not_intermediary() {
+        let findings = validate(
+            r#"{"seller_id":"1","seller_type":"PUBLISHER","name":"Pub","domain":"pub.com","is_passthrough":1}"#,
+        );
+        assert_eq!(
+            findings,
+            vec![SellersFinding::PassthroughNotIntermediary {
+                seller_id: "1".to_string(),
+                seller_type: SellerType::Publisher
+            }]
+        );
+    }
+
+    #[test]
+    // Spec: Section 2.3 — confidential sellers omit name and domain
+    fn confidential_with_identity() {
+        let findings = validate(
+            r#"{"seller_id":"1","seller_type":"PUBLISHER","is_confidential":1,"name":"Pub","domain":"pub.com"}"#,
+        );
+        assert_eq!(
+            findings,
+            vec![
+                SellersFinding::ConfidentialWithName {
+                    seller_id: "1".to_string()
+                },
+                SellersFinding::ConfidentialWithDomain {
+                    seller_id: "1".to_string()
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn findings_serialize_with_type_tag() {
+        let finding = SellersFinding::MissingDomain {
+            seller_id: "1".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_string(&finding).unwrap(),
+            r#"{"type":"missing_domain","seller_id":"1"}"#
+        );
+    }
+}
@@ -0,0 +1,436 @@
+use crate::{AuthorizedSellerChecker, Extension, SellerType, SellersIndex};
+use iab_specs_ads_txt::{AdsTxt, DomainCanonicalizer, SellerRelationType};
+use iab_specs_openrtb::{SupplyChain, SupplyChainNode};
+use isosphere::CountryCode;
+use serde::{Deserialize, Serialize};
+
+/// The status of one node of a SupplyChain object.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SupplyChainNodeStatus {
+    /// The node passed every check.
+    ///
+    /// `domain_verified` tells whether the seller domain was compared with the OWNERDOMAIN and
+    /// MANAGERDOMAIN of the publisher. It only is for the first node of a complete chain, when
+    /// the seller discloses its domain and the ads.txt file declares either variable.
+    Verified {
+        seller_type: SellerType,
+        domain_verified: bool,
+    },
+
+    /// No sellers.json file of the `asi` advertising system is indexed.
+    UnknownAdvertisingSystem,
+
+    /// The sellers.json file of `asi` has no seller `sid`.
+    SellerNotFound,
+
+    /// The first node of a complete chain is an INTERMEDIARY seller, or a later node is a
+    /// PUBLISHER seller.
+    SellerTypeMismatch { seller_type: SellerType },
+
+    /// The first node of a complete chain is not a DIRECT record of the publisher ads.txt.
+    NotDirectInAdsTxt,
+
+    /// The seller of the first node of a complete chain is neither the OWNERDOMAIN nor an
+    /// applicable MANAGERDOMAIN of the publisher.
+    DomainMismatch {
+        seller_domain: String,
+        expected: Vec<String>,
+    },
+
+    /// `hp` is not 1. In SupplyChain 1.0, every node is involved in the flow of payment.
+    NotInPaymentFlow { hp: u8 },
+}
+
+impl SupplyChainNodeStatus {
+    /// Whether the node is verified.
+    pub fn is_verified(&self) -> bool {
+        matches!(self, SupplyChainNodeStatus::Verified { .. })
+    }
+}
+
+/// A SupplyChain node with its status.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SupplyChainNodeCheck {
+    /// The advertising system domain of the node.
+    pub asi: String,
+
+    /// The seller ID of the node in the sellers.json file of `asi`.
+    pub sid: String,
+
+    /// The outcome of the checks of the node.
+    pub status: SupplyChainNodeStatus,
+}
+
+/// The status of every node of a SupplyChain object, in chain order.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SupplyChainReport {
+    /// Whether the chain declares itself complete, `complete=1`.
+    pub complete: bool,
+
+    /// One check per node of the chain.
+    pub nodes: Vec<SupplyChainNodeCheck>,
+}
+
+impl SupplyChainReport {
+    /// Whether the chain is complete and every node is verified, that is whether the whole
+    /// path from the publisher to the sender of the request is transparent.
+    pub fn is_verified(&self) -> bool {
+        self.complete
+            && !self.nodes.is_empty()
+            && self.nodes.iter().all(|node| node.status.is_verified())
+    }
+
+    /// The nodes that are not verified.
+    pub fn failures(&self) -> impl Iterator<Item = &SupplyChainNodeCheck> {
+        self.nodes.iter().filter(|node| !node.status.is_verified())
+    }
+}
+
+/// Verifies the nodes of OpenRTB SupplyChain objects against the ads.txt file of the publisher
+/// and the sellers.json files of the advertising systems.
+///
+/// Every node must be a seller of the sellers.json file of its `asi` and have `hp=1`. In a
+/// complete chain, the first node is the publisher: it must be a PUBLISHER or BOTH seller
+/// disclosing the OWNERDOMAIN, or the MANAGERDOMAIN for the country of the traffic, and be
+/// declared DIRECT in the ads.txt file. Each later hop is a reseller of the previous node and
+/// must be an INTERMEDIARY or BOTH seller.
+///
+/// Domains are compared as in [`AuthorizedSellerChecker`].
+///
+/// ```
+/// use iab_specs_ads_txt::AdsTxt;
+/// use iab_specs_openrtb::SupplyChain;
+/// use iab_specs_sellers_json::{Sellers, SellersIndex, SupplyChainValidator};
+/// use std::str::FromStr;
+///
+/// let sellers = Sellers::from_str(
+///     r#"{"version":"1.0","identifiers":[],"sellers":[{"seller_id":"pub-1","seller_type":"PUBLISHER","name":"Pub","domain":"pub.com"}]}"#,
+/// )
+/// .unwrap();
+/// let index = SellersIndex::new([("ssp.com", sellers)]);
+/// let ads_txt = AdsTxt::from_str("ownerdomain=pub.com\nssp.com, pub-1, DIRECT").unwrap();
+/// let schain: SupplyChain = serde_json::from_str(
+///     r#"{"complete":1,"ver":"1.0","nodes":[{"asi":"ssp.com","sid":"pub-1","hp":1}]}"#,
+/// )
+/// .unwrap();
+/// assert!(SupplyChainValidator::new(&index).validate(&schain, &ads_txt).is_verified());
+/// ```
+pub struct SupplyChainValidator<'a, Ext: Extension = serde_json::Value> {
+    checker: AuthorizedSellerChecker<'a, Ext>,
+}
+
+impl<'a, Ext: Extension> SupplyChainValidator<'a, Ext> {
+    /// Creates a validator looking sellers up in `index`, accepting the MANAGERDOMAIN of any
+    /// country.
+    pub fn new(index: &'a SellersIndex<Ext>) -> Self {
+        SupplyChainValidator {
+            checker: AuthorizedSellerChecker::new(index),
+        }
+    }
+
+    /// Uses `canonicalizer` to compare domains.
+    pub fn with_canonicalizer(mut self, canonicalizer: DomainCanonicalizer) -> Self {
+        self.checker = self.checker.with_canonicalizer(canonicalizer);
+        self
+    }
+
+    /// Only accepts the MANAGERDOMAIN of `country_code`, or the global one when the file has
+    /// none for this country.
+    pub fn with_country_code(mut self, country_code: CountryCode) -> Self {
+        self.checker = self.checker.with_country_code(country_code);
+        self
+    }
+
+    /// Verifies every node of `schain`, whose inventory is declared by the publisher ads.txt
+    /// file `ads_txt`.
+    pub fn validate<SExt: Extension>(
+        &self,
+        schain: &SupplyChain<SExt>,
+        ads_txt: &AdsTxt,
+    ) -> SupplyChainReport {
+        let complete = schain.complete == Some(1);
+        let expected = self.checker.expected_domains(ads_txt);
+        let nodes = schain
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(position, node)| {
+                let originating = complete && position == 0;
+                SupplyChainNodeCheck {
+                    asi: node.asi.clone(),
+                    sid: node.sid.clone(),
+                    status: self.validate_node(node, originating, ads_txt, &expected),
+                }
+            })
+            .collect();
+        SupplyChainReport { complete, nodes }
+    }
+
+    /// Verifies `node`. The `originating` node of a complete chain is checked against
+    /// `ads_txt` and the `expected` canonical domains of the publisher.
+    fn validate_node(
+        &self,
+        node: &SupplyChainNode,
+        originating: bool,
+        ads_txt: &AdsTxt,
+        expected: &[String],
+    ) -> SupplyChainNodeStatus {
+        let advertising_system = self.checker.canonicalize(&node.asi);
+        let index = self.checker.index();
+        if !index.contains_advertising_system(&advertising_system) {
+            return SupplyChainNodeStatus::UnknownAdvertisingSystem;
+        }
+        let Some(seller) = index.get(&advertising_system, &node.sid) else {
+            return SupplyChainNodeStatus::SellerNotFound;
+        };
+        let type_matches = if originating {
+            seller.seller_type != SellerType::Intermediary
+        } else {
+            seller.seller_type != SellerType::Publisher
+        };
+        if !type_matches {
+            return SupplyChainNodeStatus::SellerTypeMismatch {
+                seller_type: seller.seller_type.clone(),
+            };
+        }
+
+        let mut domain_verified = false;
+        if originating {
+            let declared = ads_txt.systems.iter().any(|record| {
+                record.relation == SellerRelationType::Direct
+                    && record.publisher_id.eq_ignore_ascii_case(&node.sid)
+                    && self.checker.canonicalize(&record.domain) == advertising_system
+            });
+            if !declared {
+                return SupplyChainNodeStatus::NotDirectInAdsTxt;
+            }
+            match self
+                .checker
+                .seller_domain(SellerRelationType::Direct, seller, expected)
+            {
+                Some(domain) if !expected.contains(&domain) => {
+                    return SupplyChainNodeStatus::DomainMismatch {
+                        seller_domain: domain,
+                        expected: expected.to_vec(),
+                    };
+                }
+                seller_domain => domain_verified = seller_domain.is_some(),
+            }
+        }
+
+        if node.hp != 1 {
+            return SupplyChainNodeStatus::NotInPaymentFlow { hp: node.hp };
+        }
+        SupplyChainNodeStatus::Verified {
+            seller_type: seller.seller_type.clone(),
+            domain_verified,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Sellers;
+    use std::str::FromStr;
+
+    fn sellers(sellers: &str) -> Sellers {
+        Sellers::from_str(&format!(
+            r#"{{"version":"1.0","identifiers":[],"sellers":[{}]}}"#,
+            sellers
+        ))
+        .unwrap()
+    }
+
+    fn index() -> SellersIndex {
+        SellersIndex::new([
+            (
+                "ssp.com",
+                sellers(
+                    r#"{"seller_id":"pub-1","seller_type":"PUBLISHER","name":"Pub","domain":"pub.com"},
+                    {"seller_id":"net-1","seller_type":"INTERMEDIARY","name":"Net","domain":"net.com"}"#,
+                ),
+            ),
+            (
+                "exchange.com",
+                sellers(
+                    r#"{"seller_id":"ssp-1","seller_type":"INTERMEDIARY","name":"SSP","domain":"ssp.com"},
+                    {"seller_id":"pub-9","seller_type":"PUBLISHER","name":"Other","domain":"other.com"}"#,
+                ),
+            ),
+        ])
+    }
+
+    fn ads_txt() -> AdsTxt {
+        AdsTxt::from_str("ownerdomain=pub.com\nssp.com, pub-1, DIRECT\nssp.com, net-1, RESELLER")
+            .unwrap()
+    }
+
+    fn schain(complete: u8, nodes: &[(&str, &str, u8)]) -> SupplyChain {
+        SupplyChain::builder()
+            .complete(Some(complete))
+            .nodes(
+                nodes
+                    .iter()
+                    .map(|(asi, sid, hp)| {
+                        SupplyChainNode::builder()
+                            .asi(asi.to_string())
+                            .sid(sid.to_string())
+                            .hp(*hp)
+                            .build()
+                            .unwrap()
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .build()
+            .unwrap()
+    }
+
+    fn statuses(report: &SupplyChainReport) -> Vec<&SupplyChainNodeStatus> {
+        report.nodes.iter().map(|node| &node.status).collect()
+    }
+
+    #[test]
+    // Spec: SupplyChain — the first node of a complete chain is the publisher
+    fn verified_complete_chain() {
+        let index = index();
+        let report = SupplyChainValidator::new(&index).validate(
+            &schain(1, &[("ssp.com", "pub-1", 1), ("exchange.com", "ssp-1", 1)]),
+            &ads_txt(),
+        );
+        assert!(report.is_verified());
+        assert_eq!(report.failures().count(), 0);
+        assert_eq!(
+            statuses(&report),
+            vec![
+                &SupplyChainNodeStatus::Verified {
+                    seller_type: SellerType::Publisher,
+                    domain_verified: true
+                },
+                &SupplyChainNodeStatus::Verified {
+                    seller_type: SellerType::Intermediary,
+                    domain_verified: false
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn incomplete_chain_is_not_verified() {
+        let index = index();
+        let report = SupplyChainValidator::new(&index)
+            .validate(&schain(0, &[("ssp.com", "net-1", 1)]), &ads_txt());
+        assert!(!report.complete);
+        assert!(!report.is_verified());
+        assert_eq!(report.failures().count(), 0);
+
+        let report = SupplyChainValidator::new(&index).validate(&schain(1, &[]), &ads_txt());
+        assert!(!report.is_verified());
+    }
+
+    #[test]
+    fn unknown_nodes() {
+        let index = index();
+        let report = SupplyChainValidator::new(&index).validate(
+            &schain(0, &[("unknown.com", "1", 1), ("exchange.com", "ssp-2", 1)]),
+            &ads_txt(),
+        );
+        assert_eq!(
+            statuses(&report),
+            vec![
+                &SupplyChainNodeStatus::UnknownAdvertisingSystem,
+                &SupplyChainNodeStatus::SellerNotFound
+            ]
+        );
+        assert_eq!(report.failures().count(), 2);
+    }
+
+    #[test]
+    fn seller_type_mismatch() {
+        let index = index();
+        let report = SupplyChainValidator::new(&index).validate(
+            &schain(1, &[("ssp.com", "net-1", 1), ("exchange.com", "pub-9", 1)]),
+            &ads_txt(),
+        );
+        assert_eq!(
+            statuses(&report),
+            vec![
+                &SupplyChainNodeStatus::SellerTypeMismatch {
+                    seller_type: SellerType::Intermediary
+                },
+                &SupplyChainNodeStatus::SellerTypeMismatch {
+                    seller_type: SellerType::Publisher
+                }
+            ]
+        );
+    }
+
+    #[test]
+    // Spec: Ads.txt 1.1 — OWNERDOMAIN matches the first node of complete chains
+    fn originating_node_against_ads_txt() {
+        let index = index();
+        let validator = SupplyChainValidator::new(&index);
+        let chain = schain(1, &[("ssp.com", "pub-1", 1)]);
+
+        let report = validator.validate(
+            &chain,
+            &AdsTxt::from_str("ssp.com, pub-1, RESELLER").unwrap(),
+        );
+        assert_eq!(
+            statuses(&report),
+            vec![&SupplyChainNodeStatus::NotDirectInAdsTxt]
+        );
+
+        let report = validator.validate(
+            &chain,
+            &AdsTxt::from_str("ownerdomain=other.com\nssp.com, pub-1, DIRECT").unwrap(),
+        );
+        assert_eq!(
+            statuses(&report),
+            vec![&SupplyChainNodeStatus::DomainMismatch {
+                seller_domain: "pub.com".to_string(),
+                expected: vec!["other.com".to_string()]
+            }]
+        );
+
+        let report = validator.validate(
+            &chain,
+            &AdsTxt::from_str(
+                "ownerdomain=other.com\nmanagerdomain=pub.com\nssp.com, pub-1, DIRECT",
+            )
+            .unwrap(),
+        );
+        assert!(report.is_verified());
+    }
+
+    #[test]
+    // Spec: SupplyChain — hp should always be 1 in version 1.0
+    fn node_not_in_payment_flow() {
+        let index = index();
+        let report = SupplyChainValidator::new(&index).validate(
+            &schain(1, &[("ssp.com", "pub-1", 1), ("exchange.com", "ssp-1", 0)]),
+            &ads_txt(),
+        );
+        assert!(!report.is_verified());
+        assert_eq!(
+            report.failures().collect::<Vec<_>>(),
+            vec![&SupplyChainNodeCheck {
+                asi: "exchange.com".to_string(),
+                sid: "ssp-1".to_string(),
+                status: SupplyChainNodeStatus::NotInPaymentFlow { hp: 0 }
+            }]
+        );
+    }
+
+    #[test]
+    fn report_serializes_with_status_tag() {
+        let index = index();
+        let report = SupplyChainValidator::new(&index)
+            .validate(&schain(0, &[("ssp.com", "pub-2", 1)]), &ads_txt());
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"{"complete":false,"nodes":[{"asi":"ssp.com","sid":"pub-2","status":{"type":"seller_not_found"}}]}"#
+        );
+    }
+}